@@ -1,7 +1,10 @@
 use crate::{
     client::{LicheszterInner, UrlBase},
     error::Result,
-    models::user::{Email, KidMode, Preferences, Timeline, User},
+    models::{
+        oauth::Scope,
+        user::{Email, KidMode, Preferences, Timeline, User},
+    },
 };
 
 use std::sync::Arc;
@@ -29,6 +32,8 @@ impl AccountApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn email(&self) -> Result<Email> {
+        self.inner.require_scope(Scope::EmailRead)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/account/email");
         let builder = self.inner.client.get(url);
 
@@ -40,6 +45,8 @@ impl AccountApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn preferences(&self) -> Result<Preferences> {
+        self.inner.require_scope(Scope::PreferenceRead)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, "api/account/preferences");
@@ -53,6 +60,8 @@ impl AccountApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn kid_mode(&self) -> Result<KidMode> {
+        self.inner.require_scope(Scope::PreferenceRead)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/account/kid");
         let builder = self.inner.client.get(url);
 
@@ -64,6 +73,8 @@ impl AccountApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn kid_mode_set(&self, kid: bool) -> Result<()> {
+        self.inner.require_scope(Scope::PreferenceWrite)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/account/kid");
         let builder = self.inner.client.post(url).query(&[("v", kid)]);
 
@@ -1,10 +1,12 @@
 use crate::{
     client::{LicheszterInner, UrlBase},
-    error::Result,
+    config::analysis::{CloudBatchOptions, CloudEvalCache, CloudEvalKey},
+    error::{ErrorKind, Result},
     models::{analysis::CloudAnalysis, game::VariantMode},
 };
 
-use std::sync::Arc;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use std::{collections::HashMap, sync::Arc};
 
 /// A struct for accessing the Analysis API endpoints.
 #[derive(Debug)]
@@ -47,4 +49,65 @@ impl AnalysisApi {
 
         self.inner.to_model::<CloudAnalysis>(builder).await
     }
+
+    /// Get the cached evaluations of many positions, sending a bounded number of requests at the same time.
+    /// The results are returned in the same order as the positions, with `None` for the positions without an evaluation.
+    /// If a cache is given, it is consulted before sending a request, and the results of all requests are stored in it.
+    ///
+    /// # Errors
+    /// Returns an error if any API request fails for another reason than the evaluation not being available,
+    /// or a response cannot be deserialized.
+    pub async fn cloud_batch<I, S>(
+        &self,
+        fens: I,
+        options: Option<&CloudBatchOptions>,
+        cache: Option<&dyn CloudEvalCache>,
+    ) -> Result<Vec<Option<CloudAnalysis>>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let options = options.copied().unwrap_or_default();
+
+        // Transpositions share the same key, so every key is only looked up once
+        let mut known = HashMap::new();
+        let mut lookups: Vec<(CloudEvalKey, String)> = Vec::new();
+        let mut indices = Vec::new();
+        for fen in fens {
+            let fen = fen.as_ref();
+            let key = options.key(fen);
+            let index = *known.entry(key.clone()).or_insert_with(|| {
+                lookups.push((key, fen.to_string()));
+                lookups.len() - 1
+            });
+            indices.push(index);
+        }
+
+        let lookups = lookups.into_iter().map(|(key, fen)| async move {
+            if let Some(cached) = cache.and_then(|cache| cache.get(&key)) {
+                return Ok(cached);
+            }
+
+            // Lichess responds with 404 Not Found if the position has no cloud evaluation
+            let analysis = match self.cloud(&fen, key.multi_pv, key.variant).await {
+                Ok(analysis) => Some(analysis),
+                Err(e) if e.kind() == ErrorKind::NotFound => None,
+                Err(e) => return Err(e),
+            };
+
+            if let Some(cache) = cache {
+                cache.insert(key, analysis.clone());
+            }
+            Ok(analysis)
+        });
+
+        let results: Vec<Option<CloudAnalysis>> = stream::iter(lookups)
+            .buffered(options.max_concurrency())
+            .try_collect()
+            .await?;
+        Ok(indices
+            .into_iter()
+            .map(|index| results[index].clone())
+            .collect())
+    }
 }
@@ -1,12 +1,14 @@
 use std::pin::Pin;
 
 use crate::{
-    client::{LicheszterInner, UrlBase},
+    client::{LicheszterInner, ResilientItem, UrlBase},
     config::board::SeekOptions,
+    config::client::ReconnectOptions,
     error::Result,
     models::{
         board::BoardState,
         chat::{ChatMessage, ChatRoom},
+        oauth::Scope,
     },
 };
 
@@ -15,6 +17,7 @@ use reqwest::header;
 use std::sync::Arc;
 
 /// A struct for accessing the Board API endpoints.
+/// All endpoints require the authentication token to have the `board:play` scope.
 #[derive(Debug)]
 pub struct BoardApi {
     pub(crate) inner: Arc<LicheszterInner>,
@@ -29,6 +32,8 @@ impl BoardApi {
         &self,
         options: Option<&SeekOptions>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<()>> + Send>>> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/board/seek");
         let mut builder = self.inner.client.post(url);
 
@@ -51,6 +56,8 @@ impl BoardApi {
         &self,
         game_id: &str,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<BoardState>> + Send>>> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/stream/{game_id}"),
@@ -60,12 +67,38 @@ impl BoardApi {
         self.inner.to_stream::<BoardState>(builder).await
     }
 
+    /// Stream game state using the Board API, reconnecting automatically when the connection is lost.
+    /// The full game state that Lichess sends again after reconnecting is skipped if the game has not progressed,
+    /// and [`ResilientItem::Reconnected`](enum@crate::client::ResilientItem) is emitted every time the stream is reopened.
+    /// The stream ends once the game is over.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn game_connect_resilient(
+        &self,
+        game_id: &str,
+        options: ReconnectOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ResilientItem<BoardState>>> + Send>>> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/board/game/stream/{game_id}"),
+        );
+
+        self.inner
+            .to_resilient_stream::<BoardState>(url, options)
+            .await
+    }
+
     /// Make a move in a game using the Board API.
     /// The move can also contain a draw offer/agreement.
     ///
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn play_move(&self, game_id: &str, uci_move: &str, draw_offer: bool) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/move/{uci_move}"),
@@ -84,6 +117,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn chat_write(&self, game_id: &str, room: ChatRoom, text: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/board/game/{game_id}/chat"));
@@ -101,6 +136,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn chat_read(&self, game_id: &str) -> Result<Vec<ChatMessage>> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/board/game/{game_id}/chat"));
@@ -114,6 +151,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn game_abort(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/board/game/{game_id}/abort"));
@@ -127,6 +166,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn game_resign(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/resign"),
@@ -141,6 +182,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn handle_draws(&self, game_id: &str, accept: bool) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/draw/{accept}"),
@@ -155,6 +198,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn handle_takebacks(&self, game_id: &str, accept: bool) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/takeback/{accept}"),
@@ -169,6 +214,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn claim_victory(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/claim-victory"),
@@ -183,6 +230,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn claim_draw(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/claim-draw"),
@@ -199,6 +248,8 @@ impl BoardApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn berserk(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BoardPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/board/game/{game_id}/berserk"),
@@ -1,18 +1,23 @@
 use std::pin::Pin;
 
 use crate::{
-    client::{LicheszterInner, UrlBase},
+    client::{LicheszterInner, ResilientItem, UrlBase},
+    config::client::ReconnectOptions,
     error::Result,
     models::{
         board::BoardState,
         chat::{ChatMessage, ChatRoom},
+        oauth::Scope,
     },
 };
 
+#[cfg(feature = "position")]
+use crate::position::Position;
 use futures_util::Stream;
 use std::sync::Arc;
 
 /// A struct for accessing the Bot API endpoints.
+/// All endpoints require the authentication token to have the `bot:play` scope.
 #[derive(Debug)]
 pub struct BotApi {
     pub(crate) inner: Arc<LicheszterInner>,
@@ -27,6 +32,8 @@ impl BotApi {
         &self,
         game_id: &str,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<BoardState>> + Send>>> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bot/game/stream/{game_id}"));
@@ -35,12 +42,37 @@ impl BotApi {
         self.inner.to_stream::<BoardState>(builder).await
     }
 
+    /// Stream game state using the Bot API, reconnecting automatically when the connection is lost.
+    /// The full game state that Lichess sends again after reconnecting is skipped if the game has not progressed,
+    /// and [`ResilientItem::Reconnected`](enum@crate::client::ResilientItem) is emitted every time the stream is reopened.
+    /// The stream ends once the game is over.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn game_connect_resilient(
+        &self,
+        game_id: &str,
+        options: ReconnectOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ResilientItem<BoardState>>> + Send>>> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/bot/game/stream/{game_id}"));
+
+        self.inner
+            .to_resilient_stream::<BoardState>(url, options)
+            .await
+    }
+
     /// Make a move in a game using the Bot API.
     /// The move can also contain a draw offer/agreement.
     ///
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn play_move(&self, game_id: &str, uci_move: &str, draw_offer: bool) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bot/game/{game_id}/move/{uci_move}"),
@@ -54,11 +86,30 @@ impl BotApi {
         self.inner.execute(builder).await
     }
 
+    /// Make a move in a game using the Bot API, checking that it is legal in the given position before sending it.
+    /// The move can also contain a draw offer/agreement.
+    ///
+    /// # Errors
+    /// Returns an error if the move is illegal, the API request fails or the response cannot be deserialized.
+    #[cfg(feature = "position")]
+    pub async fn play_move_checked(
+        &self,
+        game_id: &str,
+        position: &Position,
+        uci_move: &str,
+        draw_offer: bool,
+    ) -> Result<()> {
+        let uci_move = position.uci(&position.parse_uci(uci_move)?);
+        self.play_move(game_id, &uci_move, draw_offer).await
+    }
+
     /// Post a message to the player or spectator chat using the Bot API.
     ///
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn chat_write(&self, game_id: &str, room: ChatRoom, text: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bot/game/{game_id}/chat"));
@@ -76,6 +127,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn chat_read(&self, game_id: &str) -> Result<Vec<ChatMessage>> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bot/game/{game_id}/chat"));
@@ -89,6 +142,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn game_abort(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bot/game/{game_id}/abort"));
@@ -102,6 +157,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn game_resign(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bot/game/{game_id}/resign"));
@@ -115,6 +172,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn handle_draws(&self, game_id: &str, accept: bool) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bot/game/{game_id}/draw/{accept}"),
@@ -129,6 +188,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn handle_takebacks(&self, game_id: &str, accept: bool) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bot/game/{game_id}/takeback/{accept}"),
@@ -143,6 +204,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn claim_victory(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bot/game/{game_id}/claim-victory"),
@@ -157,6 +220,8 @@ impl BotApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn claim_draw(&self, game_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::BotPlay)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bot/game/{game_id}/claim-draw"),
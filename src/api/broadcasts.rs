@@ -0,0 +1,344 @@
+use std::pin::Pin;
+
+use futures_util::Stream;
+use reqwest::header;
+
+use crate::{
+    client::{LicheszterInner, UrlBase},
+    config::broadcasts::{BroadcastOptions, BroadcastRoundOptions},
+    error::Result,
+    models::{
+        broadcast::{
+            BroadcastPaginator, BroadcastPushResult, BroadcastRound, BroadcastRoundWithTour,
+            BroadcastTop, BroadcastWithRounds,
+        },
+        oauth::Scope,
+    },
+};
+
+use std::sync::Arc;
+
+/// A struct for accessing the Broadcasts API endpoints.
+#[derive(Debug)]
+pub struct BroadcastsApi {
+    pub(crate) inner: Arc<LicheszterInner>,
+}
+
+impl BroadcastsApi {
+    /// Stream the official broadcasts, ongoing first, then upcoming, then finished.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn official(
+        &self,
+        nb: Option<u32>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<BroadcastWithRounds>> + Send>>> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/broadcast");
+        let mut builder = self.inner.client.get(url);
+
+        // Add the amount of broadcasts as a query parameter if it's present
+        if let Some(nb) = nb {
+            builder = builder.query(&[("nb", nb)]);
+        }
+
+        self.inner.to_stream::<BroadcastWithRounds>(builder).await
+    }
+
+    /// Get the top broadcasts, as shown on the Lichess broadcasts page.
+    /// The finished broadcasts are paginated.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn top(&self, page: Option<u32>) -> Result<BroadcastTop> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/broadcast/top");
+        let mut builder = self.inner.client.get(url);
+
+        // Add the page as a query parameter if it's present
+        if let Some(page) = page {
+            builder = builder.query(&[("page", page)]);
+        }
+
+        self.inner.to_model::<BroadcastTop>(builder).await
+    }
+
+    /// Get the broadcasts created by a user, paginated.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn by_user(&self, username: &str, page: Option<u32>) -> Result<BroadcastPaginator> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/broadcast/by/{username}"));
+        let mut builder = self.inner.client.get(url);
+
+        // Add the page as a query parameter if it's present
+        if let Some(page) = page {
+            builder = builder.query(&[("page", page)]);
+        }
+
+        self.inner.to_model::<BroadcastPaginator>(builder).await
+    }
+
+    /// Search for broadcasts by their name, paginated.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn search(&self, query: &str, page: Option<u32>) -> Result<BroadcastPaginator> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/broadcast/search");
+        let mut builder = self.inner.client.get(url).query(&[("q", query)]);
+
+        // Add the page as a query parameter if it's present
+        if let Some(page) = page {
+            builder = builder.query(&[("page", page)]);
+        }
+
+        self.inner.to_model::<BroadcastPaginator>(builder).await
+    }
+
+    /// Create a new broadcast tournament to relay external games.
+    /// The name of the tournament must be set.
+    /// Rounds can be added to the tournament with [`create_round()`](fn@BroadcastsApi::create_round).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn create(&self, options: &BroadcastOptions) -> Result<BroadcastWithRounds> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(UrlBase::Lichess, "broadcast/new");
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<BroadcastWithRounds>(builder).await
+    }
+
+    /// Get information about a broadcast tournament and its rounds.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn show(&self, tournament_id: &str) -> Result<BroadcastWithRounds> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/broadcast/{tournament_id}"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<BroadcastWithRounds>(builder).await
+    }
+
+    /// Update a broadcast tournament you created.
+    /// All fields are replaced, so the options that are not given are reset to their defaults.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn update(&self, tournament_id: &str, options: &BroadcastOptions) -> Result<()> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("broadcast/{tournament_id}/edit"));
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.execute(builder).await
+    }
+
+    /// Create a new round for a broadcast tournament you created.
+    /// The name of the round must be set.
+    /// If no sync source is set, the PGN of the round must be pushed with [`push_pgn()`](fn@BroadcastsApi::push_pgn).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn create_round(
+        &self,
+        tournament_id: &str,
+        options: &BroadcastRoundOptions,
+    ) -> Result<BroadcastRoundWithTour> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("broadcast/{tournament_id}/new"));
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<BroadcastRoundWithTour>(builder).await
+    }
+
+    /// Get information about a broadcast round and its games.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn round(
+        &self,
+        tournament_slug: &str,
+        round_slug: &str,
+        round_id: &str,
+    ) -> Result<BroadcastRound> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/broadcast/{tournament_slug}/{round_slug}/{round_id}"),
+        );
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<BroadcastRound>(builder).await
+    }
+
+    /// Update a broadcast round you created.
+    /// All fields are replaced, so the options that are not given are reset to their defaults.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn update_round(
+        &self,
+        round_id: &str,
+        options: &BroadcastRoundOptions,
+    ) -> Result<BroadcastRoundWithTour> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("broadcast/round/{round_id}/edit"),
+        );
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<BroadcastRoundWithTour>(builder).await
+    }
+
+    /// Remove all games of a broadcast round you created and reset it to its initial state.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn reset_round(&self, round_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/broadcast/round/{round_id}/reset"),
+        );
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Push the PGN of one or more games to a broadcast round you created.
+    /// The round must not have a sync source, and the games are matched by their tags to update existing games.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn push_pgn(&self, round_id: &str, pgn: &str) -> Result<BroadcastPushResult> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/broadcast/round/{round_id}/push"),
+        );
+        let builder = self
+            .inner
+            .client
+            .post(url)
+            .body(pgn.to_string())
+            .header(header::CONTENT_TYPE, "text/plain");
+
+        self.inner.to_model::<BroadcastPushResult>(builder).await
+    }
+
+    /// Stream the games of an ongoing broadcast round in PGN format.
+    /// The stream first contains all the games of the round, then the PGN of a game again whenever it is updated.
+    /// Each item of the stream is the PGN text of a single game, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn stream_round_pgn(
+        &self,
+        round_id: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/stream/broadcast/round/{round_id}.pgn"),
+        );
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_pgn_stream(builder).await
+    }
+
+    /// Download the games of a broadcast round in PGN format.
+    /// Each item of the stream is the PGN text of a single game, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export_round_pgn(
+        &self,
+        round_id: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/broadcast/round/{round_id}.pgn"),
+        );
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_pgn_stream(builder).await
+    }
+
+    /// Download the games of all rounds of a broadcast tournament in PGN format.
+    /// Each item of the stream is the PGN text of a single game, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export_pgn(
+        &self,
+        tournament_id: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/broadcast/{tournament_id}.pgn"),
+        );
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_pgn_stream(builder).await
+    }
+
+    /// Stream the broadcast rounds you are a member of, most recent first.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn my_rounds(
+        &self,
+        nb: Option<u32>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<BroadcastRoundWithTour>> + Send>>> {
+        self.inner.require_scope(Scope::StudyRead)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, "api/broadcast/my-rounds");
+        let mut builder = self.inner.client.get(url);
+
+        // Add the amount of rounds as a query parameter if it's present
+        if let Some(nb) = nb {
+            builder = builder.query(&[("nb", nb)]);
+        }
+
+        self.inner
+            .to_stream::<BroadcastRoundWithTour>(builder)
+            .await
+    }
+}
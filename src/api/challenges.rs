@@ -5,14 +5,17 @@ use reqwest::header;
 
 use crate::{
     client::{LicheszterInner, UrlBase},
-    config::challenges::{AIChallengeOptions, ChallengeOptions, OpenChallengeOptions},
+    config::challenges::{
+        AIChallengeOptions, ChallengeOptions, ChallengePolicy, OpenChallengeOptions,
+    },
     error::Result,
     models::{
         challenge::{
-            AIChallenge, Challenge, ChallengeComplete, ChallengeDeclineReason, Challenges,
-            OpenChallenge,
+            AIChallenge, Challenge, ChallengeComplete, ChallengeDecision, ChallengeDeclineReason,
+            Challenges, OpenChallenge,
         },
         game::AILevel,
+        oauth::Scope,
     },
 };
 
@@ -30,6 +33,8 @@ impl ChallengesApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn list(&self) -> Result<Challenges> {
+        self.inner.require_scope(Scope::ChallengeRead)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/challenge");
         let builder = self.inner.client.get(url);
 
@@ -47,6 +52,8 @@ impl ChallengesApi {
         username: &str,
         options: Option<&ChallengeOptions>,
     ) -> Result<Challenge> {
+        self.inner.require_scope(Scope::ChallengeWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/challenge/{username}"));
@@ -75,6 +82,8 @@ impl ChallengesApi {
         username: &str,
         options: Option<&ChallengeOptions>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<ChallengeComplete>> + Send>>> {
+        self.inner.require_scope(Scope::ChallengeWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/challenge/{username}"));
@@ -101,6 +110,8 @@ impl ChallengesApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn show(&self, challenge_id: &str) -> Result<Challenge> {
+        self.inner.require_scope(Scope::ChallengeRead)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/challenge/{challenge_id}/show"),
@@ -115,6 +126,9 @@ impl ChallengesApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn accept(&self, challenge_id: &str) -> Result<()> {
+        self.inner
+            .require_any_scope(&[Scope::ChallengeWrite, Scope::BotPlay, Scope::BoardPlay])?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/challenge/{challenge_id}/accept"),
@@ -133,6 +147,9 @@ impl ChallengesApi {
         challenge_id: &str,
         reason: Option<ChallengeDeclineReason>,
     ) -> Result<()> {
+        self.inner
+            .require_any_scope(&[Scope::ChallengeWrite, Scope::BotPlay, Scope::BoardPlay])?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/challenge/{challenge_id}/decline"),
@@ -146,12 +163,41 @@ impl ChallengesApi {
         self.inner.execute(builder).await
     }
 
+    /// Accept or decline an incoming challenge based on a decision.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn respond(&self, challenge_id: &str, decision: ChallengeDecision) -> Result<()> {
+        match decision {
+            ChallengeDecision::Accept => self.accept(challenge_id).await,
+            ChallengeDecision::Decline(reason) => self.decline(challenge_id, Some(reason)).await,
+        }
+    }
+
+    /// Evaluate an incoming challenge using a [`ChallengePolicy`] and accept or decline it accordingly.
+    /// Returns the decision that was applied.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn apply_policy(
+        &self,
+        challenge: &Challenge,
+        policy: &ChallengePolicy,
+        ongoing_games: usize,
+    ) -> Result<ChallengeDecision> {
+        let decision = policy.evaluate(challenge, ongoing_games);
+        self.respond(&challenge.id, decision).await?;
+        Ok(decision)
+    }
+
     /// Cancel a challenge you sent.
     /// Aborts the game if the challenge was accepted, but the game was not yet played.
     ///
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn cancel(&self, challenge_id: &str, opponent_token: Option<&str>) -> Result<()> {
+        self.inner.require_scope(Scope::ChallengeWrite)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/challenge/{challenge_id}/cancel"),
@@ -175,6 +221,8 @@ impl ChallengesApi {
         level: AILevel,
         options: Option<&AIChallengeOptions>,
     ) -> Result<AIChallenge> {
+        self.inner.require_scope(Scope::ChallengeWrite)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/challenge/ai");
         let mut builder = self.inner.client.post(url).form(&[("level", level as u8)]);
 
@@ -239,6 +287,8 @@ impl ChallengesApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn opponent_clock_increment(&self, game_id: &str, seconds: u32) -> Result<()> {
+        self.inner.require_scope(Scope::ChallengeWrite)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/round/{game_id}/add-time/{seconds}"),
@@ -0,0 +1,254 @@
+use std::{convert::Infallible, pin::Pin, time::Duration};
+
+use crate::{
+    client::{is_temporary, LicheszterInner, UrlBase},
+    config::{
+        external_engine::{AnalysisRequest, ExternalEngineOptions, WorkRequest},
+        uci::GoOptions,
+    },
+    error::{LichessError, Result},
+    models::{
+        external_engine::{EngineAnalysis, EngineJob, EngineVariant, EngineWork, ExternalEngine},
+        oauth::Scope,
+    },
+    uci::UciEngine,
+};
+
+use futures_util::{future, stream, Stream};
+use reqwest::Body;
+use std::sync::Arc;
+use tokio::{sync::mpsc, time::sleep};
+
+// How long the provider waits before acquiring work again after a temporary error, doubled for each
+// consecutive error
+const PROVIDER_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const PROVIDER_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// A struct for accessing the External engine API endpoints.
+///
+/// External engines are registered by users and run by a provider,
+/// which acquires analysis work from Lichess and streams the engine output back.
+/// [`ExternalEngineApi::run_provider()`] implements a provider on top of a local [`UciEngine`].
+#[derive(Debug)]
+pub struct ExternalEngineApi {
+    pub(crate) inner: Arc<LicheszterInner>,
+}
+
+impl ExternalEngineApi {
+    /// List all external engines registered for the logged in user.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn list(&self) -> Result<Vec<ExternalEngine>> {
+        self.inner.require_scope(Scope::EngineRead)?;
+
+        let url = self.inner.req_url(UrlBase::Lichess, "api/external-engine");
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<Vec<ExternalEngine>>(builder).await
+    }
+
+    /// Register a new external engine for the logged in user.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn create(&self, options: &ExternalEngineOptions) -> Result<ExternalEngine> {
+        self.inner.require_scope(Scope::EngineWrite)?;
+
+        let url = self.inner.req_url(UrlBase::Lichess, "api/external-engine");
+        let builder = self.inner.client.post(url).json(options);
+
+        self.inner.to_model::<ExternalEngine>(builder).await
+    }
+
+    /// Get the details of an external engine.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn show(&self, engine_id: &str) -> Result<ExternalEngine> {
+        self.inner.require_scope(Scope::EngineRead)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/external-engine/{engine_id}"),
+        );
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<ExternalEngine>(builder).await
+    }
+
+    /// Update the properties of an external engine.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn update(
+        &self,
+        engine_id: &str,
+        options: &ExternalEngineOptions,
+    ) -> Result<ExternalEngine> {
+        self.inner.require_scope(Scope::EngineWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/external-engine/{engine_id}"),
+        );
+        let builder = self.inner.client.put(url).json(options);
+
+        self.inner.to_model::<ExternalEngine>(builder).await
+    }
+
+    /// Unregister an external engine.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails.
+    pub async fn delete(&self, engine_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::EngineWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/external-engine/{engine_id}"),
+        );
+        let builder = self.inner.client.delete(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Request analysis from an external engine, authenticated by the client secret of the engine.
+    /// The stream ends once the search finishes, and the search is stopped when the stream is dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn analyse(
+        &self,
+        engine_id: &str,
+        client_secret: &str,
+        work: &EngineWork,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<EngineAnalysis>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Engine,
+            &format!("api/external-engine/{engine_id}/analyse"),
+        );
+        let builder = self.inner.client.post(url).json(&AnalysisRequest {
+            client_secret: client_secret.to_string(),
+            work: work.clone(),
+        });
+
+        self.inner.to_stream::<EngineAnalysis>(builder).await
+    }
+
+    /// Wait for an analysis request for any engine registered with the provider secret.
+    /// Returns `None` if no work became available before the long poll timed out.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn acquire_work(&self, provider_secret: &str) -> Result<Option<EngineJob>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Engine, "api/external-engine/work");
+        let builder = self.inner.client.post(url).json(&WorkRequest {
+            provider_secret: provider_secret.to_string(),
+        });
+
+        self.inner.to_optional_model::<EngineJob>(builder).await
+    }
+
+    /// Run an acquired analysis request on the local engine, streaming the `info` lines of the engine to Lichess.
+    /// The search is stopped as soon as Lichess no longer needs the analysis.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails or the API request fails.
+    pub async fn submit_work(&self, job: &EngineJob, engine: &mut UciEngine) -> Result<()> {
+        let work = &job.work;
+        engine.set_option("Threads", work.threads).await?;
+        engine.set_option("Hash", work.hash).await?;
+        engine.set_option("MultiPV", work.multi_pv).await?;
+        if job
+            .engine
+            .variants
+            .iter()
+            .any(|v| *v != EngineVariant::Chess)
+        {
+            engine.set_option("UCI_Variant", work.variant).await?;
+        }
+        // Lichess sends castling moves as the king capturing the rook in all variants
+        engine.set_chess960(true).await?;
+
+        let mut options = GoOptions::new().infinite(work.infinite);
+        if let Some(movetime) = work.movetime {
+            options = options.movetime(movetime);
+        }
+        if let Some(depth) = work.depth {
+            options = options.depth(depth);
+        }
+        if let Some(nodes) = work.nodes {
+            options = options.nodes(nodes);
+        }
+
+        // Stream the lines written by the engine as the request body
+        let (sender, mut receiver) = mpsc::unbounded_channel::<String>();
+        let lines = stream::poll_fn(move |cx| {
+            receiver
+                .poll_recv(cx)
+                .map(|line| line.map(Ok::<_, Infallible>))
+        });
+
+        let url = self.inner.req_url(
+            UrlBase::Engine,
+            &format!("api/external-engine/work/{}", job.id),
+        );
+        let builder = self.inner.client.post(url).body(Body::wrap_stream(lines));
+
+        // The body ends when the sender is dropped after the search
+        let analysis = async move {
+            let moves = work.moves.join(" ");
+            engine
+                .analyse(&work.initial_fen, &moves, &options, |line| {
+                    sender.send(format!("{line}\n")).is_ok()
+                })
+                .await
+        };
+        let (submitted, analysed) = future::join(self.inner.execute_empty(builder), analysis).await;
+
+        analysed?;
+        submitted
+    }
+
+    /// Serve analysis requests for all engines registered with the provider secret using the local engine.
+    /// Requests are handled one at a time, and the engine is told about a new game whenever the analysis session changes.
+    /// Errors from submitting the analysis are ignored, as Lichess closes the connection once the analysis is no longer needed.
+    /// Network errors, server errors and rate limits while acquiring work are waited out with an increasing delay.
+    ///
+    /// # Errors
+    /// Returns an error if Lichess rejects acquiring work or communicating with the engine fails.
+    pub async fn run_provider(&self, provider_secret: &str, engine: &mut UciEngine) -> Result<()> {
+        let mut session = None;
+        let mut backoff = PROVIDER_INITIAL_BACKOFF;
+        loop {
+            let job = match self.acquire_work(provider_secret).await {
+                Ok(job) => job,
+                Err(e) if is_temporary(&e) => {
+                    let delay = e.lichess().and_then(LichessError::retry_after);
+                    sleep(delay.unwrap_or(backoff)).await;
+                    backoff = (backoff * 2).min(PROVIDER_MAX_BACKOFF);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            backoff = PROVIDER_INITIAL_BACKOFF;
+
+            let Some(job) = job else {
+                continue;
+            };
+
+            if session.as_ref() != Some(&job.work.session_id) {
+                engine.new_game().await?;
+                session = Some(job.work.session_id.clone());
+            }
+
+            match self.submit_work(&job, engine).await {
+                Err(e) if e.is_reqwest() || e.is_lichess() => continue,
+                result => result?,
+            }
+        }
+    }
+}
@@ -100,6 +100,35 @@ impl GamesApi {
         self.inner.to_stream::<Game>(builder).await
     }
 
+    /// Download all games of any user in PGN format.
+    /// Each item of the stream is the PGN text of a single game, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    /// By default, games are delivered in reverse chronological order (most recent first).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export_user_pgn(
+        &self,
+        username: &str,
+        options: Option<&ExtendedGameOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let mut url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/games/user/{username}"));
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self
+            .inner
+            .client
+            .get(url)
+            .header(header::ACCEPT, "application/x-chess-pgn");
+        self.inner.to_pgn_stream(builder).await
+    }
+
     /// Download games by IDs.
     /// Games are delivered in reverse chronological order (most recent first).
     /// Up to 300 game IDs can be submitted at a time.
@@ -131,6 +160,37 @@ impl GamesApi {
         self.inner.to_stream::<Game>(builder).await
     }
 
+    /// Download games by IDs in PGN format.
+    /// Each item of the stream is the PGN text of a single game, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    /// Games are delivered in reverse chronological order (most recent first).
+    /// Up to 300 game IDs can be submitted at a time.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export_pgn(
+        &self,
+        game_ids: &[&str],
+        options: Option<&GameOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let mut url = self
+            .inner
+            .req_url(UrlBase::Lichess, "api/games/export/_ids");
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self
+            .inner
+            .client
+            .post(url)
+            .header(header::ACCEPT, "application/x-chess-pgn")
+            .body(game_ids.join(","));
+        self.inner.to_pgn_stream(builder).await
+    }
+
     /// Stream the games played between a list of users in real time.
     /// Only games where both players are part of the list are included.
     /// The stream emits an event each time a game is started or finished.
@@ -1,6 +1,7 @@
 use crate::{
     client::{LicheszterInner, UrlBase},
     error::Result,
+    models::oauth::Scope,
 };
 
 use std::sync::Arc;
@@ -17,6 +18,8 @@ impl MessagingApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn private_send(&self, username: &str, text: &str) -> Result<()> {
+        self.inner.require_scope(Scope::MsgWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("/inbox/{username}"));
@@ -1,12 +1,17 @@
 #[cfg(feature = "streaming")]
 use std::pin::Pin;
 
+use std::collections::BTreeMap;
+
 use crate::{
     client::{Licheszter, UrlBase},
     error::Result,
-    models::{board::Event, user::BasicUser},
+    models::{board::Event, oauth::TokenInfo, user::BasicUser},
 };
 
+#[cfg(feature = "streaming")]
+use crate::{client::ResilientItem, config::client::ReconnectOptions};
+
 #[cfg(feature = "streaming")]
 use futures_util::Stream;
 
@@ -26,6 +31,22 @@ impl Licheszter {
         self.inner.to_stream::<Event>(builder).await
     }
 
+    /// Stream the events reaching a Lichess user in real time, reconnecting automatically when the connection is lost.
+    /// Challenges and games that Lichess sends again after reconnecting are skipped,
+    /// and [`ResilientItem::Reconnected`](enum@crate::client::ResilientItem) is emitted every time the stream is reopened.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    #[cfg(feature = "streaming")]
+    pub async fn connect_resilient(
+        &self,
+        options: ReconnectOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ResilientItem<Event>>> + Send>>> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/stream/event");
+
+        self.inner.to_resilient_stream::<Event>(url, options).await
+    }
+
     /// Get online bots.
     ///
     /// # Errors
@@ -67,4 +88,27 @@ impl Licheszter {
 
         self.inner.execute(builder).await
     }
+
+    /// Test the validity of multiple OAuth tokens at once.
+    /// The result maps each token to its scopes, owner and expiration time, or `None` if the token is invalid.
+    /// Up to 1000 tokens can be tested at once.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn test_tokens(
+        &self,
+        tokens: &[&str],
+    ) -> Result<BTreeMap<String, Option<TokenInfo>>> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/token/test");
+        let builder = self
+            .inner
+            .client
+            .post(url)
+            .body(tokens.join(","))
+            .header(header::CONTENT_TYPE, "text/plain");
+
+        self.inner
+            .to_model::<BTreeMap<String, Option<TokenInfo>>>(builder)
+            .await
+    }
 }
@@ -4,9 +4,15 @@ pub mod account;
 #[cfg(feature = "analysis")]
 pub mod analysis;
 
+#[cfg(feature = "broadcasts")]
+pub mod broadcasts;
+
 #[cfg(feature = "challenges")]
 pub mod challenges;
 
+#[cfg(feature = "external_engine")]
+pub mod external_engine;
+
 #[cfg(feature = "fide")]
 pub mod fide;
 
@@ -30,6 +36,18 @@ pub mod relations;
 #[cfg(feature = "simuls")]
 pub mod simuls;
 
+#[cfg(feature = "studies")]
+pub mod studies;
+
+#[cfg(feature = "swiss")]
+pub mod swiss;
+
+#[cfg(feature = "teams")]
+pub mod teams;
+
+#[cfg(feature = "tournaments")]
+pub mod tournaments;
+
 #[cfg(feature = "tv")]
 pub mod tv;
 
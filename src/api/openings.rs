@@ -1,17 +1,21 @@
-use std::pin::Pin;
+use std::{cmp::Reverse, collections::VecDeque, io, pin::Pin};
 
 use crate::{
     client::{LicheszterInner, UrlBase},
-    config::openings::{LichessOpeningsOptions, MastersOpeningsOptions, PlayerOpeningsOptions},
+    config::openings::{
+        CrawlOptions, ExplorerSource, LichessOpeningsOptions, MastersOpeningsOptions,
+        PlayerOpeningsOptions,
+    },
     error::Result,
     models::{
         common::Color,
-        openings::{Opening, PlayerOpening},
+        openings::{Opening, OpeningNode, OpeningTree, PlayerOpening},
     },
 };
 
-use futures_util::Stream;
+use futures_util::{Stream, StreamExt};
 use std::sync::Arc;
+use tokio::time::sleep;
 
 /// A struct for accessing the Openings API endpoints.
 #[derive(Debug)]
@@ -92,4 +96,122 @@ impl OpeningsApi {
 
         self.inner.to_string(builder).await
     }
+
+    /// Crawl the opening explorer from a root position, building a tree of the move statistics.
+    ///
+    /// Positions are expanded breadth first, one request at a time, until the maximum depth is reached.
+    /// Moves played in too few games are pruned along with everything after them.
+    /// Rate limited requests are only retried if rate limit handling is enabled with
+    /// [`with_rate_limit()`](fn@crate::client::LicheszterBuilder::with_rate_limit), which is recommended for large crawls.
+    ///
+    /// # Errors
+    /// Returns an error if any API request fails or a response cannot be deserialized.
+    pub async fn crawl(
+        &self,
+        source: &ExplorerSource,
+        options: &CrawlOptions,
+    ) -> Result<OpeningTree> {
+        let mut root = self.explore(source, options, &[]).await?;
+        let mut tree = OpeningTree {
+            fen: options.root_fen().map(str::to_string),
+            white: root.white,
+            black: root.black,
+            draws: root.draws,
+            opening: root.opening.take(),
+            children: Vec::new(),
+        };
+        if options.expands(0) {
+            tree.children = Self::prune(root, options);
+        }
+
+        // Walk the tree level by level, addressing every node by the indices of its ancestors
+        let mut queue: VecDeque<Vec<usize>> = (0..tree.children.len()).map(|i| vec![i]).collect();
+        while let Some(path) = queue.pop_front() {
+            if !options.expands(path.len()) {
+                continue;
+            }
+
+            let mut nodes = &tree.children;
+            let mut play = Vec::with_capacity(path.len());
+            for &i in &path {
+                play.push(nodes[i].stats.uci.clone());
+                nodes = &nodes[i].children;
+            }
+            let play: Vec<&str> = play.iter().map(String::as_str).collect();
+
+            sleep(options.request_delay()).await;
+            let opening = self.explore(source, options, &play).await?;
+
+            let mut node = &mut tree.children[path[0]];
+            for &i in &path[1..] {
+                node = &mut node.children[i];
+            }
+            node.children = Self::prune(opening, options);
+
+            queue.extend((0..node.children.len()).map(|i| {
+                let mut child = path.clone();
+                child.push(i);
+                child
+            }));
+        }
+
+        Ok(tree)
+    }
+
+    // Look up a single position of the crawled tree
+    async fn explore(
+        &self,
+        source: &ExplorerSource,
+        options: &CrawlOptions,
+        play: &[&str],
+    ) -> Result<Opening> {
+        match source.at(options.root_fen(), play) {
+            ExplorerSource::Masters(options) => self.masters(Some(&options)).await,
+            ExplorerSource::Lichess(options) => self.lichess(Some(&options)).await,
+            ExplorerSource::Player {
+                player,
+                color,
+                options,
+            } => self.player_complete(&player, color, &options).await,
+        }
+    }
+
+    // Wait for the player opening stream to finish indexing, returning the complete result
+    async fn player_complete(
+        &self,
+        player: &str,
+        color: Color,
+        options: &PlayerOpeningsOptions,
+    ) -> Result<Opening> {
+        let mut stream = self.player(player, color, Some(options)).await?;
+        let mut last = None;
+        while let Some(opening) = stream.next().await {
+            last = Some(opening?);
+        }
+
+        last.map(Opening::from).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "player opening stream ended without results",
+            )
+            .into()
+        })
+    }
+
+    // Keep the moves of a position that are played often enough, most played first
+    fn prune(opening: Opening, options: &CrawlOptions) -> Vec<OpeningNode> {
+        let games = u64::from(opening.white) + u64::from(opening.black) + u64::from(opening.draws);
+        let mut moves = opening.moves;
+        moves.sort_by_key(|m| Reverse(m.games()));
+
+        moves
+            .into_iter()
+            .filter(|m| options.keeps(m.games(), games))
+            .take(options.move_limit())
+            .map(|stats| OpeningNode {
+                stats,
+                children: Vec::new(),
+            })
+            .collect()
+    }
 }
@@ -9,6 +9,7 @@ use crate::{
     error::Result,
     models::{
         game::Game,
+        oauth::Scope,
         pairings::{BulkPairing, BulkPairings},
     },
 };
@@ -27,6 +28,8 @@ impl BulkPairingsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn list(&self) -> Result<Vec<BulkPairing>> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/bulk-pairing");
         let builder = self.inner.client.get(url);
 
@@ -43,6 +46,8 @@ impl BulkPairingsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn create(&self, options: &BulkPairingOptions) -> Result<BulkPairing> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/bulk-pairing");
         let mut builder = self.inner.client.post(url);
 
@@ -62,6 +67,8 @@ impl BulkPairingsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn clocks_start(&self, bulk_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
         let url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bulk-pairing/{bulk_id}/start-clocks"),
@@ -76,6 +83,8 @@ impl BulkPairingsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn show(&self, bulk_id: &str) -> Result<BulkPairing> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bulk-pairing/{bulk_id}"));
@@ -90,6 +99,8 @@ impl BulkPairingsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn cancel(&self, bulk_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/bulk-pairing/{bulk_id}"));
@@ -107,6 +118,8 @@ impl BulkPairingsApi {
         bulk_id: &str,
         options: Option<&GameOptions>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<Game>> + Send>>> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
         let mut url = self.inner.req_url(
             UrlBase::Lichess,
             &format!("api/bulk-pairing/{bulk_id}/games"),
@@ -125,4 +138,35 @@ impl BulkPairingsApi {
             .header(header::ACCEPT, "application/x-ndjson");
         self.inner.to_stream::<Game>(builder).await
     }
+
+    /// Download games of a bulk pairing in PGN format.
+    /// Each item of the stream is the PGN text of a single game, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export_pgn(
+        &self,
+        bulk_id: &str,
+        options: Option<&GameOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        self.inner.require_scope(Scope::ChallengeBulk)?;
+
+        let mut url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/bulk-pairing/{bulk_id}/games"),
+        );
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self
+            .inner
+            .client
+            .get(url)
+            .header(header::ACCEPT, "application/x-chess-pgn");
+        self.inner.to_pgn_stream(builder).await
+    }
 }
@@ -5,7 +5,7 @@ use futures_util::Stream;
 use crate::{
     client::{LicheszterInner, UrlBase},
     error::Result,
-    models::user::User,
+    models::{oauth::Scope, user::User},
 };
 
 use std::sync::Arc;
@@ -24,6 +24,8 @@ impl RelationsApi {
     pub async fn followed_users_list(
         &self,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<User>> + Send>>> {
+        self.inner.require_scope(Scope::FollowRead)?;
+
         let url = self.inner.req_url(UrlBase::Lichess, "api/rel/following");
         let builder = self.inner.client.get(url);
 
@@ -35,6 +37,8 @@ impl RelationsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn follow(&self, username: &str) -> Result<()> {
+        self.inner.require_scope(Scope::FollowWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/rel/follow/{username}"));
@@ -48,6 +52,8 @@ impl RelationsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn unfollow(&self, username: &str) -> Result<()> {
+        self.inner.require_scope(Scope::FollowWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/rel/unfollow/{username}"));
@@ -61,6 +67,8 @@ impl RelationsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn block(&self, username: &str) -> Result<()> {
+        self.inner.require_scope(Scope::FollowWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/rel/block/{username}"));
@@ -74,6 +82,8 @@ impl RelationsApi {
     /// # Errors
     /// Returns an error if the API request fails or the response cannot be deserialized.
     pub async fn unblock(&self, username: &str) -> Result<()> {
+        self.inner.require_scope(Scope::FollowWrite)?;
+
         let url = self
             .inner
             .req_url(UrlBase::Lichess, &format!("api/rel/unblock/{username}"));
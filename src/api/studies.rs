@@ -0,0 +1,203 @@
+use std::pin::Pin;
+
+use futures_util::Stream;
+use reqwest::header;
+
+use crate::{
+    client::{LicheszterInner, UrlBase},
+    config::studies::{StudyExportOptions, StudyImportOptions},
+    error::Result,
+    models::{
+        oauth::Scope,
+        study::{StudyImport, StudyMetadata},
+    },
+};
+
+use std::sync::Arc;
+
+/// A struct for accessing the Studies API endpoints.
+#[derive(Debug)]
+pub struct StudiesApi {
+    pub(crate) inner: Arc<LicheszterInner>,
+}
+
+impl StudiesApi {
+    /// Download a single chapter of a study in PGN format.
+    /// # NOTE:
+    /// This method does NOT deserialize the PGN data, it can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be read.
+    pub async fn export_chapter(
+        &self,
+        study_id: &str,
+        chapter_id: &str,
+        options: Option<&StudyExportOptions>,
+    ) -> Result<String> {
+        let mut url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/study/{study_id}/{chapter_id}.pgn"),
+        );
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self.inner.client.get(url);
+        self.inner.to_string(builder).await
+    }
+
+    /// Download all chapters of a study in PGN format.
+    /// Each item of the stream is the PGN text of a single chapter, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export(
+        &self,
+        study_id: &str,
+        options: Option<&StudyExportOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let mut url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/study/{study_id}.pgn"));
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self.inner.client.get(url);
+        self.inner.to_pgn_stream(builder).await
+    }
+
+    /// Download all chapters of all studies of a user in PGN format.
+    /// Private studies are only included if the authenticated user is the owner.
+    /// Each item of the stream is the PGN text of a single chapter, which can be parsed with [`PgnGame::parse()`](fn@crate::pgn::PgnGame::parse).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn export_by_user(
+        &self,
+        username: &str,
+        options: Option<&StudyExportOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        let mut url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("study/by/{username}/export.pgn"));
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self.inner.client.get(url);
+        self.inner.to_pgn_stream(builder).await
+    }
+
+    /// Stream the metadata of the studies of a user.
+    /// Private studies are only included if the authenticated user is the owner.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn list_by_user(
+        &self,
+        username: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StudyMetadata>> + Send>>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/study/by/{username}"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_stream::<StudyMetadata>(builder).await
+    }
+
+    /// Import PGN into a study you can contribute to.
+    /// Each game of the PGN creates a new chapter, up to 64 chapters per study.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn import_pgn(
+        &self,
+        study_id: &str,
+        pgn: &str,
+        options: Option<&StudyImportOptions>,
+    ) -> Result<StudyImport> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/study/{study_id}/import-pgn"),
+        );
+        let mut encoded = comma_serde_urlencoded::to_string([("pgn", pgn)])?;
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let options = comma_serde_urlencoded::to_string(options)?;
+            if !options.is_empty() {
+                encoded.push('&');
+                encoded.push_str(&options);
+            }
+        }
+
+        let builder = self
+            .inner
+            .client
+            .post(url)
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+        self.inner.to_model::<StudyImport>(builder).await
+    }
+
+    /// Add, update or remove the PGN tags of a study chapter you can contribute to.
+    /// Tags with an empty value are removed from the chapter.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails.
+    pub async fn update_tags(
+        &self,
+        study_id: &str,
+        chapter_id: &str,
+        tags: &[(&str, &str)],
+    ) -> Result<()> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/study/{study_id}/{chapter_id}/tags"),
+        );
+
+        // Write the tags in the PGN tag pair format
+        let pgn = tags
+            .iter()
+            .map(|(name, value)| {
+                let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+                format!("[{name} \"{value}\"]")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let builder = self.inner.client.post(url).form(&[("pgn", pgn)]);
+        self.inner.execute_empty(builder).await
+    }
+
+    /// Delete a chapter of a study you own.
+    /// If the study only has one chapter, the chapter is emptied instead.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails.
+    pub async fn delete_chapter(&self, study_id: &str, chapter_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::StudyWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/study/{study_id}/{chapter_id}"),
+        );
+        let builder = self.inner.client.delete(url);
+
+        self.inner.execute_empty(builder).await
+    }
+}
@@ -0,0 +1,245 @@
+use std::pin::Pin;
+
+use futures_util::Stream;
+use reqwest::header;
+
+use crate::{
+    client::{LicheszterInner, UrlBase},
+    config::{games::GameOptions, swiss::SwissOptions},
+    error::Result,
+    models::{
+        game::Game,
+        oauth::Scope,
+        swiss::{SwissResult, SwissStatus, SwissTournament},
+    },
+};
+
+use std::sync::Arc;
+
+/// A struct for accessing the Swiss Tournaments API endpoints.
+#[derive(Debug)]
+pub struct SwissApi {
+    pub(crate) inner: Arc<LicheszterInner>,
+}
+
+impl SwissApi {
+    /// Create a Swiss tournament for a team you lead.
+    /// The clock settings and the number of rounds must be set.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn create(&self, team_id: &str, options: &SwissOptions) -> Result<SwissTournament> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/new/{team_id}"));
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<SwissTournament>(builder).await
+    }
+
+    /// Get detailed information about a Swiss tournament.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn show(&self, swiss_id: &str) -> Result<SwissTournament> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<SwissTournament>(builder).await
+    }
+
+    /// Update a Swiss tournament you created.
+    /// Be mindful not to make important changes to ongoing tournaments.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn edit(&self, swiss_id: &str, options: &SwissOptions) -> Result<SwissTournament> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}/edit"));
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<SwissTournament>(builder).await
+    }
+
+    /// Manually schedule the next round of a Swiss tournament you created.
+    /// The timestamp is in MILLISECONDS.
+    /// This is only useful when the round interval of the tournament is set to manual scheduling.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn schedule_next_round(&self, swiss_id: &str, timestamp: u64) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/swiss/{swiss_id}/schedule-next-round"),
+        );
+        let builder = self.inner.client.post(url).form(&[("date", timestamp)]);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Join a Swiss tournament, possibly with a password.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn join(&self, swiss_id: &str, password: Option<&str>) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}/join"));
+        let mut builder = self.inner.client.post(url);
+
+        // Add the password to the request if it's present
+        if let Some(password) = password {
+            builder = builder.form(&[("password", password)]);
+        }
+
+        self.inner.execute(builder).await
+    }
+
+    /// Leave a future Swiss tournament, or take a break from an ongoing one.
+    /// It can be resumed later by joining the tournament again.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn withdraw(&self, swiss_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}/withdraw"));
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Terminate a Swiss tournament you created.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn terminate(&self, swiss_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}/terminate"));
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Download a Swiss tournament in the Tournament Report File format, the FIDE standard.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be read.
+    pub async fn export_trf(&self, swiss_id: &str) -> Result<String> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("swiss/{swiss_id}.trf"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_string(builder).await
+    }
+
+    /// Download games of a Swiss tournament.
+    /// Games are sorted by chronological order.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn games(
+        &self,
+        swiss_id: &str,
+        options: Option<&GameOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Game>> + Send>>> {
+        let mut url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}/games"));
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self
+            .inner
+            .client
+            .get(url)
+            .header(header::ACCEPT, "application/x-ndjson");
+        self.inner.to_stream::<Game>(builder).await
+    }
+
+    /// Stream the results of a Swiss tournament.
+    /// Results are the players sorted by rank.
+    /// Ongoing tournaments are also supported, with live results.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn results(
+        &self,
+        swiss_id: &str,
+        nb: Option<u32>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<SwissResult>> + Send>>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/swiss/{swiss_id}/results"));
+        let mut builder = self.inner.client.get(url);
+
+        // Add the amount of players as a query parameter if it's present
+        if let Some(nb) = nb {
+            builder = builder.query(&[("nb", nb)]);
+        }
+
+        self.inner.to_stream::<SwissResult>(builder).await
+    }
+
+    /// Stream the Swiss tournaments of a team, most recent first.
+    /// The tournaments can be filtered by their status.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn team(
+        &self,
+        team_id: &str,
+        max: Option<u32>,
+        status: Option<SwissStatus>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<SwissTournament>> + Send>>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/{team_id}/swiss"));
+        let mut builder = self.inner.client.get(url);
+
+        // Add the maximum amount of tournaments as a query parameter if it's present
+        if let Some(max) = max {
+            builder = builder.query(&[("max", max)]);
+        }
+
+        // Add the status as a query parameter if it's present
+        if let Some(status) = status {
+            builder = builder.query(&[("status", status)]);
+        }
+
+        self.inner.to_stream::<SwissTournament>(builder).await
+    }
+}
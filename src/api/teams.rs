@@ -0,0 +1,276 @@
+use std::pin::Pin;
+
+use futures_util::Stream;
+
+use crate::{
+    client::{LicheszterInner, UrlBase},
+    error::Result,
+    models::{
+        oauth::Scope,
+        team::{Team, TeamMember, TeamPaginator, TeamRequest},
+    },
+};
+
+#[cfg(feature = "swiss")]
+use crate::models::swiss::{SwissStatus, SwissTournament};
+
+#[cfg(feature = "tournaments")]
+use crate::models::tournament::ArenaTournament;
+
+use std::sync::Arc;
+
+/// A struct for accessing the Teams API endpoints.
+#[derive(Debug)]
+pub struct TeamsApi {
+    pub(crate) inner: Arc<LicheszterInner>,
+}
+
+impl TeamsApi {
+    /// Get public information about a team.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn show(&self, team_id: &str) -> Result<Team> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/{team_id}"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<Team>(builder).await
+    }
+
+    /// Get the most popular teams, paginated.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn popular(&self, page: Option<u32>) -> Result<TeamPaginator> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/team/all");
+        let mut builder = self.inner.client.get(url);
+
+        // Add the page as a query parameter if it's present
+        if let Some(page) = page {
+            builder = builder.query(&[("page", page)]);
+        }
+
+        self.inner.to_model::<TeamPaginator>(builder).await
+    }
+
+    /// Get all the teams a user is a member of.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn of_user(&self, username: &str) -> Result<Vec<Team>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/of/{username}"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<Vec<Team>>(builder).await
+    }
+
+    /// Search for teams by their name or description, paginated.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn search(&self, text: &str, page: Option<u32>) -> Result<TeamPaginator> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/team/search");
+        let mut builder = self.inner.client.get(url).query(&[("text", text)]);
+
+        // Add the page as a query parameter if it's present
+        if let Some(page) = page {
+            builder = builder.query(&[("page", page)]);
+        }
+
+        self.inner.to_model::<TeamPaginator>(builder).await
+    }
+
+    /// Stream the members of a team, most recent first.
+    /// Private teams require the authenticated user to be a member.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn members(
+        &self,
+        team_id: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<TeamMember>> + Send>>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/{team_id}/users"));
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_stream::<TeamMember>(builder).await
+    }
+
+    /// Join a team.
+    /// If the team requires a join request, a message must be given, and the request is sent to the team leaders.
+    /// A password can be given to join a team that requires one.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn join(
+        &self,
+        team_id: &str,
+        message: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<()> {
+        self.inner.require_scope(Scope::TeamWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("team/{team_id}/join"));
+        let builder = self
+            .inner
+            .client
+            .post(url)
+            .form(&(("message", message), ("password", password)));
+
+        self.inner.execute(builder).await
+    }
+
+    /// Leave a team.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn leave(&self, team_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TeamWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("team/{team_id}/quit"));
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Get the pending join requests of a team you lead.
+    /// Declined join requests can be fetched instead.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn requests(&self, team_id: &str, declined: bool) -> Result<Vec<TeamRequest>> {
+        self.inner.require_scope(Scope::TeamRead)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/{team_id}/requests"));
+        let builder = self.inner.client.get(url).query(&[("declined", declined)]);
+
+        self.inner.to_model::<Vec<TeamRequest>>(builder).await
+    }
+
+    /// Accept a join request to a team you lead.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn accept_request(&self, team_id: &str, user_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TeamLead)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/team/{team_id}/request/{user_id}/accept"),
+        );
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Decline a join request to a team you lead.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn decline_request(&self, team_id: &str, user_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TeamLead)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/team/{team_id}/request/{user_id}/decline"),
+        );
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Kick a member out of a team you lead.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn kick(&self, team_id: &str, user_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TeamLead)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/team/{team_id}/kick/{user_id}"),
+        );
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Send a private message to all members of a team you lead.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn message_all(&self, team_id: &str, message: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TeamLead)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("team/{team_id}/pm-all"));
+        let builder = self.inner.client.post(url).form(&[("message", message)]);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Stream the arena tournaments of a team, most recent first.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    #[cfg(feature = "tournaments")]
+    pub async fn arenas(
+        &self,
+        team_id: &str,
+        max: Option<u32>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ArenaTournament>> + Send>>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/{team_id}/arena"));
+        let mut builder = self.inner.client.get(url);
+
+        // Add the maximum amount of tournaments as a query parameter if it's present
+        if let Some(max) = max {
+            builder = builder.query(&[("max", max)]);
+        }
+
+        self.inner.to_stream::<ArenaTournament>(builder).await
+    }
+
+    /// Stream the Swiss tournaments of a team, most recent first.
+    /// The tournaments can be filtered by their status.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    #[cfg(feature = "swiss")]
+    pub async fn swisses(
+        &self,
+        team_id: &str,
+        max: Option<u32>,
+        status: Option<SwissStatus>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<SwissTournament>> + Send>>> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/team/{team_id}/swiss"));
+        let mut builder = self.inner.client.get(url);
+
+        // Add the maximum amount of tournaments as a query parameter if it's present
+        if let Some(max) = max {
+            builder = builder.query(&[("max", max)]);
+        }
+
+        // Add the status as a query parameter if it's present
+        if let Some(status) = status {
+            builder = builder.query(&[("status", status)]);
+        }
+
+        self.inner.to_stream::<SwissTournament>(builder).await
+    }
+}
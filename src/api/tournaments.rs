@@ -0,0 +1,316 @@
+use std::pin::Pin;
+
+use futures_util::Stream;
+use reqwest::header;
+
+use crate::{
+    client::{LicheszterInner, UrlBase},
+    config::{
+        games::GameOptions,
+        tournaments::{ArenaJoinOptions, ArenaTournamentOptions},
+    },
+    error::Result,
+    models::{
+        game::Game,
+        oauth::Scope,
+        tournament::{
+            ArenaResult, ArenaStatus, ArenaTeamStanding, ArenaTournament, ArenaTournamentFull,
+            ArenaTournaments, PlayedArenaTournament,
+        },
+    },
+};
+
+use std::sync::Arc;
+
+/// A struct for accessing the Arena Tournaments API endpoints.
+#[derive(Debug)]
+pub struct TournamentsApi {
+    pub(crate) inner: Arc<LicheszterInner>,
+}
+
+impl TournamentsApi {
+    /// Get recently active and finished arena tournaments.
+    /// This API is used to display the Lichess tournament schedule.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn current(&self) -> Result<ArenaTournaments> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/tournament");
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<ArenaTournaments>(builder).await
+    }
+
+    /// Create a public or private arena tournament.
+    /// The clock settings and the duration of the tournament must be set.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn create(&self, options: &ArenaTournamentOptions) -> Result<ArenaTournamentFull> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self.inner.req_url(UrlBase::Lichess, "api/tournament");
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<ArenaTournamentFull>(builder).await
+    }
+
+    /// Get detailed information about an arena tournament.
+    /// The standing of the players can be paginated by giving a page number.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn show(
+        &self,
+        tournament_id: &str,
+        page: Option<u16>,
+    ) -> Result<ArenaTournamentFull> {
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/tournament/{tournament_id}"));
+        let mut builder = self.inner.client.get(url);
+
+        // Add the page as a query parameter if it's present
+        if let Some(page) = page {
+            builder = builder.query(&[("page", page)]);
+        }
+
+        self.inner.to_model::<ArenaTournamentFull>(builder).await
+    }
+
+    /// Update an arena tournament you created.
+    /// Be mindful not to make important changes to ongoing tournaments.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn update(
+        &self,
+        tournament_id: &str,
+        options: &ArenaTournamentOptions,
+    ) -> Result<ArenaTournamentFull> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self
+            .inner
+            .req_url(UrlBase::Lichess, &format!("api/tournament/{tournament_id}"));
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request
+        let encoded = comma_serde_urlencoded::to_string(options)?;
+        builder = builder
+            .body(encoded)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        self.inner.to_model::<ArenaTournamentFull>(builder).await
+    }
+
+    /// Join an arena tournament, possibly with a password and/or a team.
+    /// Also unpauses if you had previously paused the tournament.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn join(
+        &self,
+        tournament_id: &str,
+        options: Option<&ArenaJoinOptions>,
+    ) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/{tournament_id}/join"),
+        );
+        let mut builder = self.inner.client.post(url);
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            builder = builder
+                .body(encoded)
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+        }
+
+        self.inner.execute(builder).await
+    }
+
+    /// Leave a future arena tournament, or take a break from an ongoing one.
+    /// It can be resumed later by joining the tournament again.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn withdraw(&self, tournament_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/{tournament_id}/withdraw"),
+        );
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Terminate an arena tournament you created.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn terminate(&self, tournament_id: &str) -> Result<()> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/{tournament_id}/terminate"),
+        );
+        let builder = self.inner.client.post(url);
+
+        self.inner.execute(builder).await
+    }
+
+    /// Set the teams and the number of leaders of a team battle you created.
+    /// Up to 200 teams can be set, each one scoring with up to 20 leaders.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn team_battle_update(
+        &self,
+        tournament_id: &str,
+        team_ids: &[&str],
+        leaders: u8,
+    ) -> Result<ArenaTournamentFull> {
+        self.inner.require_scope(Scope::TournamentWrite)?;
+
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/team-battle/{tournament_id}"),
+        );
+        let builder = self
+            .inner
+            .client
+            .post(url)
+            .form(&(("teams", team_ids.join(",")), ("nbLeaders", leaders)));
+
+        self.inner.to_model::<ArenaTournamentFull>(builder).await
+    }
+
+    /// Download games of an arena tournament.
+    /// Games are sorted by reverse chronological order (most recent first).
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn games(
+        &self,
+        tournament_id: &str,
+        options: Option<&GameOptions>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Game>> + Send>>> {
+        let mut url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/{tournament_id}/games"),
+        );
+
+        // Add the options to the request if they are present
+        if let Some(options) = options {
+            let encoded = comma_serde_urlencoded::to_string(options)?;
+            url.set_query(Some(&encoded));
+        }
+
+        let builder = self
+            .inner
+            .client
+            .get(url)
+            .header(header::ACCEPT, "application/x-ndjson");
+        self.inner.to_stream::<Game>(builder).await
+    }
+
+    /// Stream the results of an arena tournament.
+    /// Results are the players sorted by rank, optionally including their score sheets.
+    /// Ongoing tournaments are also supported, with live results.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn results(
+        &self,
+        tournament_id: &str,
+        nb: Option<u32>,
+        sheet: bool,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ArenaResult>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/{tournament_id}/results"),
+        );
+        let mut builder = self.inner.client.get(url).query(&[("sheet", sheet)]);
+
+        // Add the amount of players as a query parameter if it's present
+        if let Some(nb) = nb {
+            builder = builder.query(&[("nb", nb)]);
+        }
+
+        self.inner.to_stream::<ArenaResult>(builder).await
+    }
+
+    /// Get the team standing of a team battle.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn teams(&self, tournament_id: &str) -> Result<ArenaTeamStanding> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/tournament/{tournament_id}/teams"),
+        );
+        let builder = self.inner.client.get(url);
+
+        self.inner.to_model::<ArenaTeamStanding>(builder).await
+    }
+
+    /// Stream the arena tournaments created by a user, most recent first.
+    /// The tournaments can be filtered by their status.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn created(
+        &self,
+        username: &str,
+        status: Option<ArenaStatus>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ArenaTournament>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/user/{username}/tournament/created"),
+        );
+        let mut builder = self.inner.client.get(url);
+
+        // Add the status as a query parameter if it's present
+        if let Some(status) = status {
+            builder = builder.query(&[("status", u8::from(status))]);
+        }
+
+        self.inner.to_stream::<ArenaTournament>(builder).await
+    }
+
+    /// Stream the arena tournaments played by a user, most recent first.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response stream cannot be created.
+    pub async fn played(
+        &self,
+        username: &str,
+        nb: Option<u32>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<PlayedArenaTournament>> + Send>>> {
+        let url = self.inner.req_url(
+            UrlBase::Lichess,
+            &format!("api/user/{username}/tournament/played"),
+        );
+        let mut builder = self.inner.client.get(url);
+
+        // Add the amount of tournaments as a query parameter if it's present
+        if let Some(nb) = nb {
+            builder = builder.query(&[("nb", nb)]);
+        }
+
+        self.inner.to_stream::<PlayedArenaTournament>(builder).await
+    }
+}
@@ -8,8 +8,12 @@ pub use crate::api::analysis::AnalysisApi;
 pub use crate::api::board::BoardApi;
 #[cfg(feature = "bot")]
 pub use crate::api::bot::BotApi;
+#[cfg(feature = "broadcasts")]
+pub use crate::api::broadcasts::BroadcastsApi;
 #[cfg(feature = "challenges")]
 pub use crate::api::challenges::ChallengesApi;
+#[cfg(feature = "external_engine")]
+pub use crate::api::external_engine::ExternalEngineApi;
 #[cfg(feature = "fide")]
 pub use crate::api::fide::FideApi;
 #[cfg(feature = "games")]
@@ -26,29 +30,63 @@ pub use crate::api::puzzles::PuzzlesApi;
 pub use crate::api::relations::RelationsApi;
 #[cfg(feature = "simuls")]
 pub use crate::api::simuls::SimulsApi;
+#[cfg(feature = "studies")]
+pub use crate::api::studies::StudiesApi;
+#[cfg(feature = "swiss")]
+pub use crate::api::swiss::SwissApi;
 #[cfg(feature = "tablebase")]
 pub use crate::api::tablebase::TablebaseApi;
+#[cfg(feature = "teams")]
+pub use crate::api::teams::TeamsApi;
+#[cfg(feature = "tournaments")]
+pub use crate::api::tournaments::TournamentsApi;
 #[cfg(feature = "tv")]
 pub use crate::api::tv::TvApi;
 #[cfg(feature = "users")]
 pub use crate::api::users::UsersApi;
 use crate::{
-    error::{LichessError, Result},
-    models::common::OkResponse,
+    config::client::{
+        CacheOptions, CachedResponse, Middleware, RateLimitEvent, RateLimitOptions, RequestEvent,
+        RetryPolicy,
+    },
+    error::{Error, ErrorKind, LichessError, Result},
+    models::{common::OkResponse, oauth::Scope},
 };
 
 #[cfg(feature = "streaming")]
-use futures_util::{stream, Stream, TryStreamExt};
+use crate::{
+    config::client::ReconnectOptions,
+    models::{
+        board::{BoardState, Event},
+        game::{GameState, GameStatus},
+    },
+};
+
+#[cfg(any(
+    feature = "broadcasts",
+    feature = "games",
+    feature = "pairings",
+    feature = "studies"
+))]
+use crate::pgn::PgnReader;
+
+#[cfg(feature = "streaming")]
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
 
 use reqwest::{
     header::{self, HeaderMap, HeaderValue},
-    Client, IntoUrl, RequestBuilder, Url,
+    Client, IntoUrl, Method, Request, RequestBuilder, Response, StatusCode, Url,
 };
 use serde::de::DeserializeOwned;
-use std::{fmt::Display, sync::Arc};
+use std::{
+    fmt::{Debug, Display},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime},
+};
+use tokio::time::{self, Instant};
 
 #[cfg(feature = "streaming")]
-use std::{io::Error as StdIoError, pin::Pin};
+use std::{collections::HashSet, io::Error as StdIoError, pin::Pin};
 
 #[cfg(feature = "streaming")]
 use tokio::io::{AsyncBufReadExt, BufReader};
@@ -57,16 +95,19 @@ use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio_util::io::StreamReader;
 
 // Lichess default URL constants
-const BASE_URL: &str = "https://lichess.org";
+pub(crate) const BASE_URL: &str = "https://lichess.org";
 
 #[cfg(feature = "openings")]
 const OPENINGS_URL: &str = "https://explorer.lichess.org";
 
+#[cfg(feature = "external_engine")]
+const ENGINE_URL: &str = "https://engine.lichess.ovh";
+
 #[cfg(feature = "tablebase")]
 const TABLEBASE_URL: &str = "https://tablebase.lichess.org";
 
 // Default user agent
-const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+pub(crate) const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 /// Shared inner state for the Lichess client.
 ///
@@ -85,24 +126,173 @@ pub(crate) struct LicheszterInner {
     pub(crate) openings_url: Url,
     #[cfg(feature = "tablebase")]
     pub(crate) tablebase_url: Url,
+    #[cfg(feature = "external_engine")]
+    pub(crate) engine_url: Url,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) cache: Option<CacheOptions>,
+    pub(crate) scopes: Option<Vec<Scope>>,
+    pub(crate) auth: Option<HeaderValue>,
+    pub(crate) middleware: Middlewares,
 }
 
 impl LicheszterInner {
+    // Send the request and return an error if the request failed
+    async fn send(&self, builder: RequestBuilder) -> Result<Response> {
+        let (client, request) = builder.build_split();
+        let mut request = request?;
+        let mut attempt = 1;
+
+        // Send the request & get the response, retrying transient failures if enabled
+        let response = loop {
+            let retry = self
+                .retry_policy
+                .as_ref()
+                .filter(|policy| attempt < policy.max_attempts && policy.allows(request.method()))
+                .zip(request.try_clone());
+            let result = self.dispatch(&client, request).await;
+
+            let Some((policy, retry)) = retry else {
+                break result?;
+            };
+
+            let transient = match &result {
+                Ok(response) => policy.retries_status(response.status()),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            if !transient {
+                break result?;
+            }
+
+            time::sleep(policy.backoff(attempt)).await;
+            attempt += 1;
+            request = retry;
+        };
+
+        // Return an error if the request failed, a revalidated cached response is not an error
+        if !response.status().is_success() && response.status() != StatusCode::NOT_MODIFIED {
+            return Err(LichessError::from_response(response).await?.into());
+        }
+
+        Ok(response)
+    }
+
+    // Send the request and return the response body, using the response cache if enabled for the request
+    async fn fetch(&self, builder: RequestBuilder) -> Result<Vec<u8>> {
+        let Some(cache) = &self.cache else {
+            return Ok(self.send(builder).await?.bytes().await?.to_vec());
+        };
+
+        let (client, request) = builder.build_split();
+        let mut request = request?;
+        let ttl = match request.method() {
+            &Method::GET => cache.ttl_for(request.url().path()),
+            _ => None,
+        };
+        let Some(ttl) = ttl else {
+            let builder = RequestBuilder::from_parts(client, request);
+            return Ok(self.send(builder).await?.bytes().await?.to_vec());
+        };
+
+        // Use a fresh response right away, and ask the server whether an expired one has changed
+        let key = request.url().to_string();
+        let cached = cache.backend.get(&key);
+        if let Some(cached) = &cached {
+            if cached.is_fresh() {
+                return Ok(cached.body.clone());
+            }
+            if let Some(etag) = cached.etag.as_deref().and_then(|etag| etag.parse().ok()) {
+                request.headers_mut().insert(header::IF_NONE_MATCH, etag);
+            }
+        }
+
+        let response = self
+            .send(RequestBuilder::from_parts(client, request))
+            .await?;
+        let ttl = cache_ttl(response.headers(), ttl);
+        let etag = response
+            .headers()
+            .get(header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(String::from);
+
+        let (body, etag) = match cached {
+            Some(cached) if response.status() == StatusCode::NOT_MODIFIED => {
+                (cached.body, etag.or(cached.etag))
+            }
+            _ => (response.bytes().await?.to_vec(), etag),
+        };
+
+        if let Some(ttl) = ttl {
+            let response = CachedResponse {
+                body: body.clone(),
+                etag,
+                expires: SystemTime::now() + ttl,
+            };
+            cache.backend.insert(&key, response);
+        }
+
+        Ok(body)
+    }
+
+    // Send a single request, handling rate limits if enabled
+    async fn dispatch(&self, client: &Client, request: Request) -> reqwest::Result<Response> {
+        match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.send(self, client, request).await,
+            None => self.transmit(client, request).await,
+        }
+    }
+
+    // Authenticate the request and send it over the network, passing it through the middleware
+    async fn transmit(&self, client: &Client, mut request: Request) -> reqwest::Result<Response> {
+        // Requests that carry their own token, e.g. for another account, are left untouched
+        if let Some(auth) = &self.auth {
+            request
+                .headers_mut()
+                .entry(header::AUTHORIZATION)
+                .or_insert_with(|| auth.clone());
+        }
+
+        // Lichess asks clients to identify themselves, which custom HTTP clients might not do
+        request
+            .headers_mut()
+            .entry(header::USER_AGENT)
+            .or_insert_with(|| HeaderValue::from_static(USER_AGENT));
+
+        for middleware in &self.middleware.0 {
+            middleware.on_request(&mut request);
+        }
+
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let start = Instant::now();
+        let result = client.execute(request).await;
+
+        let event = RequestEvent {
+            method,
+            url,
+            elapsed: start.elapsed(),
+        };
+        for middleware in &self.middleware.0 {
+            match &result {
+                Ok(response) => middleware.on_response(&event, response),
+                Err(e) => middleware.on_error(&event, e),
+            }
+        }
+
+        result
+    }
+
     // Convert the API response into a deserialized model
     pub(crate) async fn to_model<T>(&self, builder: RequestBuilder) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        // Send the request & get the response
-        let response = builder.send().await?;
-
-        // Return an error if the request failed
-        if !response.status().is_success() {
-            return Err(LichessError::from_response(response).await?.into());
-        }
+        // Send the request & get the response body
+        let body = self.fetch(builder).await?;
 
         // Deserialize the response data into JSON
-        serde_json::from_slice::<T>(&response.bytes().await?).map_err(Into::into)
+        serde_json::from_slice::<T>(&body).map_err(Into::into)
     }
 
     // Convert API response into a deserialized stream model
@@ -115,12 +305,7 @@ impl LicheszterInner {
         T: DeserializeOwned,
     {
         // Send the request
-        let response = builder.send().await?;
-
-        // Return an error if the request failed
-        if !response.status().is_success() {
-            return Err(LichessError::from_response(response).await?.into());
-        }
+        let response = self.send(builder).await?;
 
         // Get the byte stream returned by the response
         let byte_stream = response.bytes_stream();
@@ -152,18 +337,101 @@ impl LicheszterInner {
         Ok(Box::pin(stream))
     }
 
+    // Convert the API response into a stream of PGN texts, one for each game
+    #[cfg(any(
+        feature = "broadcasts",
+        feature = "games",
+        feature = "pairings",
+        feature = "studies"
+    ))]
+    pub(crate) async fn to_pgn_stream(
+        &self,
+        builder: RequestBuilder,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String>> + Send>>> {
+        // Send the request
+        let response = self.send(builder).await?;
+
+        // Create a reader over the lines of the response
+        let byte_stream = response.bytes_stream();
+        let reader = BufReader::new(StreamReader::new(byte_stream.map_err(StdIoError::other)));
+        let state = (reader.lines(), PgnReader::new(), false);
+
+        // Feed the lines to the PGN reader until it completes a game
+        let stream = stream::unfold(state, |(mut lines, mut reader, mut finished)| async move {
+            loop {
+                if let Some(pgn) = reader.next_pgn() {
+                    return Some((Ok(pgn), (lines, reader, finished)));
+                }
+                if finished {
+                    return None;
+                }
+
+                match lines.next_line().await {
+                    Ok(Some(line)) => {
+                        reader.push(&line);
+                        reader.push("\n");
+                    }
+                    Ok(None) => {
+                        reader.finish();
+                        finished = true;
+                    }
+                    Err(e) => {
+                        finished = true;
+                        return Some((Err(e.into()), (lines, reader, finished)));
+                    }
+                }
+            }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    // Convert the API response into a deserialized stream model that reopens itself after disconnecting
+    #[cfg(feature = "streaming")]
+    pub(crate) async fn to_resilient_stream<T>(
+        self: &Arc<Self>,
+        url: Url,
+        options: ReconnectOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ResilientItem<T>>> + Send>>>
+    where
+        T: Replay,
+    {
+        // Open the first connection right away so that errors are returned to the caller
+        let stream = self.to_stream::<T>(self.client.get(url.clone())).await?;
+
+        let state = ResilientStream {
+            inner: Arc::clone(self),
+            url,
+            options,
+            stream: Some(stream),
+            seen: T::Seen::default(),
+            attempt: 0,
+            finished: false,
+            error: None,
+        };
+
+        let stream = stream::unfold(state, |mut state| async {
+            let item = state.next().await?;
+            Some((item, state))
+        });
+
+        Ok(Box::pin(stream))
+    }
+
     // Convert the API response into a string
-    #[cfg(any(feature = "games", feature = "openings"))]
+    #[cfg(any(
+        feature = "games",
+        feature = "openings",
+        feature = "studies",
+        feature = "swiss"
+    ))]
     pub(crate) async fn to_string(&self, builder: RequestBuilder) -> Result<String> {
-        // Send the request & get the response
-        let response = builder.send().await?;
+        // Send the request & get the response body
+        let body = self.fetch(builder).await?;
 
-        // Return an error if the request failed
-        if !response.status().is_success() {
-            return Err(LichessError::from_response(response).await?.into());
-        }
-
-        Ok(response.text().await?)
+        // Reject bodies that are not valid UTF-8 instead of replacing the invalid bytes
+        String::from_utf8(body)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e).into())
     }
 
     // Execute a request that returns an OkResponse and discard the response body
@@ -172,6 +440,77 @@ impl LicheszterInner {
         Ok(())
     }
 
+    // Convert the API response into a deserialized model, or None if the response has no content
+    #[cfg(feature = "external_engine")]
+    pub(crate) async fn to_optional_model<T>(&self, builder: RequestBuilder) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        // Send the request & get the response
+        let response = self.send(builder).await?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+
+        // Deserialize the response data into JSON
+        Ok(Some(serde_json::from_slice::<T>(&response.bytes().await?)?))
+    }
+
+    // Execute a request that returns no content
+    #[cfg(any(feature = "external_engine", feature = "studies"))]
+    pub(crate) async fn execute_empty(&self, builder: RequestBuilder) -> Result<()> {
+        self.send(builder).await?;
+        Ok(())
+    }
+
+    // Return an error if the token is known to lack the given scope
+    #[cfg_attr(
+        not(any(
+            feature = "account",
+            feature = "board",
+            feature = "bot",
+            feature = "broadcasts",
+            feature = "challenges",
+            feature = "external_engine",
+            feature = "messaging",
+            feature = "pairings",
+            feature = "relations",
+            feature = "studies",
+            feature = "swiss",
+            feature = "teams",
+            feature = "tournaments"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn require_scope(&self, scope: Scope) -> Result<()> {
+        match &self.scopes {
+            Some(scopes) if !scopes.contains(&scope) => Err(Error::new(
+                ErrorKind::MissingScope,
+                format!("the authentication token lacks the {scope} scope"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    // Return an error if the token is known to lack all of the given scopes
+    // An empty list of scopes requires nothing
+    #[cfg(feature = "challenges")]
+    pub(crate) fn require_any_scope(&self, any: &[Scope]) -> Result<()> {
+        match &self.scopes {
+            Some(scopes) if !any.is_empty() && !any.iter().any(|scope| scopes.contains(scope)) => {
+                let names: Vec<String> = any.iter().map(ToString::to_string).collect();
+                Err(Error::new(
+                    ErrorKind::MissingScope,
+                    format!(
+                        "the authentication token lacks one of the {} scopes",
+                        names.join(", ")
+                    ),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     // Construct the full URL of a request with given path
     pub(crate) fn req_url(&self, url: UrlBase, path: &str) -> Url {
         let mut base = match url {
@@ -180,12 +519,316 @@ impl LicheszterInner {
             UrlBase::Openings => self.openings_url.clone(),
             #[cfg(feature = "tablebase")]
             UrlBase::Tablebase => self.tablebase_url.clone(),
+            #[cfg(feature = "external_engine")]
+            UrlBase::Engine => self.engine_url.clone(),
         };
         base.set_path(path);
         base
     }
 }
 
+// The middlewares registered on a client, in the order of registration
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn Middleware>>);
+
+impl Debug for Middlewares {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Middlewares").field(&self.0.len()).finish()
+    }
+}
+
+// Shared rate limit state, pausing all requests of a client after a rate limited response
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    options: RateLimitOptions,
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    fn new(options: RateLimitOptions) -> Self {
+        Self {
+            options,
+            paused_until: Mutex::new(None),
+        }
+    }
+
+    // Send the request, retrying it after the cooldown if it gets rate limited
+    async fn send(
+        &self,
+        inner: &LicheszterInner,
+        client: &Client,
+        mut request: Request,
+    ) -> reqwest::Result<Response> {
+        let mut attempt = 0;
+
+        loop {
+            self.wait().await;
+
+            // Keep a copy of the request around in case it needs to be retried
+            let retry = request.try_clone();
+            let method = request.method().clone();
+            let response = inner.transmit(client, request).await?;
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+
+            // Pause all requests, even if this one will not be retried anymore
+            let retry = retry.filter(|_| attempt < self.options.max_retries);
+            attempt = attempt.saturating_add(1);
+            self.pause();
+
+            if let Some(hook) = &self.options.hook {
+                hook(&RateLimitEvent {
+                    method,
+                    url: response.url().clone(),
+                    attempt,
+                    cooldown: retry.as_ref().map(|_| self.options.cooldown),
+                });
+            }
+
+            match retry {
+                Some(retry) => request = retry,
+                None => return Ok(response),
+            }
+        }
+    }
+
+    // Wait until the requests are no longer paused
+    async fn wait(&self) {
+        // Another request may extend the pause while waiting, so check again after each sleep
+        loop {
+            let paused_until = *self
+                .paused_until
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            match paused_until {
+                Some(until) if until > Instant::now() => time::sleep_until(until).await,
+                _ => return,
+            }
+        }
+    }
+
+    // Pause all requests for the cooldown period
+    fn pause(&self) {
+        let until = Instant::now() + self.options.cooldown;
+        let mut paused_until = self
+            .paused_until
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if paused_until.is_none_or(|paused_until| paused_until < until) {
+            *paused_until = Some(until);
+        }
+    }
+}
+
+/// An item produced by a stream that reopens itself after the connection is lost.
+#[cfg(feature = "streaming")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResilientItem<T> {
+    /// An item received from Lichess.
+    Item(T),
+    /// The connection was lost and has been reopened.
+    /// Items sent while the stream was disconnected may have been missed, so any local state should be resynchronized.
+    Reconnected,
+}
+
+// A stream item type that Lichess may send again after reconnecting
+#[cfg(feature = "streaming")]
+pub(crate) trait Replay: DeserializeOwned + Send + 'static {
+    type Seen: Default + Send;
+
+    // Check whether the item has already been emitted, remembering it for later
+    fn is_replay(&self, seen: &mut Self::Seen) -> bool;
+
+    // Check whether the item is the last one and the stream should not be reopened after it
+    fn is_final(&self) -> bool {
+        false
+    }
+
+    // Forget the items that are no longer relevant after the stream has been reopened
+    fn reconnected(_seen: &mut Self::Seen) {}
+}
+
+// The challenges and games emitted by the event stream, split by whether Lichess has sent them
+// again since the stream was last reopened
+#[cfg(feature = "streaming")]
+#[derive(Debug, Default)]
+pub(crate) struct SeenEvents {
+    current: HashSet<String>,
+    previous: HashSet<String>,
+}
+
+#[cfg(feature = "streaming")]
+impl SeenEvents {
+    // Remember an item, returning whether it was already known
+    fn insert(&mut self, id: String) -> bool {
+        let known = self.previous.remove(&id);
+        !self.current.insert(id) || known
+    }
+
+    fn remove(&mut self, id: &str) {
+        self.current.remove(id);
+        self.previous.remove(id);
+    }
+}
+
+#[cfg(feature = "streaming")]
+impl Replay for Event {
+    type Seen = SeenEvents;
+
+    fn is_replay(&self, seen: &mut Self::Seen) -> bool {
+        // Lichess sends all current challenges and games again when the stream is opened
+        match self {
+            Event::Challenge { challenge } => seen.insert(format!("challenge/{}", challenge.id)),
+            Event::GameStart { game } => {
+                seen.remove(&format!("challenge/{}", game.id));
+                seen.insert(format!("game/{}", game.id))
+            }
+            Event::GameFinish { game } => {
+                seen.remove(&format!("game/{}", game.id));
+                false
+            }
+            Event::ChallengeCanceled { challenge } | Event::ChallengeDeclined { challenge } => {
+                seen.remove(&format!("challenge/{}", challenge.id));
+                false
+            }
+        }
+    }
+
+    fn reconnected(seen: &mut Self::Seen) {
+        // Challenges and games that ended while disconnected are not sent again,
+        // so anything not sent again by the next reconnection is forgotten
+        seen.previous = std::mem::take(&mut seen.current);
+    }
+}
+
+#[cfg(feature = "streaming")]
+impl Replay for BoardState {
+    type Seen = Option<GameState>;
+
+    fn is_replay(&self, seen: &mut Self::Seen) -> bool {
+        // Lichess sends the full game again when the stream is opened,
+        // which is only relevant if the game has progressed while disconnected
+        match self {
+            BoardState::GameFull(game) => {
+                let replay = seen.as_ref().is_some_and(|state| {
+                    state.moves == game.state.moves && state.status == game.state.status
+                });
+                *seen = Some(game.state.clone());
+                replay
+            }
+            BoardState::GameState(state) => {
+                *seen = Some(state.clone());
+                false
+            }
+            BoardState::ChatLine(_) | BoardState::OpponentGone(_) => false,
+        }
+    }
+
+    fn is_final(&self) -> bool {
+        let status = match self {
+            BoardState::GameFull(game) => game.state.status,
+            BoardState::GameState(state) => state.status,
+            BoardState::ChatLine(_) | BoardState::OpponentGone(_) => return false,
+        };
+        !matches!(status, GameStatus::Created | GameStatus::Started)
+    }
+}
+
+// The state of a stream that reopens itself after the connection is lost
+#[cfg(feature = "streaming")]
+struct ResilientStream<T: Replay> {
+    inner: Arc<LicheszterInner>,
+    url: Url,
+    options: ReconnectOptions,
+    stream: Option<Pin<Box<dyn Stream<Item = Result<T>> + Send>>>,
+    seen: T::Seen,
+    attempt: u32,
+    finished: bool,
+    error: Option<Error>,
+}
+
+#[cfg(feature = "streaming")]
+impl<T: Replay> ResilientStream<T> {
+    async fn next(&mut self) -> Option<Result<ResilientItem<T>>> {
+        loop {
+            let Some(stream) = &mut self.stream else {
+                if self.finished {
+                    return None;
+                }
+
+                // Give up after too many failed attempts, returning the latest error if there is one
+                self.attempt += 1;
+                if self
+                    .options
+                    .max_attempts
+                    .is_some_and(|max| self.attempt > max)
+                {
+                    self.finished = true;
+                    return self.error.take().map(Err);
+                }
+
+                // Wait at least as long as Lichess asked for after a rate limited or unavailable response
+                let backoff = self.options.backoff(self.attempt);
+                let retry_after = self
+                    .error
+                    .as_ref()
+                    .and_then(Error::lichess)
+                    .and_then(LichessError::retry_after);
+                time::sleep(retry_after.map_or(backoff, |delay| delay.max(backoff))).await;
+
+                let builder = self.inner.client.get(self.url.clone());
+                match self.inner.to_stream::<T>(builder).await {
+                    Ok(stream) => {
+                        self.stream = Some(stream);
+                        T::reconnected(&mut self.seen);
+                        return Some(Ok(ResilientItem::Reconnected));
+                    }
+                    // Network errors, server errors and rate limits are most likely temporary,
+                    // unlike requests rejected by Lichess
+                    Err(e) if is_temporary(&e) => self.error = Some(e),
+                    Err(e) => {
+                        self.finished = true;
+                        return Some(Err(e));
+                    }
+                }
+                continue;
+            };
+
+            match stream.next().await {
+                Some(Ok(item)) => {
+                    self.attempt = 0;
+                    self.error = None;
+                    self.finished = item.is_final();
+                    if !item.is_replay(&mut self.seen) {
+                        return Some(Ok(ResilientItem::Item(item)));
+                    }
+                }
+                // The connection was lost in the middle of the stream
+                Some(Err(e)) if e.is_io() => {
+                    self.stream = None;
+                    self.error = Some(e);
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None if self.finished => return None,
+                None => self.stream = None,
+            }
+        }
+    }
+}
+
+// Check whether reopening a stream or repeating a request may succeed later after the given error
+#[cfg(feature = "streaming")]
+pub(crate) fn is_temporary(error: &Error) -> bool {
+    error.is_reqwest()
+        || error.status().is_some_and(|status| {
+            status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+        })
+}
+
 /// [`Licheszter`] is used to connect to the Lichess API.
 ///
 /// API endpoints are organized into categories, each accessible as a field
@@ -203,8 +846,12 @@ pub struct Licheszter {
     board: BoardApi,
     #[cfg(feature = "bot")]
     bot: BotApi,
+    #[cfg(feature = "broadcasts")]
+    broadcasts: BroadcastsApi,
     #[cfg(feature = "challenges")]
     challenges: ChallengesApi,
+    #[cfg(feature = "external_engine")]
+    external_engine: ExternalEngineApi,
     #[cfg(feature = "fide")]
     fide: FideApi,
     #[cfg(feature = "games")]
@@ -221,8 +868,16 @@ pub struct Licheszter {
     relations: RelationsApi,
     #[cfg(feature = "simuls")]
     simuls: SimulsApi,
+    #[cfg(feature = "studies")]
+    studies: StudiesApi,
+    #[cfg(feature = "swiss")]
+    swiss: SwissApi,
     #[cfg(feature = "tablebase")]
     tablebase: TablebaseApi,
+    #[cfg(feature = "teams")]
+    teams: TeamsApi,
+    #[cfg(feature = "tournaments")]
+    tournaments: TournamentsApi,
     #[cfg(feature = "tv")]
     tv: TvApi,
     #[cfg(feature = "users")]
@@ -256,11 +911,25 @@ impl Licheszter {
     ///
     /// Note: `reqwest::Client` uses `Arc` internally, so this clone is very cheap.
     /// The returned client shares the same connection pool and configuration.
+    ///
+    /// Requests sent directly with the returned client are authenticated with the token set with
+    /// [`LicheszterBuilder::with_authentication()`], unless a custom client was set with
+    /// [`LicheszterBuilder::with_http_client()`].
+    /// In that case, use [`auth_header()`](fn@Licheszter::auth_header) to authenticate them.
     #[must_use]
     pub fn client(&self) -> Client {
         self.inner.client.clone()
     }
 
+    /// Get the `Authorization` header sent with the requests of this [`Licheszter`] instance,
+    /// or `None` if the client is not authenticated.
+    ///
+    /// The header value is marked as sensitive, so it is not shown in debug output.
+    #[must_use]
+    pub fn auth_header(&self) -> Option<HeaderValue> {
+        self.inner.auth.clone()
+    }
+
     /// Get the opening explorer server URL used in this [`Licheszter`] client.
     #[cfg(feature = "openings")]
     #[must_use]
@@ -275,6 +944,13 @@ impl Licheszter {
         self.inner.tablebase_url.clone()
     }
 
+    /// Get the external engine server URL used in this [`Licheszter`] client.
+    #[cfg(feature = "external_engine")]
+    #[must_use]
+    pub fn engine_url(&self) -> Url {
+        self.inner.engine_url.clone()
+    }
+
     /// Access the Account API endpoints.
     #[cfg(feature = "account")]
     #[must_use]
@@ -352,6 +1028,27 @@ impl Licheszter {
         &self.pairings
     }
 
+    /// Access the Teams API endpoints.
+    #[cfg(feature = "teams")]
+    #[must_use]
+    pub fn teams(&self) -> &TeamsApi {
+        &self.teams
+    }
+
+    /// Access the Arena Tournaments API endpoints.
+    #[cfg(feature = "tournaments")]
+    #[must_use]
+    pub fn tournaments(&self) -> &TournamentsApi {
+        &self.tournaments
+    }
+
+    /// Access the Swiss Tournaments API endpoints.
+    #[cfg(feature = "swiss")]
+    #[must_use]
+    pub fn swiss(&self) -> &SwissApi {
+        &self.swiss
+    }
+
     /// Access the Simuls API endpoints.
     #[cfg(feature = "simuls")]
     #[must_use]
@@ -359,6 +1056,20 @@ impl Licheszter {
         &self.simuls
     }
 
+    /// Access the Studies API endpoints.
+    #[cfg(feature = "studies")]
+    #[must_use]
+    pub fn studies(&self) -> &StudiesApi {
+        &self.studies
+    }
+
+    /// Access the Broadcasts API endpoints.
+    #[cfg(feature = "broadcasts")]
+    #[must_use]
+    pub fn broadcasts(&self) -> &BroadcastsApi {
+        &self.broadcasts
+    }
+
     /// Access the FIDE API endpoints.
     #[cfg(feature = "fide")]
     #[must_use]
@@ -386,6 +1097,13 @@ impl Licheszter {
     pub fn tablebase(&self) -> &TablebaseApi {
         &self.tablebase
     }
+
+    /// Access the External engine API endpoints.
+    #[cfg(feature = "external_engine")]
+    #[must_use]
+    pub fn external_engine(&self) -> &ExternalEngineApi {
+        &self.external_engine
+    }
 }
 
 impl Default for Licheszter {
@@ -404,6 +1122,15 @@ pub struct LicheszterBuilder {
     openings_url: Url,
     #[cfg(feature = "tablebase")]
     tablebase_url: Url,
+    #[cfg(feature = "external_engine")]
+    engine_url: Url,
+    rate_limit: Option<RateLimitOptions>,
+    retry_policy: Option<RetryPolicy>,
+    cache: Option<CacheOptions>,
+    scopes: Option<Vec<Scope>>,
+    auth: Option<HeaderValue>,
+    custom_client: bool,
+    middleware: Middlewares,
 }
 
 impl LicheszterBuilder {
@@ -425,6 +1152,14 @@ impl LicheszterBuilder {
             openings_url: self.openings_url,
             #[cfg(feature = "tablebase")]
             tablebase_url: self.tablebase_url,
+            #[cfg(feature = "external_engine")]
+            engine_url: self.engine_url,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+            retry_policy: self.retry_policy,
+            cache: self.cache,
+            scopes: self.scopes,
+            auth: self.auth,
+            middleware: self.middleware,
         });
 
         Licheszter {
@@ -444,10 +1179,18 @@ impl LicheszterBuilder {
             bot: BotApi {
                 inner: Arc::clone(&inner),
             },
+            #[cfg(feature = "broadcasts")]
+            broadcasts: BroadcastsApi {
+                inner: Arc::clone(&inner),
+            },
             #[cfg(feature = "challenges")]
             challenges: ChallengesApi {
                 inner: Arc::clone(&inner),
             },
+            #[cfg(feature = "external_engine")]
+            external_engine: ExternalEngineApi {
+                inner: Arc::clone(&inner),
+            },
             #[cfg(feature = "fide")]
             fide: FideApi {
                 inner: Arc::clone(&inner),
@@ -480,10 +1223,26 @@ impl LicheszterBuilder {
             simuls: SimulsApi {
                 inner: Arc::clone(&inner),
             },
+            #[cfg(feature = "studies")]
+            studies: StudiesApi {
+                inner: Arc::clone(&inner),
+            },
+            #[cfg(feature = "swiss")]
+            swiss: SwissApi {
+                inner: Arc::clone(&inner),
+            },
             #[cfg(feature = "tablebase")]
             tablebase: TablebaseApi {
                 inner: Arc::clone(&inner),
             },
+            #[cfg(feature = "teams")]
+            teams: TeamsApi {
+                inner: Arc::clone(&inner),
+            },
+            #[cfg(feature = "tournaments")]
+            tournaments: TournamentsApi {
+                inner: Arc::clone(&inner),
+            },
             #[cfg(feature = "tv")]
             tv: TvApi {
                 inner: Arc::clone(&inner),
@@ -500,32 +1259,37 @@ impl LicheszterBuilder {
     /// This is recommended for most use cases.
     ///
     /// # Errors
-    /// Returns an error if:
-    /// - The authentication token contains invalid characters (non-visible ASCII, newlines, etc.)
-    /// - The HTTP client fails to initialize (extremely rare)
+    /// Returns an error if the authentication token contains invalid characters (non-visible ASCII, newlines, etc.)
     pub fn with_authentication<S>(mut self, token: S) -> Result<LicheszterBuilder>
     where
         S: AsRef<str> + Display,
     {
-        // Create a new header map & the authentication header
-        let mut header_map = HeaderMap::new();
-        let token = format!("Bearer {token}");
-
         // Validate the token and create header (returns error instead of panicking)
-        let mut auth_header = HeaderValue::from_str(&token)?;
-
-        // Insert the authentication header into the header map
+        let mut auth_header = HeaderValue::from_str(&format!("Bearer {token}"))?;
         auth_header.set_sensitive(true);
-        header_map.insert(header::AUTHORIZATION, auth_header);
 
-        self.client = Client::builder()
-            .default_headers(header_map)
-            .user_agent(USER_AGENT)
-            .tls_backend_rustls()
-            .build()?;
+        // The client built by the library also authenticates requests sent with it directly
+        if !self.custom_client {
+            let mut header_map = HeaderMap::new();
+            header_map.insert(header::AUTHORIZATION, auth_header.clone());
+            self.client = http_client(header_map)?;
+        }
+
+        self.auth = Some(auth_header);
         Ok(self)
     }
 
+    /// Use a custom [`reqwest::Client`] for sending the requests.
+    /// This can be useful, for example, for configuring proxies, timeouts or a different TLS backend.
+    /// The authentication token set with [`LicheszterBuilder::with_authentication()`] and the user agent
+    /// of the library are still added to every request, unless the client sets them itself.
+    #[must_use]
+    pub fn with_http_client(mut self, client: Client) -> LicheszterBuilder {
+        self.client = client;
+        self.custom_client = true;
+        self
+    }
+
     /// Insert a valid base URL of a custom Lichess server.
     /// This can be useful, for example, when hosting your own server for debugging purposes.
     ///
@@ -557,24 +1321,127 @@ impl LicheszterBuilder {
         self.tablebase_url = url.into_url()?;
         Ok(self)
     }
+
+    /// Insert a valid URL of a custom external engine server.
+    /// This can be useful, for example, when hosting your own server for debugging purposes.
+    ///
+    /// # Errors
+    /// Returns an error if the given URL cannot be converted into a [`reqwest::Url`].
+    #[cfg(feature = "external_engine")]
+    pub fn with_engine_url(mut self, url: impl IntoUrl) -> Result<LicheszterBuilder> {
+        self.engine_url = url.into_url()?;
+        Ok(self)
+    }
+
+    /// Enable automatic handling of rate limited (`429 Too Many Requests`) responses.
+    /// When a request is rate limited, all requests sent by the [`Licheszter`] instance are paused
+    /// for the configured cooldown, after which the rate limited request is retried.
+    /// Disabled by default.
+    #[must_use]
+    pub fn with_rate_limit(mut self, options: RateLimitOptions) -> LicheszterBuilder {
+        self.rate_limit = Some(options);
+        self
+    }
+
+    /// Enable automatic retrying of requests that failed due to network errors or transient server errors.
+    /// Only idempotent requests (such as `GET`) are retried unless [`RetryPolicy`] explicitly allows otherwise.
+    /// Disabled by default.
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> LicheszterBuilder {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Enable caching the responses of rarely changing endpoints, such as the opening explorer and the tablebases.
+    /// See [`CacheOptions`] for which responses are cached and for how long.
+    /// Disabled by default.
+    #[must_use]
+    pub fn with_cache(mut self, options: CacheOptions) -> LicheszterBuilder {
+        self.cache = Some(options);
+        self
+    }
+
+    /// Declare the scopes granted to the authentication token, e.g. the ones returned by [`Licheszter::test_tokens()`].
+    /// Requests to endpoints that require a scope missing from the list fail immediately without being sent.
+    /// Disabled by default.
+    #[must_use]
+    pub fn with_scopes(mut self, scopes: &[Scope]) -> LicheszterBuilder {
+        self.scopes = Some(scopes.to_vec());
+        self
+    }
+
+    /// Register a [`Middleware`] that sees every request sent and every response received,
+    /// including retries and the requests opening streams.
+    /// Several middlewares can be registered, they are invoked in the order of registration.
+    #[must_use]
+    pub fn with_middleware<M>(mut self, middleware: M) -> LicheszterBuilder
+    where
+        M: Middleware,
+    {
+        self.middleware.0.push(Arc::new(middleware));
+        self
+    }
 }
 
 impl Default for LicheszterBuilder {
     /// Create an unauthenticated instance of Licheszter.
     fn default() -> Self {
         Self {
-            client: Client::builder()
-                .user_agent(USER_AGENT)
-                .tls_backend_rustls()
-                .build()
-                .expect("Failed to build HTTP client - this should never fail with default configuration"),
+            client: http_client(HeaderMap::new()).expect(
+                "Failed to build HTTP client - this should never fail with default configuration",
+            ),
             base_url: Url::parse(BASE_URL).expect("BASE_URL constant is not a valid URL"),
             #[cfg(feature = "openings")]
-            openings_url: Url::parse(OPENINGS_URL).expect("OPENINGS_URL constant is not a valid URL"),
+            openings_url: Url::parse(OPENINGS_URL)
+                .expect("OPENINGS_URL constant is not a valid URL"),
             #[cfg(feature = "tablebase")]
-            tablebase_url: Url::parse(TABLEBASE_URL).expect("TABLEBASE_URL constant is not a valid URL"),
+            tablebase_url: Url::parse(TABLEBASE_URL)
+                .expect("TABLEBASE_URL constant is not a valid URL"),
+            #[cfg(feature = "external_engine")]
+            engine_url: Url::parse(ENGINE_URL).expect("ENGINE_URL constant is not a valid URL"),
+            rate_limit: None,
+            retry_policy: None,
+            cache: None,
+            scopes: None,
+            auth: None,
+            custom_client: false,
+            middleware: Middlewares::default(),
+        }
+    }
+}
+
+// Build the HTTP client used when no custom client is given
+fn http_client(header_map: HeaderMap) -> reqwest::Result<Client> {
+    Client::builder()
+        .default_headers(header_map)
+        .user_agent(USER_AGENT)
+        .tls_backend_rustls()
+        .build()
+}
+
+// Get the time to live of a response from its Cache-Control header, or None if it must not be stored
+fn cache_ttl(headers: &header::HeaderMap, mut ttl: Duration) -> Option<Duration> {
+    let cache_control = headers
+        .get(header::CACHE_CONTROL)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    // Responses that must always be revalidated are stored only for their ETag
+    let mut revalidate = false;
+    for directive in cache_control.split(',').map(str::trim) {
+        match directive.split_once('=') {
+            _ if directive.eq_ignore_ascii_case("no-store") => return None,
+            _ if directive.eq_ignore_ascii_case("no-cache") => revalidate = true,
+            Some((name, seconds)) if name.eq_ignore_ascii_case("max-age") => {
+                if let Ok(seconds) = seconds.trim_matches('"').parse() {
+                    ttl = Duration::from_secs(seconds);
+                }
+            }
+            _ => {}
         }
     }
+
+    Some(if revalidate { Duration::ZERO } else { ttl })
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -584,4 +1451,6 @@ pub(crate) enum UrlBase {
     Openings,
     #[cfg(feature = "tablebase")]
     Tablebase,
+    #[cfg(feature = "external_engine")]
+    Engine,
 }
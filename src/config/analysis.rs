@@ -0,0 +1,205 @@
+use std::{
+    fmt::Debug,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    config::client::Lru,
+    models::{analysis::CloudAnalysis, game::VariantMode},
+};
+
+const DEFAULT_CLOUD_EVAL_CAPACITY: usize = 10_000;
+const DEFAULT_MISSING_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Optional configuration for looking up many cloud evaluations using
+/// [`analysis().cloud_batch()`](fn@crate::client::AnalysisApi::cloud_batch).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CloudBatchOptions {
+    multi_pv: Option<u8>,
+    variant: Option<VariantMode>,
+    concurrency: usize,
+}
+
+impl CloudBatchOptions {
+    /// Create a new instance of [`CloudBatchOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the number of variations to look up for every position.
+    #[must_use]
+    pub fn multi_pv(mut self, multi_pv: u8) -> Self {
+        self.multi_pv = Some(multi_pv);
+        self
+    }
+
+    /// Determines the variant of the positions.
+    #[must_use]
+    pub fn variant(mut self, variant: VariantMode) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Determines how many requests are sent at the same time, at least 1.
+    /// Consider enabling [`with_rate_limit()`](fn@crate::client::LicheszterBuilder::with_rate_limit)
+    /// when sending many requests at the same time.
+    /// Defaults to 4.
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    // Get the maximum number of requests sent at the same time
+    pub(crate) fn max_concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    // Create the cache key of a position looked up with these options
+    pub(crate) fn key(&self, fen: &str) -> CloudEvalKey {
+        CloudEvalKey::new(fen, self.multi_pv, self.variant)
+    }
+}
+
+impl Default for CloudBatchOptions {
+    fn default() -> Self {
+        Self {
+            multi_pv: None,
+            variant: None,
+            concurrency: 4,
+        }
+    }
+}
+
+/// The key of a cloud evaluation in a [`CloudEvalCache`].
+///
+/// The FEN is normalised by dropping the halfmove clock and the fullmove number,
+/// which do not affect the evaluation, so transpositions share the same key.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CloudEvalKey {
+    /// The normalised FEN of the position.
+    pub fen: String,
+    /// The number of variations that were looked up.
+    pub multi_pv: Option<u8>,
+    /// The variant of the position.
+    pub variant: Option<VariantMode>,
+}
+
+impl CloudEvalKey {
+    /// Create a new [`CloudEvalKey`], normalising the FEN.
+    #[must_use]
+    pub fn new(fen: &str, multi_pv: Option<u8>, variant: Option<VariantMode>) -> Self {
+        Self {
+            fen: fen.split_whitespace().take(4).collect::<Vec<_>>().join(" "),
+            multi_pv,
+            variant,
+        }
+    }
+}
+
+/// A cache of cloud evaluations consulted by [`analysis().cloud_batch()`](fn@crate::client::AnalysisApi::cloud_batch)
+/// before sending any requests.
+///
+/// Positions without a cloud evaluation are cached as `None`, so they are not looked up again while cached either.
+/// [`MemoryCloudEvalCache`] keeps the evaluations in memory, other backends can be plugged in by implementing this trait.
+///
+/// Unlike a [`CacheBackend`](trait@crate::config::client::CacheBackend), which stores raw responses by URL,
+/// this cache stores the lookup results by position, so transpositions and positions without
+/// a cloud evaluation (which Lichess answers with an error) are cached as well.
+pub trait CloudEvalCache: Send + Sync {
+    /// Get the cached lookup result of a position, or `None` if the position has not been looked up.
+    fn get(&self, key: &CloudEvalKey) -> Option<Option<CloudAnalysis>>;
+
+    /// Store the lookup result of a position.
+    fn insert(&self, key: CloudEvalKey, analysis: Option<CloudAnalysis>);
+}
+
+/// A [`CloudEvalCache`] that keeps a limited number of lookup results in memory,
+/// evicting the least recently used result when it is full.
+///
+/// Positions without a cloud evaluation are looked up again once [`missing_ttl()`](fn@MemoryCloudEvalCache::missing_ttl)
+/// has passed, as Lichess may evaluate them in the meantime.
+#[derive(Debug)]
+pub struct MemoryCloudEvalCache {
+    entries: Mutex<Lru<CloudEvalKey, (Option<CloudAnalysis>, Instant)>>,
+    missing_ttl: Duration,
+}
+
+impl MemoryCloudEvalCache {
+    /// Create a new, empty [`MemoryCloudEvalCache`] holding up to 10000 positions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new, empty [`MemoryCloudEvalCache`] holding up to the given number of positions.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(Lru::new(capacity)),
+            missing_ttl: DEFAULT_MISSING_TTL,
+        }
+    }
+
+    /// Determines how long positions without a cloud evaluation are cached.
+    /// Defaults to 1 hour.
+    #[must_use]
+    pub fn missing_ttl(mut self, ttl: Duration) -> Self {
+        self.missing_ttl = ttl;
+        self
+    }
+
+    /// Returns the number of cached positions, including expired positions without a cloud evaluation.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns whether the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached positions.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl Default for MemoryCloudEvalCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CLOUD_EVAL_CAPACITY)
+    }
+}
+
+impl CloudEvalCache for MemoryCloudEvalCache {
+    fn get(&self, key: &CloudEvalKey) -> Option<Option<CloudAnalysis>> {
+        let (analysis, stored) = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)?;
+
+        // Missing evaluations are only trusted for a while, found evaluations are kept until evicted
+        match analysis {
+            None if stored.elapsed() >= self.missing_ttl => None,
+            analysis => Some(analysis),
+        }
+    }
+
+    fn insert(&self, key: CloudEvalKey, analysis: Option<CloudAnalysis>) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, (analysis, Instant::now()));
+    }
+}
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::broadcast::{
+    BroadcastFideTimeControl, BroadcastRoundStatus, BroadcastVisibility,
+};
+
+/// Configuration for creating and updating broadcast tournaments using
+/// [`broadcasts().create()`](fn@crate::client::BroadcastsApi::create) and [`broadcasts().update()`](fn@crate::client::BroadcastsApi::update).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastOptions {
+    name: Option<String>,
+    #[serde(rename = "info.format")]
+    format: Option<String>,
+    #[serde(rename = "info.location")]
+    location: Option<String>,
+    #[serde(rename = "info.tc")]
+    time_control: Option<String>,
+    #[serde(rename = "info.fideTc")]
+    fide_time_control: Option<BroadcastFideTimeControl>,
+    #[serde(rename = "info.timeZone")]
+    time_zone: Option<String>,
+    #[serde(rename = "info.players")]
+    featured_players: Option<String>,
+    #[serde(rename = "info.website")]
+    website: Option<String>,
+    #[serde(rename = "info.standings")]
+    standings: Option<String>,
+    markdown: Option<String>,
+    show_scores: Option<bool>,
+    show_rating_diffs: Option<bool>,
+    team_table: Option<bool>,
+    visibility: Option<BroadcastVisibility>,
+    players: Option<String>,
+    teams: Option<String>,
+}
+
+impl BroadcastOptions {
+    /// Create a new instance of [`BroadcastOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the name of the broadcast tournament.
+    /// This is required when creating a broadcast tournament.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the format of the tournament, e.g. "8-player round-robin".
+    #[must_use]
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Set the location of the tournament.
+    #[must_use]
+    pub fn location(mut self, location: &str) -> Self {
+        self.location = Some(location.to_string());
+        self
+    }
+
+    /// Set a human-readable description of the time control, e.g. "90+30".
+    #[must_use]
+    pub fn time_control(mut self, time_control: &str) -> Self {
+        self.time_control = Some(time_control.to_string());
+        self
+    }
+
+    /// Determines the FIDE rating category of the games.
+    #[must_use]
+    pub fn fide_time_control(mut self, time_control: BroadcastFideTimeControl) -> Self {
+        self.fide_time_control = Some(time_control);
+        self
+    }
+
+    /// Set the time zone of the tournament, e.g. "Europe/Helsinki".
+    /// Defaults to UTC.
+    #[must_use]
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// Set the names of the featured players of the tournament.
+    #[must_use]
+    pub fn featured_players(mut self, players: &[&str]) -> Self {
+        self.featured_players = Some(players.join(", "));
+        self
+    }
+
+    /// Set the official website of the tournament.
+    #[must_use]
+    pub fn website(mut self, url: &str) -> Self {
+        self.website = Some(url.to_string());
+        self
+    }
+
+    /// Set the official standings website of the tournament.
+    #[must_use]
+    pub fn standings(mut self, url: &str) -> Self {
+        self.standings = Some(url.to_string());
+        self
+    }
+
+    /// Set a description of the tournament, with Markdown support.
+    #[must_use]
+    pub fn markdown(mut self, markdown: &str) -> Self {
+        self.markdown = Some(markdown.to_string());
+        self
+    }
+
+    /// Determines whether the scores of the players are shown based on game results.
+    /// Defaults to true.
+    #[must_use]
+    pub fn show_scores(mut self, show_scores: bool) -> Self {
+        self.show_scores = Some(show_scores);
+        self
+    }
+
+    /// Determines whether the rating changes of the players are shown.
+    /// Defaults to true.
+    #[must_use]
+    pub fn show_rating_diffs(mut self, show_rating_diffs: bool) -> Self {
+        self.show_rating_diffs = Some(show_rating_diffs);
+        self
+    }
+
+    /// Determines whether a team table is shown, if the games have team tags.
+    /// Defaults to false.
+    #[must_use]
+    pub fn team_table(mut self, team_table: bool) -> Self {
+        self.team_table = Some(team_table);
+        self
+    }
+
+    /// Determines who can see the broadcast.
+    /// Defaults to public.
+    #[must_use]
+    pub fn visibility(mut self, visibility: BroadcastVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Replace the player names, ratings and titles of the PGN games.
+    /// Each line of the replacement text has the format of "Original name = FIDE ID" or "Original name / FIDE ID / Title / Rating / New name".
+    #[must_use]
+    pub fn players(mut self, replacements: &[&str]) -> Self {
+        self.players = Some(replacements.join("\n"));
+        self
+    }
+
+    /// Assign the players to teams.
+    /// Each line of the text has the format of "Team name; FIDE ID or player name".
+    #[must_use]
+    pub fn teams(mut self, teams: &[&str]) -> Self {
+        self.teams = Some(teams.join("\n"));
+        self
+    }
+}
+
+/// Configuration for creating and updating broadcast rounds using
+/// [`broadcasts().create_round()`](fn@crate::client::BroadcastsApi::create_round) and [`broadcasts().update_round()`](fn@crate::client::BroadcastsApi::update_round).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastRoundOptions {
+    name: Option<String>,
+    sync_url: Option<String>,
+    sync_urls: Option<String>,
+    sync_ids: Option<String>,
+    sync_users: Option<String>,
+    only_round: Option<u16>,
+    slices: Option<String>,
+    starts_at: Option<u64>,
+    starts_after_previous: Option<bool>,
+    delay: Option<u16>,
+    status: Option<BroadcastRoundStatus>,
+    rated: Option<bool>,
+    period: Option<u8>,
+}
+
+impl BroadcastRoundOptions {
+    /// Create a new instance of [`BroadcastRoundOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the name of the broadcast round.
+    /// This is required when creating a broadcast round.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Make Lichess poll the given URL for the PGN of the round.
+    /// Leave all sync sources empty to push the PGN manually.
+    #[must_use]
+    pub fn sync_url(mut self, url: &str) -> Self {
+        self.sync_url = Some(url.to_string());
+        self
+    }
+
+    /// Make Lichess poll the given URLs for the PGN of the round, combining the games of all of them.
+    #[must_use]
+    pub fn sync_urls(mut self, urls: &[&str]) -> Self {
+        self.sync_urls = Some(urls.join("\n"));
+        self
+    }
+
+    /// Make Lichess follow the given Lichess game IDs.
+    #[must_use]
+    pub fn sync_ids(mut self, game_ids: &[&str]) -> Self {
+        self.sync_ids = Some(game_ids.join(" "));
+        self
+    }
+
+    /// Make Lichess follow the ongoing games of the given Lichess users.
+    #[must_use]
+    pub fn sync_users(mut self, usernames: &[&str]) -> Self {
+        self.sync_users = Some(usernames.join(" "));
+        self
+    }
+
+    /// Only keep the games of the given round number from the synced source.
+    #[must_use]
+    pub fn only_round(mut self, round: u16) -> Self {
+        self.only_round = Some(round);
+        self
+    }
+
+    /// Only keep a slice of the games from the synced source, e.g. "1-5,8".
+    #[must_use]
+    pub fn slices(mut self, slices: &str) -> Self {
+        self.slices = Some(slices.to_string());
+        self
+    }
+
+    /// Determines when the round starts.
+    /// The timestamp is in MILLISECONDS.
+    #[must_use]
+    pub fn starts_at(mut self, timestamp: u64) -> Self {
+        self.starts_at = Some(timestamp);
+        self
+    }
+
+    /// Determines whether the round starts right after the previous round finishes.
+    /// Defaults to false.
+    #[must_use]
+    pub fn starts_after_previous(mut self, starts_after_previous: bool) -> Self {
+        self.starts_after_previous = Some(starts_after_previous);
+        self
+    }
+
+    /// Determines how long to delay the moves coming from the synced source, in seconds.
+    #[must_use]
+    pub fn delay(mut self, seconds: u16) -> Self {
+        self.delay = Some(seconds);
+        self
+    }
+
+    /// Set the status of the round manually.
+    /// Lichess automatically sets the status of synced rounds.
+    #[must_use]
+    pub fn status(mut self, status: BroadcastRoundStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Determines whether the games of the round count towards the FIDE ratings of the players.
+    /// Defaults to true.
+    #[must_use]
+    pub fn rated(mut self, rated: bool) -> Self {
+        self.rated = Some(rated);
+        self
+    }
+
+    /// Determines how often Lichess polls the synced source, in seconds.
+    #[must_use]
+    pub fn period(mut self, seconds: u8) -> Self {
+        self.period = Some(seconds);
+        self
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::{
-    common::Color,
-    game::{CorrespondenceDays, Rules, VariantMode},
+    challenge::{Challenge, ChallengeDecision, ChallengeDeclineReason},
+    common::{Color, Title},
+    game::{CorrespondenceDays, Rules, Speed, VariantMode},
 };
 
 /// Optional configuration for creating challenges using [`challenges().ai()`](fn@crate::api::challenges::ChallengesApi::ai).
@@ -254,3 +257,167 @@ impl OpenChallengeOptions {
         self
     }
 }
+
+/// Determines which kinds of opponents are accepted by a [`ChallengePolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OpponentKind {
+    /// Accept challenges from both bots and humans.
+    #[default]
+    Any,
+    /// Only accept challenges from bot accounts.
+    Bots,
+    /// Only accept challenges from human accounts.
+    Humans,
+}
+
+/// A declarative policy for deciding which incoming challenges to accept.
+///
+/// The policy can be evaluated with [`evaluate()`](fn@ChallengePolicy::evaluate)
+/// or applied directly with [`challenges().apply_policy()`](fn@crate::client::ChallengesApi::apply_policy).
+/// By default, every challenge is accepted.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ChallengePolicy {
+    variants: Option<Vec<VariantMode>>,
+    min_speed: Option<Speed>,
+    max_speed: Option<Speed>,
+    rated: Option<bool>,
+    min_rating: Option<u16>,
+    max_rating: Option<u16>,
+    opponents: OpponentKind,
+    blocklist: HashSet<String>,
+    max_games: Option<usize>,
+}
+
+impl ChallengePolicy {
+    /// Create a new instance of [`ChallengePolicy`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines which variants are accepted.
+    /// Defaults to all variants.
+    #[must_use]
+    pub fn variants(mut self, variants: &[VariantMode]) -> Self {
+        self.variants = Some(variants.into());
+        self
+    }
+
+    /// Determines the fastest and slowest accepted speeds, e.g. from bullet to rapid.
+    /// Defaults to all speeds.
+    #[must_use]
+    pub fn speeds(mut self, min: Speed, max: Speed) -> Self {
+        self.min_speed = Some(min);
+        self.max_speed = Some(max);
+        self
+    }
+
+    /// Determines whether only rated (`true`) or only casual (`false`) games are accepted.
+    /// Defaults to both.
+    #[must_use]
+    pub fn rated(mut self, rated: bool) -> Self {
+        self.rated = Some(rated);
+        self
+    }
+
+    /// Determines the lowest and highest accepted ratings of the challenger.
+    /// Challengers without a rating are accepted.
+    /// Defaults to all ratings.
+    #[must_use]
+    pub fn rating(mut self, min: u16, max: u16) -> Self {
+        self.min_rating = Some(min);
+        self.max_rating = Some(max);
+        self
+    }
+
+    /// Determines whether bots, humans or both are accepted as opponents.
+    /// Defaults to both.
+    #[must_use]
+    pub fn opponents(mut self, opponents: OpponentKind) -> Self {
+        self.opponents = opponents;
+        self
+    }
+
+    /// Determines the users whose challenges are always declined.
+    /// Does not have a default value.
+    #[must_use]
+    pub fn blocklist(mut self, users: &[&str]) -> Self {
+        self.blocklist = users.iter().map(|user| user.to_lowercase()).collect();
+        self
+    }
+
+    /// Determines how many games can be played at the same time.
+    /// Defaults to unlimited.
+    #[must_use]
+    pub fn max_games(mut self, max_games: usize) -> Self {
+        self.max_games = Some(max_games);
+        self
+    }
+
+    /// Decide whether to accept a challenge while the given number of games are ongoing.
+    /// If the challenge is declined, the decision contains the reason shown to the challenger.
+    #[must_use]
+    pub fn evaluate(&self, challenge: &Challenge, ongoing_games: usize) -> ChallengeDecision {
+        match self.decline_reason(challenge, ongoing_games) {
+            Some(reason) => ChallengeDecision::Decline(reason),
+            None => ChallengeDecision::Accept,
+        }
+    }
+
+    // Find the first rule the challenge does not satisfy
+    fn decline_reason(
+        &self,
+        challenge: &Challenge,
+        ongoing_games: usize,
+    ) -> Option<ChallengeDeclineReason> {
+        let challenger = &challenge.challenger;
+
+        if self.blocklist.contains(&challenger.id.to_lowercase()) {
+            return Some(ChallengeDeclineReason::Generic);
+        }
+
+        if self.max_games.is_some_and(|max| ongoing_games >= max) {
+            return Some(ChallengeDeclineReason::Later);
+        }
+
+        let is_bot = challenger.title == Some(Title::BOT);
+        match self.opponents {
+            OpponentKind::Bots if !is_bot => return Some(ChallengeDeclineReason::OnlyBot),
+            OpponentKind::Humans if is_bot => return Some(ChallengeDeclineReason::NoBot),
+            _ => {}
+        }
+
+        if let Some(variants) = &self.variants {
+            if !variants.contains(&challenge.variant.key) {
+                return Some(if variants == &[VariantMode::Standard] {
+                    ChallengeDeclineReason::Standard
+                } else {
+                    ChallengeDeclineReason::Variant
+                });
+            }
+        }
+
+        if self.min_speed.is_some_and(|min| challenge.speed < min) {
+            return Some(ChallengeDeclineReason::TooFast);
+        }
+        if self.max_speed.is_some_and(|max| challenge.speed > max) {
+            return Some(ChallengeDeclineReason::TooSlow);
+        }
+
+        match self.rated {
+            Some(true) if !challenge.rated => return Some(ChallengeDeclineReason::Rated),
+            Some(false) if challenge.rated => return Some(ChallengeDeclineReason::Casual),
+            _ => {}
+        }
+
+        if let Some(rating) = challenger.rating {
+            let too_low = self.min_rating.is_some_and(|min| rating < min);
+            let too_high = self.max_rating.is_some_and(|max| rating > max);
+            if too_low || too_high {
+                return Some(ChallengeDeclineReason::Generic);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,599 @@
+use std::{
+    borrow::Borrow,
+    collections::{hash_map::RandomState, BTreeMap, HashMap},
+    fmt::Debug,
+    hash::{BuildHasher, Hash, Hasher},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{Method, Request, Response, StatusCode, Url};
+
+// Lichess asks clients to wait a full minute after receiving a 429 response
+pub(crate) const DEFAULT_RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(60);
+const DEFAULT_RATE_LIMIT_RETRIES: u8 = 3;
+
+const DEFAULT_RETRY_ATTEMPTS: u8 = 3;
+const DEFAULT_RETRY_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const DEFAULT_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(10);
+#[cfg(feature = "streaming")]
+const DEFAULT_RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+#[cfg(feature = "streaming")]
+const DEFAULT_RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+const DEFAULT_CACHE_CAPACITY: usize = 1000;
+
+// Explorer and tablebase data changes rarely, and tablebase results or masters games never change
+const DEFAULT_CACHE_TTLS: [(&str, Duration); 9] = [
+    ("/masters", Duration::from_secs(24 * 60 * 60)),
+    ("/masters/pgn/*", Duration::from_secs(30 * 24 * 60 * 60)),
+    ("/lichess", Duration::from_secs(60 * 60)),
+    ("/standard", Duration::from_secs(30 * 24 * 60 * 60)),
+    ("/atomic", Duration::from_secs(30 * 24 * 60 * 60)),
+    ("/antichess", Duration::from_secs(30 * 24 * 60 * 60)),
+    ("/api/player", Duration::from_secs(10 * 60)),
+    ("/api/player/top/*/*", Duration::from_secs(10 * 60)),
+    ("/api/user/*/rating-history", Duration::from_secs(10 * 60)),
+];
+
+const DEFAULT_RETRY_STATUSES: [StatusCode; 4] = [
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+/// A callback that is invoked every time the rate limit handling is triggered.
+pub type RateLimitHook = Arc<dyn Fn(&RateLimitEvent) + Send + Sync>;
+
+/// Configuration for handling `429 Too Many Requests` responses, used with
+/// [`LicheszterBuilder::with_rate_limit()`](fn@crate::client::LicheszterBuilder::with_rate_limit).
+///
+/// When a request is rate limited, all requests sent through the same [`Licheszter`](struct@crate::client::Licheszter)
+/// instance are paused for the cooldown period, after which the rate limited request is retried.
+#[derive(Clone)]
+pub struct RateLimitOptions {
+    pub(crate) cooldown: Duration,
+    pub(crate) max_retries: u8,
+    pub(crate) hook: Option<RateLimitHook>,
+}
+
+impl RateLimitOptions {
+    /// Create a new instance of [`RateLimitOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines how long all requests are paused after a rate limited response.
+    /// Defaults to 60 seconds, as mandated by Lichess.
+    #[must_use]
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Determines how many times a single rate limited request is retried before giving up.
+    /// Once the retries are exhausted, the `429` response is returned as an error.
+    /// Defaults to 3.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u8) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set a callback that is invoked every time a rate limited response is received.
+    /// This can be used, for example, to log when the rate limit handling is triggered.
+    #[must_use]
+    pub fn on_rate_limit<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RateLimitEvent) + Send + Sync + 'static,
+    {
+        self.hook = Some(Arc::new(hook));
+        self
+    }
+}
+
+impl Default for RateLimitOptions {
+    fn default() -> Self {
+        Self {
+            cooldown: DEFAULT_RATE_LIMIT_COOLDOWN,
+            max_retries: DEFAULT_RATE_LIMIT_RETRIES,
+            hook: None,
+        }
+    }
+}
+
+impl Debug for RateLimitOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimitOptions")
+            .field("cooldown", &self.cooldown)
+            .field("max_retries", &self.max_retries)
+            .field("hook", &self.hook.is_some())
+            .finish()
+    }
+}
+
+/// Information about a rate limited request, passed to the hook set with
+/// [`RateLimitOptions::on_rate_limit()`](fn@RateLimitOptions::on_rate_limit).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitEvent {
+    /// The HTTP method of the rate limited request.
+    pub method: Method,
+    /// The URL of the rate limited request.
+    pub url: Url,
+    /// How many times the request has been rate limited so far, starting from 1.
+    pub attempt: u8,
+    /// How long all requests are paused before the request is retried.
+    /// This is `None` if the retries have been exhausted and the request will not be retried.
+    pub cooldown: Option<Duration>,
+}
+
+/// A middleware that is invoked for every request sent by [`Licheszter`](struct@crate::client::Licheszter),
+/// registered with [`LicheszterBuilder::with_middleware()`](fn@crate::client::LicheszterBuilder::with_middleware).
+///
+/// The middleware sees every attempt of a request, including retries and the requests that open or reopen streams.
+/// This can be used, for example, for logging, collecting metrics or adding custom headers to the requests.
+/// All methods do nothing by default, so only the needed ones have to be implemented.
+pub trait Middleware: Send + Sync + 'static {
+    /// Called right before a request is sent.
+    /// The request can be modified, e.g. to add headers or to sign it.
+    fn on_request(&self, _request: &mut Request) {}
+
+    /// Called after the response headers have been received.
+    /// The response may still contain an error status code, and the body of streams has not been read yet.
+    fn on_response(&self, _event: &RequestEvent, _response: &Response) {}
+
+    /// Called when the request could not be sent or no response was received.
+    fn on_error(&self, _event: &RequestEvent, _error: &reqwest::Error) {}
+}
+
+/// Information about a sent request, passed to the [`Middleware`] along with its outcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestEvent {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The URL of the request.
+    pub url: Url,
+    /// How long it took to receive the response headers or the error.
+    pub elapsed: Duration,
+}
+
+/// Configuration for retrying requests that failed due to transient errors, used with
+/// [`LicheszterBuilder::with_retry_policy()`](fn@crate::client::LicheszterBuilder::with_retry_policy).
+///
+/// Requests are retried after connection failures, timeouts and responses with a retryable status code.
+/// Only idempotent requests (such as `GET`) are retried, unless configured otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u8,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) jitter: bool,
+    pub(crate) statuses: Vec<StatusCode>,
+    pub(crate) non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new instance of [`RetryPolicy`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines how many times a request is sent in total, including the first attempt.
+    /// Defaults to 3.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u8) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Determines how long to wait before the first retry.
+    /// The wait time is doubled after every failed attempt.
+    /// Defaults to 500 milliseconds.
+    #[must_use]
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Determines the maximum wait time between two attempts.
+    /// Defaults to 10 seconds.
+    #[must_use]
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Determines whether the wait times are randomized to avoid several clients retrying at once.
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Determines which HTTP status codes are considered transient and retried.
+    /// Defaults to `500`, `502`, `503` and `504`.
+    #[must_use]
+    pub fn statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Determines whether non-idempotent requests (such as `POST`) are retried as well.
+    /// Retrying them may cause the same action to be performed several times, e.g. a move to be played twice.
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn retry_non_idempotent(mut self, enable: bool) -> Self {
+        self.non_idempotent = enable;
+        self
+    }
+
+    // Check whether requests with the given method may be retried
+    pub(crate) fn allows(&self, method: &Method) -> bool {
+        self.non_idempotent || method.is_idempotent()
+    }
+
+    // Check whether the given status code is considered transient
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    // Calculate the wait time before the next attempt, starting from 1 for the first retry
+    pub(crate) fn backoff(&self, retry: u8) -> Duration {
+        backoff(
+            self.initial_backoff,
+            self.max_backoff,
+            u32::from(retry),
+            self.jitter,
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_RETRY_ATTEMPTS,
+            initial_backoff: DEFAULT_RETRY_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_RETRY_MAX_BACKOFF,
+            jitter: true,
+            statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+            non_idempotent: false,
+        }
+    }
+}
+
+/// Configuration for streams that reopen themselves after the connection is lost, used with e.g.
+/// [`Licheszter::connect_resilient()`](fn@crate::client::Licheszter::connect_resilient).
+///
+/// Reconnecting is attempted after network errors, server errors and rate limited responses,
+/// waiting at least as long as Lichess asks for. Any other error returned by Lichess ends the stream.
+#[cfg(feature = "streaming")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReconnectOptions {
+    pub(crate) max_attempts: Option<u32>,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) jitter: bool,
+}
+
+#[cfg(feature = "streaming")]
+impl ReconnectOptions {
+    /// Create a new instance of [`ReconnectOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines how many times in a row reconnecting is attempted before the stream gives up.
+    /// The counter is reset every time an item is received.
+    /// Unlimited by default.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Determines how long to wait before the first reconnection attempt.
+    /// The wait time is doubled after every failed attempt.
+    /// Defaults to 1 second.
+    #[must_use]
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Determines the maximum wait time between two reconnection attempts.
+    /// Defaults to 60 seconds.
+    #[must_use]
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Determines whether the wait times are randomized to avoid several clients reconnecting at once.
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    // Calculate the wait time before the next attempt, starting from 1 for the first reconnection
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        backoff(self.initial_backoff, self.max_backoff, attempt, self.jitter)
+    }
+}
+
+#[cfg(feature = "streaming")]
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            initial_backoff: DEFAULT_RECONNECT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_RECONNECT_MAX_BACKOFF,
+            jitter: true,
+        }
+    }
+}
+
+/// Configuration for caching the responses of rarely changing endpoints, used with
+/// [`LicheszterBuilder::with_cache()`](fn@crate::client::LicheszterBuilder::with_cache).
+///
+/// Only `GET` requests to the paths with a time to live are cached, by default the opening explorer,
+/// the tablebases, the leaderboards and the rating histories.
+/// A `max-age` in the `Cache-Control` header of a response takes precedence over the configured time to live,
+/// and responses with `no-store` are not cached at all.
+/// Expired responses with an `ETag` are revalidated, so an unchanged response is not downloaded again.
+/// The cached responses are shared between all requests of the client, regardless of the authentication.
+#[derive(Clone)]
+pub struct CacheOptions {
+    pub(crate) backend: Arc<dyn CacheBackend>,
+    pub(crate) ttls: Vec<(String, Duration)>,
+}
+
+impl CacheOptions {
+    /// Create a new instance of [`CacheOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines where the responses are stored.
+    /// Defaults to a [`MemoryCache`] holding up to 1000 responses.
+    #[must_use]
+    pub fn backend<B: CacheBackend>(mut self, backend: B) -> Self {
+        self.backend = Arc::new(backend);
+        self
+    }
+
+    /// Determines how long the responses of requests to the path are cached.
+    /// A `*` segment in the path matches any single segment, e.g. `/api/user/*/rating-history`.
+    /// A zero duration disables caching for the path, which is also useful for overriding the defaults.
+    #[must_use]
+    pub fn ttl(mut self, path: &str, ttl: Duration) -> Self {
+        self.ttls.retain(|(pattern, _)| pattern != path);
+        self.ttls.push((path.to_string(), ttl));
+        self
+    }
+
+    /// Disable caching for all paths, including the defaults, so only the paths configured afterwards are cached.
+    #[must_use]
+    pub fn clear_ttls(mut self) -> Self {
+        self.ttls.clear();
+        self
+    }
+
+    // Get the time to live of the responses of requests to the given path, if they are cached
+    pub(crate) fn ttl_for(&self, path: &str) -> Option<Duration> {
+        self.ttls
+            .iter()
+            .find(|(pattern, _)| path_matches(pattern, path))
+            .map(|(_, ttl)| *ttl)
+            .filter(|ttl| !ttl.is_zero())
+    }
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            backend: Arc::new(MemoryCache::new(DEFAULT_CACHE_CAPACITY)),
+            ttls: DEFAULT_CACHE_TTLS
+                .iter()
+                .map(|(path, ttl)| ((*path).to_string(), *ttl))
+                .collect(),
+        }
+    }
+}
+
+impl Debug for CacheOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheOptions")
+            .field("ttls", &self.ttls)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A storage for the responses cached by [`CacheOptions`].
+///
+/// [`MemoryCache`] keeps the responses in memory, other backends (e.g. a file or a database) can be plugged in
+/// by implementing this trait. The keys are the full URLs of the requests, including the query.
+///
+/// Cloud evaluations looked up in bulk have their own `CloudEvalCache` (enabled by the `analysis` feature),
+/// which is keyed by position rather than by URL.
+pub trait CacheBackend: Send + Sync + 'static {
+    /// Get the response stored with the key, whether it is fresh or not.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Store a response with the key, replacing any previous response.
+    fn insert(&self, key: &str, response: CachedResponse);
+}
+
+/// A response stored in a [`CacheBackend`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// The body of the response.
+    pub body: Vec<u8>,
+    /// The `ETag` header of the response, used to revalidate the response after it has expired.
+    pub etag: Option<String>,
+    /// The time after which the response has to be revalidated or fetched again.
+    pub expires: SystemTime,
+}
+
+impl CachedResponse {
+    /// Returns whether the response can still be used without contacting the server.
+    #[must_use]
+    pub fn is_fresh(&self) -> bool {
+        SystemTime::now() < self.expires
+    }
+}
+
+/// A [`CacheBackend`] that keeps a limited number of responses in memory,
+/// evicting the least recently used response when it is full.
+#[derive(Debug)]
+pub struct MemoryCache {
+    entries: Mutex<Lru<String, CachedResponse>>,
+}
+
+// A map holding a limited number of entries, evicting the least recently used entry when it is full
+#[derive(Debug)]
+pub(crate) struct Lru<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    // The keys ordered by the tick of their latest use
+    order: BTreeMap<u64, K>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Lru<K, V> {
+    // Create an empty map holding up to the given number of entries, at least 1
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tick: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    // Get an entry, marking it as the most recently used one
+    pub(crate) fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.tick += 1;
+        let (value, used) = self.entries.get_mut(key)?;
+        if let Some(key) = self.order.remove(used) {
+            self.order.insert(self.tick, key);
+        }
+        *used = self.tick;
+        Some(value.clone())
+    }
+
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        self.tick += 1;
+        if let Some((_, used)) = self.entries.insert(key.clone(), (value, self.tick)) {
+            self.order.remove(&used);
+        }
+        self.order.insert(self.tick, key);
+
+        // Evict the least recently used entries
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+impl MemoryCache {
+    /// Create a new, empty [`MemoryCache`] holding up to the given number of responses.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// Returns the number of cached responses.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns whether the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached responses.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+    }
+
+    fn insert(&self, key: &str, response: CachedResponse) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_string(), response);
+    }
+}
+
+// Check whether the path matches the pattern, where `*` matches any single segment
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let mut pattern = pattern.trim_end_matches('/').split('/');
+    let mut path = path.trim_end_matches('/').split('/');
+    loop {
+        match (pattern.next(), path.next()) {
+            (None, None) => return true,
+            (Some("*"), Some(_)) => {}
+            (Some(expected), Some(segment)) if expected == segment => {}
+            _ => return false,
+        }
+    }
+}
+
+// Calculate an exponentially growing wait time, optionally randomized
+fn backoff(initial: Duration, max: Duration, attempt: u32, jitter: bool) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(31);
+    let backoff = initial.saturating_mul(1 << exponent).min(max);
+
+    if !jitter {
+        return backoff;
+    }
+
+    // Keep at least half of the wait time and randomize the rest
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(attempt);
+    let random = (hasher.finish() >> 11) as f64 / (1_u64 << 53) as f64;
+    backoff / 2 + backoff.mul_f64(random) / 2
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::external_engine::{EngineVariant, EngineWork};
+
+/// Configuration for registering or updating an external engine using
+/// [`external_engine().create()`](fn@crate::client::ExternalEngineApi::create)
+/// or [`external_engine().update()`](fn@crate::client::ExternalEngineApi::update).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEngineOptions {
+    name: String,
+    max_threads: u32,
+    max_hash: u32,
+    default_depth: Option<u16>,
+    variants: Option<Vec<EngineVariant>>,
+    provider_secret: String,
+    provider_data: Option<String>,
+}
+
+impl ExternalEngineOptions {
+    /// Create a new instance of [`ExternalEngineOptions`].
+    /// The provider secret is used by the provider to acquire analysis work for the engine,
+    /// it should be a random string of at least 16 characters.
+    #[must_use]
+    pub fn new(name: &str, provider_secret: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            max_threads: 1,
+            max_hash: 16,
+            provider_secret: provider_secret.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Determines the maximum number of threads the engine can use.
+    /// Defaults to 1.
+    #[must_use]
+    pub fn max_threads(mut self, max_threads: u32) -> Self {
+        self.max_threads = max_threads;
+        self
+    }
+
+    /// Determines the maximum hash table size of the engine in MiB.
+    /// Defaults to 16.
+    #[must_use]
+    pub fn max_hash(mut self, max_hash: u32) -> Self {
+        self.max_hash = max_hash;
+        self
+    }
+
+    /// Determines the depth the engine analyses to by default.
+    #[must_use]
+    pub fn default_depth(mut self, default_depth: u16) -> Self {
+        self.default_depth = Some(default_depth);
+        self
+    }
+
+    /// Determines the variants the engine supports.
+    /// Defaults to standard chess only.
+    #[must_use]
+    pub fn variants(mut self, variants: &[EngineVariant]) -> Self {
+        self.variants = Some(variants.to_vec());
+        self
+    }
+
+    /// Arbitrary data stored with the engine, which is only visible to the provider.
+    #[must_use]
+    pub fn provider_data(mut self, provider_data: &str) -> Self {
+        self.provider_data = Some(provider_data.to_string());
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AnalysisRequest {
+    pub(crate) client_secret: String,
+    pub(crate) work: EngineWork,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WorkRequest {
+    pub(crate) provider_secret: String,
+}
@@ -1,10 +1,26 @@
+#[cfg(feature = "analysis")]
+pub mod analysis;
+
+pub mod client;
+
 #[cfg(feature = "board")]
 pub mod board;
 
+#[cfg(feature = "broadcasts")]
+pub mod broadcasts;
+
 #[cfg(feature = "challenges")]
 pub mod challenges;
 
-#[cfg(feature = "games")]
+#[cfg(feature = "external_engine")]
+pub mod external_engine;
+
+#[cfg(any(
+    feature = "games",
+    feature = "pairings",
+    feature = "swiss",
+    feature = "tournaments"
+))]
 pub mod games;
 
 #[cfg(feature = "openings")]
@@ -16,13 +32,25 @@ pub mod pairings;
 #[cfg(feature = "puzzles")]
 pub mod puzzles;
 
+#[cfg(feature = "studies")]
+pub mod studies;
+
+#[cfg(feature = "swiss")]
+pub mod swiss;
+
+#[cfg(feature = "tournaments")]
+pub mod tournaments;
+
 #[cfg(feature = "tv")]
 pub mod tv;
 
+#[cfg(feature = "uci")]
+pub mod uci;
+
 #[cfg(feature = "users")]
 pub mod users;
 
-#[cfg(any(feature = "challenges", feature = "pairings"))]
+#[cfg(any(feature = "challenges", feature = "pairings", feature = "swiss"))]
 pub(super) fn set_clock(clock_limit: u16, clock_increment: u8) -> (u16, u8) {
     let limit = match clock_limit {
         0 | 15 | 30 | 45 | 60 | 90 => clock_limit,
@@ -1,7 +1,10 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::{
+    common::Color,
     game::{GameType, Speed, VariantMode},
     openings::OpeningRatings,
 };
@@ -279,3 +282,190 @@ impl PlayerOpeningsOptions {
         self
     }
 }
+
+/// The opening database crawled by [`openings().crawl()`](fn@crate::client::OpeningsApi::crawl),
+/// along with the options used for every position.
+/// The FEN and the moves of the options are replaced by the position of each crawled node.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExplorerSource {
+    /// The Masters opening database.
+    Masters(MastersOpeningsOptions),
+    /// The Lichess opening database.
+    Lichess(LichessOpeningsOptions),
+    /// The games of a single player with the given color.
+    Player {
+        /// The username of the player.
+        player: String,
+        /// The color the player played with.
+        color: Color,
+        /// The options used for every position.
+        options: PlayerOpeningsOptions,
+    },
+}
+
+impl ExplorerSource {
+    // Get the source with the options pointed at the given position,
+    // keeping the default starting position of the variant if no FEN is given
+    pub(crate) fn at(&self, fen: Option<&str>, play: &[&str]) -> Self {
+        match self {
+            Self::Masters(options) => {
+                let options = with_play(options, play, MastersOpeningsOptions::play);
+                Self::Masters(match fen {
+                    Some(fen) => options.fen(fen),
+                    None => options,
+                })
+            }
+            Self::Lichess(options) => {
+                let options = with_play(options, play, LichessOpeningsOptions::play);
+                Self::Lichess(match fen {
+                    Some(fen) => options.fen(fen),
+                    None => options,
+                })
+            }
+            Self::Player {
+                player,
+                color,
+                options,
+            } => {
+                let options = with_play(options, play, PlayerOpeningsOptions::play);
+                Self::Player {
+                    player: player.clone(),
+                    color: *color,
+                    options: match fen {
+                        Some(fen) => options.fen(fen),
+                        None => options,
+                    },
+                }
+            }
+        }
+    }
+}
+
+// Play the moves from the root position, leaving the root position itself unchanged
+fn with_play<T>(options: &T, play: &[&str], set_play: fn(T, &[&str]) -> T) -> T
+where
+    T: Clone,
+{
+    if play.is_empty() {
+        options.clone()
+    } else {
+        set_play(options.clone(), play)
+    }
+}
+
+/// Configuration for crawling the opening explorer using [`openings().crawl()`](fn@crate::client::OpeningsApi::crawl).
+///
+/// Every expanded position costs one request, so the tree is bounded by its depth and pruned
+/// by the number of games of each move. Requests are sent one at a time, as asked by Lichess.
+/// Rate limited requests are not retried by the crawl itself, so enable
+/// [`with_rate_limit()`](fn@crate::client::LicheszterBuilder::with_rate_limit) on the client for large crawls.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct CrawlOptions {
+    fen: Option<String>,
+    max_depth: u16,
+    min_games: u64,
+    min_frequency: f64,
+    max_moves: Option<usize>,
+    delay: Duration,
+}
+
+impl CrawlOptions {
+    /// Create a new instance of [`CrawlOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the FEN of the root position.
+    /// Defaults to the starting position.
+    #[must_use]
+    pub fn fen(mut self, fen: &str) -> Self {
+        self.fen = Some(fen.to_string());
+        self
+    }
+
+    /// Determines the maximum depth of the tree in plies from the root position.
+    /// Defaults to 4.
+    #[must_use]
+    pub fn max_depth(mut self, max_depth: u16) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Determines the minimum number of games a move must have been played in to be included.
+    /// Defaults to 1.
+    #[must_use]
+    pub fn min_games(mut self, min_games: u64) -> Self {
+        self.min_games = min_games;
+        self
+    }
+
+    /// Determines the minimum share of the games of its position a move must have been played in to be included,
+    /// between 0 and 1.
+    /// Defaults to 0.
+    #[must_use]
+    pub fn min_frequency(mut self, min_frequency: f64) -> Self {
+        self.min_frequency = min_frequency.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Determines the maximum number of the most common moves included for every position.
+    /// Defaults to all moves returned by the opening explorer.
+    #[must_use]
+    pub fn max_moves(mut self, max_moves: usize) -> Self {
+        self.max_moves = Some(max_moves);
+        self
+    }
+
+    /// Determines how long to wait between requests.
+    /// Defaults to 0 seconds.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    // Get the FEN of the root position
+    pub(crate) fn root_fen(&self) -> Option<&str> {
+        self.fen.as_deref()
+    }
+
+    // Check whether the moves of a position at the given depth are looked up
+    pub(crate) fn expands(&self, depth: usize) -> bool {
+        depth < usize::from(self.max_depth)
+    }
+
+    // Check whether a move played in the given number of games is kept
+    pub(crate) fn keeps(&self, games: u64, parent_games: u64) -> bool {
+        #[allow(clippy::cast_precision_loss)]
+        let frequency = if parent_games == 0 {
+            0.0
+        } else {
+            games as f64 / parent_games as f64
+        };
+        games >= self.min_games && frequency >= self.min_frequency
+    }
+
+    // Get the maximum number of moves kept for every position
+    pub(crate) fn move_limit(&self) -> usize {
+        self.max_moves.unwrap_or(usize::MAX)
+    }
+
+    // Get the delay between requests
+    pub(crate) fn request_delay(&self) -> Duration {
+        self.delay
+    }
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            fen: None,
+            max_depth: 4,
+            min_games: 1,
+            min_frequency: 0.0,
+            max_moves: None,
+            delay: Duration::ZERO,
+        }
+    }
+}
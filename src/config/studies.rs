@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{common::FinalColor, game::VariantMode};
+
+/// Optional configuration for exporting studies in PGN format.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+pub struct StudyExportOptions {
+    clocks: Option<bool>,
+    comments: Option<bool>,
+    variations: Option<bool>,
+    orientation: Option<bool>,
+}
+
+impl StudyExportOptions {
+    /// Create a new instance of [`StudyExportOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include the clock comments when available.
+    #[must_use]
+    pub fn clocks(mut self, clocks: bool) -> Self {
+        self.clocks = Some(clocks);
+        self
+    }
+
+    /// Include the analysis and annotator comments.
+    #[must_use]
+    pub fn comments(mut self, comments: bool) -> Self {
+        self.comments = Some(comments);
+        self
+    }
+
+    /// Include the non-mainline moves.
+    #[must_use]
+    pub fn variations(mut self, variations: bool) -> Self {
+        self.variations = Some(variations);
+        self
+    }
+
+    /// Include the orientation of the board as a PGN tag.
+    #[must_use]
+    pub fn orientation(mut self, orientation: bool) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+}
+
+/// Optional configuration for importing PGN into studies using [`studies().import_pgn()`](fn@crate::client::StudiesApi::import_pgn).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+pub struct StudyImportOptions {
+    name: Option<String>,
+    orientation: Option<FinalColor>,
+    variant: Option<VariantMode>,
+}
+
+impl StudyImportOptions {
+    /// Create a new instance of [`StudyImportOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the name of the new chapter.
+    /// If multiple games are imported, the name is only used for the first chapter and the rest are named after the players.
+    /// Defaults to the names of the players.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Determines the orientation of the board in the new chapters.
+    /// Defaults to white.
+    #[must_use]
+    pub fn orientation(mut self, orientation: FinalColor) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    /// Determines the game variant of the new chapters.
+    /// Defaults to Standard.
+    #[must_use]
+    pub fn variant(mut self, variant: VariantMode) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+}
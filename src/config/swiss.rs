@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{game::VariantMode, swiss::SwissChat};
+
+/// Configuration for creating and editing Swiss tournaments using
+/// [`swiss().create()`](fn@crate::client::SwissApi::create) and [`swiss().edit()`](fn@crate::client::SwissApi::edit).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SwissOptions {
+    name: Option<String>,
+    #[serde(rename = "clock.limit")]
+    clock_limit: Option<u16>,
+    #[serde(rename = "clock.increment")]
+    clock_increment: Option<u8>,
+    nb_rounds: Option<u8>,
+    starts_at: Option<u64>,
+    round_interval: Option<u32>,
+    variant: Option<VariantMode>,
+    position: Option<String>,
+    description: Option<String>,
+    rated: Option<bool>,
+    password: Option<String>,
+    forbidden_pairings: Option<String>,
+    manual_pairings: Option<String>,
+    chat_for: Option<SwissChat>,
+    #[serde(rename = "conditions.minRating.rating")]
+    min_rating: Option<u16>,
+    #[serde(rename = "conditions.maxRating.rating")]
+    max_rating: Option<u16>,
+    #[serde(rename = "conditions.nbRatedGame.nb")]
+    min_rated_games: Option<u16>,
+    #[serde(rename = "conditions.allowList")]
+    allow_list: Option<String>,
+    #[serde(rename = "conditions.playYourGames")]
+    play_your_games: Option<bool>,
+    #[serde(rename = "conditions.titled")]
+    titled: Option<bool>,
+    #[serde(rename = "conditions.accountAge")]
+    account_age: Option<u16>,
+}
+
+impl SwissOptions {
+    /// Create a new instance of [`SwissOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the name of the tournament.
+    /// Defaults to a random grandmaster name.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Determines the clock settings for the tournament.
+    /// Invalid clock limit values default to 0 and clock increment values over 180 default to 180.
+    /// This is required when creating a tournament.
+    #[must_use]
+    pub fn clock(mut self, clock_limit: u16, clock_increment: u8) -> Self {
+        let (limit, increment) = super::set_clock(clock_limit, clock_increment);
+        self.clock_limit = Some(limit);
+        self.clock_increment = Some(increment);
+        self
+    }
+
+    /// Determines the maximum number of rounds to play.
+    /// This is required when creating a tournament.
+    #[must_use]
+    pub fn nb_rounds(mut self, nb_rounds: u8) -> Self {
+        self.nb_rounds = Some(nb_rounds);
+        self
+    }
+
+    /// Determines when the tournament starts.
+    /// The timestamp is in MILLISECONDS.
+    /// Defaults to 10 minutes from the creation of the tournament.
+    #[must_use]
+    pub fn starts_at(mut self, timestamp: u64) -> Self {
+        self.starts_at = Some(timestamp);
+        self
+    }
+
+    /// Determines how long to wait between each round, in seconds.
+    /// Set to 99999999 to schedule the rounds manually.
+    /// Defaults to an automatic interval depending on the clock settings.
+    #[must_use]
+    pub fn round_interval(mut self, seconds: u32) -> Self {
+        self.round_interval = Some(seconds);
+        self
+    }
+
+    /// Determines the game variant.
+    /// Defaults to Standard.
+    #[must_use]
+    pub fn variant(mut self, variant: VariantMode) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Determines a custom FEN string for the starting position of the games.
+    /// Requires the variant to be set as Standard and the tournament to be unrated.
+    /// Defaults to the default chess starting position.
+    #[must_use]
+    pub fn position(mut self, fen: &str) -> Self {
+        self.position = Some(fen.to_string());
+        self
+    }
+
+    /// Set a custom description for the tournament.
+    #[must_use]
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Determines whether the games are rated or not.
+    /// Defaults to true.
+    #[must_use]
+    pub fn rated(mut self, rated: bool) -> Self {
+        self.rated = Some(rated);
+        self
+    }
+
+    /// Make the tournament private and require a password to join it.
+    #[must_use]
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Prevent the given pairs of players from playing against each other.
+    /// Each pair consists of two usernames.
+    #[must_use]
+    pub fn forbidden_pairings(mut self, pairs: &[(&str, &str)]) -> Self {
+        self.forbidden_pairings = Some(join_pairs(pairs));
+        self
+    }
+
+    /// Manually pair the given players for the next round instead of using the automatic pairing system.
+    /// Each pair consists of the usernames of the white and black player.
+    /// A player can be given a bye by pairing them with the username "1".
+    #[must_use]
+    pub fn manual_pairings(mut self, pairs: &[(&str, &str)]) -> Self {
+        self.manual_pairings = Some(join_pairs(pairs));
+        self
+    }
+
+    /// Determines who can read and write in the tournament chat.
+    /// Defaults to team members only.
+    #[must_use]
+    pub fn chat_for(mut self, chat: SwissChat) -> Self {
+        self.chat_for = Some(chat);
+        self
+    }
+
+    /// Determines the minimum rating required to join the tournament.
+    #[must_use]
+    pub fn min_rating(mut self, rating: u16) -> Self {
+        self.min_rating = Some(rating);
+        self
+    }
+
+    /// Determines the maximum rating allowed to join the tournament.
+    #[must_use]
+    pub fn max_rating(mut self, rating: u16) -> Self {
+        self.max_rating = Some(rating);
+        self
+    }
+
+    /// Determines the minimum number of rated games required to join the tournament.
+    #[must_use]
+    pub fn min_rated_games(mut self, games: u16) -> Self {
+        self.min_rated_games = Some(games);
+        self
+    }
+
+    /// Restrict the tournament to the given usernames.
+    #[must_use]
+    pub fn allow_list(mut self, usernames: &[&str]) -> Self {
+        self.allow_list = Some(usernames.join(","));
+        self
+    }
+
+    /// Determines whether players who abandoned their previous Swiss tournaments are prevented from joining.
+    /// Defaults to false.
+    #[must_use]
+    pub fn play_your_games(mut self, play_your_games: bool) -> Self {
+        self.play_your_games = Some(play_your_games);
+        self
+    }
+
+    /// Determines whether only titled players can join the tournament.
+    /// Defaults to false.
+    #[must_use]
+    pub fn titled(mut self, titled: bool) -> Self {
+        self.titled = Some(titled);
+        self
+    }
+
+    /// Determines the minimum account age in days required to join the tournament.
+    #[must_use]
+    pub fn account_age(mut self, days: u16) -> Self {
+        self.account_age = Some(days);
+        self
+    }
+}
+
+// Join pairs of usernames into the line-separated format used by Lichess
+fn join_pairs(pairs: &[(&str, &str)]) -> String {
+    pairs
+        .iter()
+        .map(|(first, second)| format!("{first} {second}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
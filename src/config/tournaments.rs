@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::game::VariantMode;
+
+/// Configuration for creating and updating arena tournaments using
+/// [`tournaments().create()`](fn@crate::client::TournamentsApi::create) and [`tournaments().update()`](fn@crate::client::TournamentsApi::update).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaTournamentOptions {
+    name: Option<String>,
+    clock_time: Option<String>,
+    clock_increment: Option<u8>,
+    minutes: Option<u16>,
+    wait_minutes: Option<u8>,
+    start_date: Option<u64>,
+    variant: Option<VariantMode>,
+    rated: Option<bool>,
+    position: Option<String>,
+    berserkable: Option<bool>,
+    streakable: Option<bool>,
+    has_chat: Option<bool>,
+    description: Option<String>,
+    password: Option<String>,
+    team_battle_by_team: Option<String>,
+    #[serde(rename = "conditions.teamMember.teamId")]
+    team_member: Option<String>,
+    #[serde(rename = "conditions.minRating.rating")]
+    min_rating: Option<u16>,
+    #[serde(rename = "conditions.maxRating.rating")]
+    max_rating: Option<u16>,
+    #[serde(rename = "conditions.nbRatedGame.nb")]
+    min_rated_games: Option<u16>,
+    #[serde(rename = "conditions.allowList")]
+    allow_list: Option<String>,
+    #[serde(rename = "conditions.bots")]
+    bots: Option<bool>,
+    #[serde(rename = "conditions.accountAge")]
+    account_age: Option<u16>,
+}
+
+impl ArenaTournamentOptions {
+    /// Create a new instance of [`ArenaTournamentOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines the name of the tournament.
+    /// Defaults to a random grandmaster name.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Determines the clock settings for the tournament.
+    /// The clock limit is in SECONDS and the clock increment is in seconds.
+    /// Lichess only accepts a fixed set of clock limits, e.g. 15, 30, 45, 60, 90 or full minutes.
+    /// This is required when creating a tournament.
+    #[must_use]
+    pub fn clock(mut self, clock_limit: u16, clock_increment: u8) -> Self {
+        self.clock_time = Some((f32::from(clock_limit) / 60.0).to_string());
+        self.clock_increment = Some(clock_increment);
+        self
+    }
+
+    /// Determines the duration of the tournament in minutes.
+    /// This is required when creating a tournament.
+    #[must_use]
+    pub fn minutes(mut self, minutes: u16) -> Self {
+        self.minutes = Some(minutes);
+        self
+    }
+
+    /// Determines how long to wait before the tournament starts, in minutes.
+    /// Defaults to 5 minutes.
+    #[must_use]
+    pub fn wait_minutes(mut self, wait_minutes: u8) -> Self {
+        self.wait_minutes = Some(wait_minutes);
+        self
+    }
+
+    /// Determines when the tournament starts.
+    /// The timestamp is in MILLISECONDS.
+    /// Overrides the wait minutes setting.
+    #[must_use]
+    pub fn start_date(mut self, timestamp: u64) -> Self {
+        self.start_date = Some(timestamp);
+        self
+    }
+
+    /// Determines the game variant.
+    /// Defaults to Standard.
+    #[must_use]
+    pub fn variant(mut self, variant: VariantMode) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Determines whether the games are rated or not.
+    /// Defaults to true.
+    #[must_use]
+    pub fn rated(mut self, rated: bool) -> Self {
+        self.rated = Some(rated);
+        self
+    }
+
+    /// Determines a custom FEN string for the starting position of the games.
+    /// Requires the variant to be set as Standard.
+    /// Defaults to the default chess starting position.
+    #[must_use]
+    pub fn position(mut self, fen: &str) -> Self {
+        self.position = Some(fen.to_string());
+        self
+    }
+
+    /// Determines whether players can berserk or not.
+    /// Defaults to true.
+    #[must_use]
+    pub fn berserkable(mut self, berserkable: bool) -> Self {
+        self.berserkable = Some(berserkable);
+        self
+    }
+
+    /// Determines whether players get extra points from winning streaks or not.
+    /// Defaults to true.
+    #[must_use]
+    pub fn streakable(mut self, streakable: bool) -> Self {
+        self.streakable = Some(streakable);
+        self
+    }
+
+    /// Determines whether the tournament has a chat or not.
+    /// Defaults to true.
+    #[must_use]
+    pub fn has_chat(mut self, has_chat: bool) -> Self {
+        self.has_chat = Some(has_chat);
+        self
+    }
+
+    /// Set a custom description for the tournament.
+    #[must_use]
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Make the tournament private and require a password to join it.
+    #[must_use]
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Turn the tournament into a team battle by the given team.
+    /// Only available when creating a tournament.
+    #[must_use]
+    pub fn team_battle_by_team(mut self, team_id: &str) -> Self {
+        self.team_battle_by_team = Some(team_id.to_string());
+        self
+    }
+
+    /// Restrict the tournament to members of the given team.
+    #[must_use]
+    pub fn team_member(mut self, team_id: &str) -> Self {
+        self.team_member = Some(team_id.to_string());
+        self
+    }
+
+    /// Determines the minimum rating required to join the tournament.
+    #[must_use]
+    pub fn min_rating(mut self, rating: u16) -> Self {
+        self.min_rating = Some(rating);
+        self
+    }
+
+    /// Determines the maximum rating allowed to join the tournament.
+    #[must_use]
+    pub fn max_rating(mut self, rating: u16) -> Self {
+        self.max_rating = Some(rating);
+        self
+    }
+
+    /// Determines the minimum number of rated games required to join the tournament.
+    #[must_use]
+    pub fn min_rated_games(mut self, games: u16) -> Self {
+        self.min_rated_games = Some(games);
+        self
+    }
+
+    /// Restrict the tournament to the given usernames.
+    #[must_use]
+    pub fn allow_list(mut self, usernames: &[&str]) -> Self {
+        self.allow_list = Some(usernames.join(","));
+        self
+    }
+
+    /// Determines whether bots can join the tournament or not.
+    /// Defaults to false.
+    #[must_use]
+    pub fn bots(mut self, bots: bool) -> Self {
+        self.bots = Some(bots);
+        self
+    }
+
+    /// Determines the minimum account age in days required to join the tournament.
+    #[must_use]
+    pub fn account_age(mut self, days: u16) -> Self {
+        self.account_age = Some(days);
+        self
+    }
+}
+
+/// Configuration for joining arena tournaments using [`tournaments().join()`](fn@crate::client::TournamentsApi::join).
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaJoinOptions {
+    password: Option<String>,
+    team: Option<String>,
+    pair_me_asap: Option<bool>,
+}
+
+impl ArenaJoinOptions {
+    /// Create a new instance of [`ArenaJoinOptions`] with default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The password of a private tournament.
+    #[must_use]
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// The team to join a team battle with.
+    #[must_use]
+    pub fn team(mut self, team_id: &str) -> Self {
+        self.team = Some(team_id.to_string());
+        self
+    }
+
+    /// Determines whether to be paired as soon as possible if the tournament has already started.
+    /// Defaults to false.
+    #[must_use]
+    pub fn pair_me_asap(mut self, pair_me_asap: bool) -> Self {
+        self.pair_me_asap = Some(pair_me_asap);
+        self
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use crate::models::game::GameState;
+
+/// Search limits for choosing a move using [`UciEngine::best_move()`](fn@crate::uci::UciEngine::best_move).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct GoOptions {
+    wtime: Option<u64>,
+    btime: Option<u64>,
+    winc: Option<u32>,
+    binc: Option<u32>,
+    movetime: Option<u64>,
+    depth: Option<u16>,
+    nodes: Option<u64>,
+    infinite: bool,
+}
+
+impl GoOptions {
+    /// Create a new instance of [`GoOptions`] with default configuration.
+    /// Without any limits, most engines search until they are told to stop, so at least one limit should be set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance of [`GoOptions`] with the remaining clock times and increments of a game.
+    #[must_use]
+    pub fn from_state(state: &GameState) -> Self {
+        Self::new()
+            .clock(state.wtime, state.btime)
+            .increment(state.winc, state.binc)
+    }
+
+    /// Determines the remaining clock times of white and black in milliseconds.
+    #[must_use]
+    pub fn clock(mut self, wtime: u64, btime: u64) -> Self {
+        self.wtime = Some(wtime);
+        self.btime = Some(btime);
+        self
+    }
+
+    /// Determines the clock increments of white and black in milliseconds.
+    #[must_use]
+    pub fn increment(mut self, winc: u32, binc: u32) -> Self {
+        self.winc = Some(winc);
+        self.binc = Some(binc);
+        self
+    }
+
+    /// Determines exactly how long the engine searches in milliseconds.
+    #[must_use]
+    pub fn movetime(mut self, movetime: u64) -> Self {
+        self.movetime = Some(movetime);
+        self
+    }
+
+    /// Determines how many plies deep the engine searches.
+    #[must_use]
+    pub fn depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Determines how many nodes the engine searches.
+    #[must_use]
+    pub fn nodes(mut self, nodes: u64) -> Self {
+        self.nodes = Some(nodes);
+        self
+    }
+
+    /// Determines whether the engine searches until it is told to stop.
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn infinite(mut self, infinite: bool) -> Self {
+        self.infinite = infinite;
+        self
+    }
+}
+
+impl Display for GoOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "go")?;
+
+        let limits = [
+            ("wtime", self.wtime),
+            ("btime", self.btime),
+            ("winc", self.winc.map(u64::from)),
+            ("binc", self.binc.map(u64::from)),
+            ("movetime", self.movetime),
+            ("depth", self.depth.map(u64::from)),
+            ("nodes", self.nodes),
+        ];
+        for (name, value) in limits {
+            if let Some(value) = value {
+                write!(f, " {name} {value}")?;
+            }
+        }
+
+        if self.infinite {
+            write!(f, " infinite")?;
+        }
+
+        Ok(())
+    }
+}
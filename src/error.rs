@@ -1,6 +1,11 @@
-use reqwest::{Response, StatusCode};
+use reqwest::{header, Response, StatusCode};
 use serde_json::Value;
-use std::{error::Error as StdError, fmt::Display, result::Result as StdResult};
+use std::{
+    collections::BTreeMap, error::Error as StdError, fmt::Display, result::Result as StdResult,
+    time::Duration,
+};
+
+use crate::config::client::DEFAULT_RATE_LIMIT_COOLDOWN;
 
 /// A shorthand for the actual result type.
 pub type Result<T> = StdResult<T, Error>;
@@ -24,6 +29,31 @@ impl Error {
         }
     }
 
+    /// Returns the kind of the error.
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the details of the error if it was produced by the Lichess API.
+    #[must_use]
+    pub fn lichess(&self) -> Option<&LichessError> {
+        self.source.downcast_ref::<LichessError>()
+    }
+
+    /// Returns the HTTP status code if the error was produced by the Lichess API.
+    #[must_use]
+    pub fn status(&self) -> Option<StatusCode> {
+        self.lichess().map(LichessError::status)
+    }
+
+    /// Returns the raw JSON body of the response if the error was produced by the Lichess API
+    /// and the body could be parsed.
+    #[must_use]
+    pub fn payload(&self) -> Option<&Value> {
+        self.lichess().and_then(LichessError::payload)
+    }
+
     /// Returns true if the error is an [`IO` error](struct@std::io::Error).
     #[must_use]
     pub fn is_io(&self) -> bool {
@@ -33,7 +63,15 @@ impl Error {
     /// Returns true if the error is produced by the Lichess API.
     #[must_use]
     pub fn is_lichess(&self) -> bool {
-        matches!(self.kind, ErrorKind::Lichess)
+        matches!(
+            self.kind,
+            ErrorKind::Lichess
+                | ErrorKind::BadRequest
+                | ErrorKind::Unauthorized
+                | ErrorKind::Forbidden
+                | ErrorKind::NotFound
+                | ErrorKind::RateLimited
+        )
     }
 
     /// Returns true if the error is a [`reqwest` error](struct@reqwest::Error).
@@ -55,11 +93,52 @@ impl Error {
         matches!(self.kind, ErrorKind::UrlEncoded)
     }
 
+    /// Returns true if the error is caused by a UCI engine.
+    #[cfg(feature = "uci")]
+    #[must_use]
+    pub fn is_engine(&self) -> bool {
+        matches!(self.kind, ErrorKind::Engine)
+    }
+
+    /// Returns true if the error is caused by an invalid FEN.
+    #[cfg(feature = "position")]
+    #[must_use]
+    pub fn is_invalid_fen(&self) -> bool {
+        matches!(self.kind, ErrorKind::InvalidFen)
+    }
+
+    /// Returns true if the error is caused by an illegal move.
+    #[cfg(feature = "position")]
+    #[must_use]
+    pub fn is_illegal_move(&self) -> bool {
+        matches!(self.kind, ErrorKind::IllegalMove)
+    }
+
+    /// Returns true if the error is caused by a variant that is not supported.
+    #[cfg(feature = "position")]
+    #[must_use]
+    pub fn is_unsupported_variant(&self) -> bool {
+        matches!(self.kind, ErrorKind::UnsupportedVariant)
+    }
+
+    /// Returns true if the error is caused by malformed PGN.
+    #[cfg(feature = "pgn")]
+    #[must_use]
+    pub fn is_pgn(&self) -> bool {
+        matches!(self.kind, ErrorKind::Pgn)
+    }
+
     /// Returns true if the error is caused by an invalid authentication token.
     #[must_use]
     pub fn is_invalid_auth_token(&self) -> bool {
         matches!(self.kind, ErrorKind::InvalidAuthToken)
     }
+
+    /// Returns true if the request was not sent because the authentication token lacks a required scope.
+    #[must_use]
+    pub fn is_missing_scope(&self) -> bool {
+        matches!(self.kind, ErrorKind::MissingScope)
+    }
 }
 
 impl StdError for Error {
@@ -82,7 +161,7 @@ impl From<std::io::Error> for Error {
 
 impl From<LichessError> for Error {
     fn from(source: LichessError) -> Self {
-        Error::new(ErrorKind::Lichess, source)
+        Error::new(source.kind(), source)
     }
 }
 
@@ -111,15 +190,50 @@ impl From<reqwest::header::InvalidHeaderValue> for Error {
     }
 }
 
-#[derive(Debug, Clone)]
-pub(crate) enum ErrorKind {
+/// The category of an [`Error`], returned by [`Error::kind()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An IO error occurred.
     IO,
+    /// The Lichess API returned an error that does not belong to any of the more specific kinds.
     Lichess,
+    /// The Lichess API rejected the request as invalid (`400 Bad Request`).
+    BadRequest,
+    /// The request was not authenticated or the token is invalid (`401 Unauthorized`).
+    Unauthorized,
+    /// The authenticated user is not allowed to perform the request (`403 Forbidden`).
+    Forbidden,
+    /// The requested resource does not exist (`404 Not Found`).
+    NotFound,
+    /// The request was rate limited (`429 Too Many Requests`).
+    RateLimited,
+    /// The HTTP request could not be sent or the response could not be read.
     Reqwest,
+    /// A JSON value could not be serialized or deserialized.
     Json,
+    /// A value could not be serialized into the `x-www-form-urlencoded` format.
     #[cfg(feature = "url-encoding")]
     UrlEncoded,
+    /// The authentication token contains invalid characters.
     InvalidAuthToken,
+    /// The request was not sent because the authentication token lacks a required scope.
+    MissingScope,
+    /// The PGN data is malformed.
+    #[cfg(feature = "pgn")]
+    Pgn,
+    /// A UCI engine failed or violated the protocol.
+    #[cfg(feature = "uci")]
+    Engine,
+    /// A FEN string is invalid.
+    #[cfg(feature = "position")]
+    InvalidFen,
+    /// A move is illegal in the given position.
+    #[cfg(feature = "position")]
+    IllegalMove,
+    /// The rules of a variant are not supported.
+    #[cfg(feature = "position")]
+    UnsupportedVariant,
 }
 
 impl Display for ErrorKind {
@@ -128,46 +242,142 @@ impl Display for ErrorKind {
             Self::IO => write!(f, "IO error"),
             Self::Json => write!(f, "JSON error"),
             Self::Lichess => write!(f, "Lichess API error"),
+            Self::BadRequest => write!(f, "bad request"),
+            Self::Unauthorized => write!(f, "unauthorized"),
+            Self::Forbidden => write!(f, "forbidden"),
+            Self::NotFound => write!(f, "not found"),
+            Self::RateLimited => write!(f, "rate limited"),
             Self::Reqwest => write!(f, "reqwest error"),
             #[cfg(feature = "url-encoding")]
             Self::UrlEncoded => write!(f, "url-encoded error"),
             Self::InvalidAuthToken => write!(f, "invalid authentication token"),
+            Self::MissingScope => write!(f, "missing OAuth scope"),
+            #[cfg(feature = "pgn")]
+            Self::Pgn => write!(f, "PGN error"),
+            #[cfg(feature = "uci")]
+            Self::Engine => write!(f, "UCI engine error"),
+            #[cfg(feature = "position")]
+            Self::InvalidFen => write!(f, "invalid FEN"),
+            #[cfg(feature = "position")]
+            Self::IllegalMove => write!(f, "illegal move"),
+            #[cfg(feature = "position")]
+            Self::UnsupportedVariant => write!(f, "unsupported variant"),
         }
     }
 }
 
-// An error produced by the Lichess API
-#[derive(Debug)]
-pub(crate) struct LichessError {
+/// An error returned by the Lichess API.
+///
+/// It can be accessed with [`Error::lichess()`] when the [`Error`] was produced by the Lichess API.
+#[derive(Debug, Clone)]
+pub struct LichessError {
     status: StatusCode,
     message: String,
+    payload: Option<Value>,
+    retry_after: Option<Duration>,
+    field_errors: BTreeMap<String, Vec<String>>,
 }
 
 impl LichessError {
     pub(crate) async fn from_response(response: Response) -> Result<Self> {
         let status = response.status();
-        let error = serde_json::from_slice::<Value>(&response.bytes().await?);
 
-        // Design decision: Return a simple "Not found" message for 404s with unparseable bodies.
-        // Lichess often returns long HTML pages instead of JSON for 404 errors, even on API endpoints.
+        // Lichess asks clients to wait a full minute after being rate limited, unless told otherwise
+        let retry_after = response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs)
+            .or((status == StatusCode::TOO_MANY_REQUESTS).then_some(DEFAULT_RATE_LIMIT_COOLDOWN));
+
+        // Design decision: Use the canonical reason of the status code for unparseable bodies.
+        // Lichess often returns long HTML pages instead of JSON for some errors (especially 404s), even on API endpoints.
         // These HTML responses don't contain any actionable information, just generic error pages.
-        // Returning "Not found" provides a cleaner, more consistent error message than showing raw HTML.
-        let message = if status == StatusCode::NOT_FOUND && error.is_err() {
-            String::from("Not found")
-        } else {
-            let error_json = error?;
-            let error_msg = error_json
-                .get("error")
-                .and_then(|v| v.as_str())
-                .unwrap_or("Unexpected error format, failed to parse the actual error message");
-
-            let mut msg = error_msg.to_string();
-            let removable_chars = ['{', '}', '[', ']', '"'];
-            msg.retain(|c| !removable_chars.contains(&c));
-            msg.replace(':', ": ")
+        // Returning e.g. "Not found" provides a cleaner, more consistent error message than showing raw HTML.
+        let payload = serde_json::from_slice::<Value>(&response.bytes().await?).ok();
+        let error = payload.as_ref().and_then(|v| v.get("error"));
+
+        // Form validation errors are returned as an object of field names and their errors
+        let field_errors = error
+            .and_then(Value::as_object)
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|(field, errors)| {
+                        let errors = match errors {
+                            Value::Array(errors) => errors.iter().map(value_to_string).collect(),
+                            error => vec![value_to_string(error)],
+                        };
+                        (field.clone(), errors)
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let message = match error {
+            Some(Value::String(message)) => message.clone(),
+            Some(_) if !field_errors.is_empty() => field_errors
+                .iter()
+                .map(|(field, errors)| format!("{field}: {}", errors.join(", ")))
+                .collect::<Vec<_>>()
+                .join("; "),
+            _ => status
+                .canonical_reason()
+                .map_or_else(|| String::from("Unknown error"), capitalize),
         };
 
-        Ok(LichessError { status, message })
+        Ok(LichessError {
+            status,
+            message,
+            payload,
+            retry_after,
+            field_errors,
+        })
+    }
+
+    /// The HTTP status code of the response.
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The error message returned by Lichess, e.g. `Not your turn, or game already over`.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The raw JSON body of the response, if it could be parsed.
+    #[must_use]
+    pub fn payload(&self) -> Option<&Value> {
+        self.payload.as_ref()
+    }
+
+    /// How long to wait before sending more requests.
+    /// This is only present for rate limited responses and for responses with a `Retry-After` header.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    /// The validation errors of the request fields, keyed by the field name.
+    /// This is empty unless Lichess rejected some of the submitted fields.
+    #[must_use]
+    pub fn field_errors(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.field_errors
+    }
+
+    // Get the kind of the error based on the status code
+    fn kind(&self) -> ErrorKind {
+        match self.status {
+            StatusCode::BAD_REQUEST => ErrorKind::BadRequest,
+            StatusCode::UNAUTHORIZED => ErrorKind::Unauthorized,
+            StatusCode::FORBIDDEN => ErrorKind::Forbidden,
+            StatusCode::NOT_FOUND => ErrorKind::NotFound,
+            StatusCode::TOO_MANY_REQUESTS => ErrorKind::RateLimited,
+            _ => ErrorKind::Lichess,
+        }
     }
 }
 
@@ -178,3 +388,20 @@ impl Display for LichessError {
         write!(f, "HTTP code {}: {}", self.status, self.message)
     }
 }
+
+// Convert a JSON value into a string without quoting strings
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+// Capitalize the first letter of a status code reason, e.g. "Not Found" becomes "Not found"
+fn capitalize(reason: &str) -> String {
+    let mut message = reason.to_lowercase();
+    if let Some(first) = message.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    message
+}
@@ -69,3 +69,24 @@ pub mod client;
 pub mod config;
 pub mod error;
 pub mod models;
+
+#[cfg(feature = "pgn")]
+pub mod pgn;
+
+#[cfg(feature = "oauth")]
+pub mod oauth;
+
+#[cfg(feature = "position")]
+pub mod position;
+
+#[cfg(feature = "bot")]
+pub mod runner;
+
+#[cfg(any(feature = "bot", feature = "board"))]
+pub mod tracker;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "uci")]
+pub mod uci;
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::score::{self, Score};
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
 pub struct CloudAnalysis {
@@ -10,8 +12,28 @@ pub struct CloudAnalysis {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(try_from = "RawPositionVariation")]
 pub struct PositionVariation {
     pub moves: String,
-    pub cp: i8,
+    #[serde(flatten)]
+    pub score: Score,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+struct RawPositionVariation {
+    moves: String,
+    cp: Option<i32>,
+    mate: Option<i32>,
+}
+
+impl TryFrom<RawPositionVariation> for PositionVariation {
+    type Error = &'static str;
+
+    fn try_from(raw: RawPositionVariation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            moves: raw.moves,
+            score: score::from_fields(raw.cp, raw.mate).ok_or("missing field `cp` or `mate`")?,
+        })
+    }
 }
@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, TimestampMilliSeconds};
+use time::PrimitiveDateTime;
+
+use crate::models::common::Title;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastWithRounds {
+    pub tour: BroadcastTour,
+    pub group: Option<String>,
+    #[serde(default)]
+    pub rounds: Vec<BroadcastRoundInfo>,
+    pub default_round_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastWithLastRound {
+    pub tour: BroadcastTour,
+    pub group: Option<String>,
+    pub round: Option<BroadcastRoundInfo>,
+    pub round_to_link: Option<BroadcastRoundInfo>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastTour {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub info: Option<BroadcastTourInfo>,
+    #[serde_as(as = "Option<TimestampMilliSeconds>")]
+    pub created_at: Option<PrimitiveDateTime>,
+    #[serde_as(as = "Vec<TimestampMilliSeconds>")]
+    #[serde(default)]
+    pub dates: Vec<PrimitiveDateTime>,
+    pub tier: Option<u8>,
+    pub image: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub leaderboard: bool,
+    #[serde(default)]
+    pub team_table: bool,
+    pub url: String,
+    pub community_owner: Option<BroadcastOwner>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastTourInfo {
+    pub website: Option<String>,
+    pub players: Option<String>,
+    pub location: Option<String>,
+    pub tc: Option<String>,
+    pub fide_tc: Option<BroadcastFideTimeControl>,
+    pub time_zone: Option<String>,
+    pub standings: Option<String>,
+    pub format: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastOwner {
+    pub id: String,
+    pub name: String,
+    pub title: Option<Title>,
+    pub flair: Option<String>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastRoundInfo {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    #[serde_as(as = "Option<TimestampMilliSeconds>")]
+    pub created_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub rated: bool,
+    #[serde(default)]
+    pub ongoing: bool,
+    #[serde_as(as = "Option<TimestampMilliSeconds>")]
+    pub starts_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub starts_after_previous: bool,
+    #[serde_as(as = "Option<TimestampMilliSeconds>")]
+    pub finished_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub finished: bool,
+    pub url: String,
+    pub delay: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastTop {
+    #[serde(default)]
+    pub active: Vec<BroadcastWithLastRound>,
+    #[serde(default)]
+    pub upcoming: Vec<BroadcastWithLastRound>,
+    pub past: BroadcastPaginator,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastPaginator {
+    pub current_page: u32,
+    pub max_per_page: u32,
+    pub current_page_results: Vec<BroadcastWithLastRound>,
+    pub previous_page: Option<u32>,
+    pub next_page: Option<u32>,
+    pub nb_results: Option<u32>,
+    pub nb_pages: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastRound {
+    pub round: BroadcastRoundInfo,
+    pub tour: BroadcastTour,
+    pub study: BroadcastStudy,
+    #[serde(default)]
+    pub games: Vec<BroadcastRoundGame>,
+    pub group: Option<BroadcastGroup>,
+    #[serde(default)]
+    pub is_subscribed: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastRoundWithTour {
+    pub round: BroadcastRoundInfo,
+    pub tour: BroadcastTour,
+    pub study: BroadcastStudy,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastStudy {
+    #[serde(default)]
+    pub writeable: bool,
+    pub features: Option<BroadcastStudyFeatures>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastStudyFeatures {
+    #[serde(default)]
+    pub chat: bool,
+    #[serde(default)]
+    pub computer: bool,
+    #[serde(default)]
+    pub explorer: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastGroup {
+    pub name: String,
+    pub tours: Vec<BroadcastGroupTour>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastGroupTour {
+    pub id: String,
+    pub name: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastRoundGame {
+    pub id: String,
+    pub name: String,
+    pub fen: Option<String>,
+    #[serde(default)]
+    pub players: Vec<BroadcastPlayer>,
+    pub last_move: Option<String>,
+    pub check: Option<String>,
+    pub think_time: Option<u32>,
+    pub status: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastPlayer {
+    pub name: String,
+    pub title: Option<Title>,
+    pub rating: Option<u16>,
+    pub fide_id: Option<u32>,
+    pub fed: Option<String>,
+    pub clock: Option<u32>,
+    pub team: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastPushResult {
+    pub games: Vec<BroadcastPushGame>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct BroadcastPushGame {
+    pub tags: BTreeMap<String, String>,
+    pub moves: Option<u32>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BroadcastFideTimeControl {
+    Standard,
+    Rapid,
+    Blitz,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BroadcastVisibility {
+    Public,
+    Unlisted,
+    Private,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BroadcastRoundStatus {
+    New,
+    Started,
+    Finished,
+}
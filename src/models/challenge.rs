@@ -122,6 +122,15 @@ pub enum ChallengeDeclineReason {
     OnlyBot,
 }
 
+/// The outcome of deciding on an incoming challenge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChallengeDecision {
+    /// Accept the challenge.
+    Accept,
+    /// Decline the challenge, showing the given reason to the challenger.
+    Decline(ChallengeDeclineReason),
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ChallengeDirection {
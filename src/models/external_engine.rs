@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::score::{self, Score};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEngine {
+    pub id: String,
+    pub name: String,
+    pub client_secret: String,
+    pub user_id: String,
+    pub max_threads: u32,
+    pub max_hash: u32,
+    pub default_depth: Option<u16>,
+    pub variants: Vec<EngineVariant>,
+    pub provider_data: Option<String>,
+}
+
+/// The variants an external engine can analyse, named after the values of the `UCI_Variant` engine option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EngineVariant {
+    Chess,
+    Crazyhouse,
+    Antichess,
+    Atomic,
+    Horde,
+    #[serde(rename = "kingofthehill")]
+    KingOfTheHill,
+    #[serde(rename = "racingkings")]
+    RacingKings,
+    #[serde(rename = "3check")]
+    ThreeCheck,
+}
+
+impl Display for EngineVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Chess => write!(f, "chess"),
+            Self::Crazyhouse => write!(f, "crazyhouse"),
+            Self::Antichess => write!(f, "antichess"),
+            Self::Atomic => write!(f, "atomic"),
+            Self::Horde => write!(f, "horde"),
+            Self::KingOfTheHill => write!(f, "kingofthehill"),
+            Self::RacingKings => write!(f, "racingkings"),
+            Self::ThreeCheck => write!(f, "3check"),
+        }
+    }
+}
+
+/// An analysis request for an external engine.
+/// Castling moves are given in the `UCI_Chess960` notation, i.e. as the king capturing the rook.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EngineWork {
+    pub session_id: String,
+    pub threads: u32,
+    pub hash: u32,
+    #[serde(default)]
+    pub infinite: bool,
+    pub multi_pv: u8,
+    pub variant: EngineVariant,
+    pub initial_fen: String,
+    pub moves: Vec<String>,
+    pub movetime: Option<u64>,
+    pub depth: Option<u16>,
+    pub nodes: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct EngineJob {
+    pub id: String,
+    pub work: EngineWork,
+    pub engine: ExternalEngine,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct EngineAnalysis {
+    pub time: u64,
+    pub depth: u16,
+    pub nodes: u64,
+    pub pvs: Vec<EnginePv>,
+}
+
+/// A principal variation of an external engine, scored from the perspective of the side to move.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawEnginePv")]
+pub struct EnginePv {
+    pub depth: u16,
+    #[serde(flatten)]
+    pub score: Score,
+    pub moves: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+struct RawEnginePv {
+    depth: u16,
+    cp: Option<i32>,
+    mate: Option<i32>,
+    moves: Vec<String>,
+}
+
+impl TryFrom<RawEnginePv> for EnginePv {
+    type Error = &'static str;
+
+    fn try_from(raw: RawEnginePv) -> Result<Self, Self::Error> {
+        Ok(Self {
+            depth: raw.depth,
+            score: score::from_fields(raw.cp, raw.mate).ok_or("missing field `cp` or `mate`")?,
+            moves: raw.moves,
+        })
+    }
+}
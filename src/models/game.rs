@@ -1,6 +1,7 @@
 use crate::models::{
     challenge::ChallengeSource,
     common::{date_dot, Color, FinalColor, Title},
+    score::{self, Score},
     user::{LightUser, MinimalUser, PerfType},
 };
 use serde::{Deserialize, Serialize};
@@ -145,15 +146,36 @@ pub enum JudgementTier {
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(from = "RawMoveAnalysis")]
 pub struct MoveAnalysis {
-    pub mate: Option<u8>,
-    pub eval: Option<i16>,
+    #[serde(flatten, serialize_with = "super::score::eval::serialize")]
+    pub score: Option<Score>,
     pub best: Option<String>,
     pub variation: Option<String>,
     pub judgment: Option<Judgement>,
 }
 
+#[derive(Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+struct RawMoveAnalysis {
+    eval: Option<i32>,
+    mate: Option<i32>,
+    best: Option<String>,
+    variation: Option<String>,
+    judgment: Option<Judgement>,
+}
+
+impl From<RawMoveAnalysis> for MoveAnalysis {
+    fn from(raw: RawMoveAnalysis) -> Self {
+        Self {
+            score: score::from_fields(raw.eval, raw.mate),
+            best: raw.best,
+            variation: raw.variation,
+            judgment: raw.judgment,
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
@@ -4,12 +4,20 @@ pub mod challenge;
 pub mod chat;
 pub mod common;
 pub mod game;
+pub mod oauth;
+pub mod score;
 pub mod user;
 
 // API-specific models
 #[cfg(feature = "analysis")]
 pub mod analysis;
 
+#[cfg(feature = "broadcasts")]
+pub mod broadcast;
+
+#[cfg(feature = "external_engine")]
+pub mod external_engine;
+
 #[cfg(feature = "fide")]
 pub mod fide;
 
@@ -22,6 +30,18 @@ pub mod puzzle;
 #[cfg(feature = "simuls")]
 pub mod simul;
 
+#[cfg(feature = "studies")]
+pub mod study;
+
+#[cfg(feature = "swiss")]
+pub mod swiss;
+
+#[cfg(feature = "teams")]
+pub mod team;
+
+#[cfg(feature = "tournaments")]
+pub mod tournament;
+
 #[cfg(feature = "tv")]
 pub mod tv;
 
@@ -0,0 +1,180 @@
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{
+    formats::CommaSeparator, serde_as, skip_serializing_none, StringWithSeparator,
+    TimestampMilliSeconds,
+};
+use time::PrimitiveDateTime;
+
+/// Represents a permission that can be granted to an OAuth access token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Scope {
+    /// Read the preferences of the user.
+    PreferenceRead,
+    /// Change the preferences of the user.
+    PreferenceWrite,
+    /// Read the email address of the user.
+    EmailRead,
+    /// View the external engines of the user.
+    EngineRead,
+    /// Create, update and delete the external engines of the user.
+    EngineWrite,
+    /// Read incoming challenges.
+    ChallengeRead,
+    /// Create, accept and decline challenges.
+    ChallengeWrite,
+    /// Create many games at once for other players.
+    ChallengeBulk,
+    /// Read private studies and broadcasts.
+    StudyRead,
+    /// Create, update and delete studies and broadcasts.
+    StudyWrite,
+    /// Create, update and join tournaments.
+    TournamentWrite,
+    /// Create and join puzzle races.
+    RacerWrite,
+    /// Read the puzzle activity of the user.
+    PuzzleRead,
+    /// Solve puzzles and record the puzzle activity of the user.
+    PuzzleWrite,
+    /// Read private team information.
+    TeamRead,
+    /// Join and leave teams.
+    TeamWrite,
+    /// Manage the teams led by the user, e.g. message and kick members.
+    TeamLead,
+    /// Read the players followed by the user.
+    FollowRead,
+    /// Follow and unfollow other players.
+    FollowWrite,
+    /// Send private messages to other players.
+    MsgWrite,
+    /// Play games with the Board API.
+    BoardPlay,
+    /// Play games with the Bot API, only for bot accounts.
+    BotPlay,
+    /// Log in to the Lichess website, only for administrators.
+    WebLogin,
+    /// Use the moderator tools, only for moderators.
+    WebMod,
+}
+
+impl Scope {
+    /// All scopes known to Lichess.
+    pub const ALL: [Self; 24] = [
+        Self::PreferenceRead,
+        Self::PreferenceWrite,
+        Self::EmailRead,
+        Self::EngineRead,
+        Self::EngineWrite,
+        Self::ChallengeRead,
+        Self::ChallengeWrite,
+        Self::ChallengeBulk,
+        Self::StudyRead,
+        Self::StudyWrite,
+        Self::TournamentWrite,
+        Self::RacerWrite,
+        Self::PuzzleRead,
+        Self::PuzzleWrite,
+        Self::TeamRead,
+        Self::TeamWrite,
+        Self::TeamLead,
+        Self::FollowRead,
+        Self::FollowWrite,
+        Self::MsgWrite,
+        Self::BoardPlay,
+        Self::BotPlay,
+        Self::WebLogin,
+        Self::WebMod,
+    ];
+
+    /// Returns the name of the scope used by Lichess, e.g. `challenge:write`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PreferenceRead => "preference:read",
+            Self::PreferenceWrite => "preference:write",
+            Self::EmailRead => "email:read",
+            Self::EngineRead => "engine:read",
+            Self::EngineWrite => "engine:write",
+            Self::ChallengeRead => "challenge:read",
+            Self::ChallengeWrite => "challenge:write",
+            Self::ChallengeBulk => "challenge:bulk",
+            Self::StudyRead => "study:read",
+            Self::StudyWrite => "study:write",
+            Self::TournamentWrite => "tournament:write",
+            Self::RacerWrite => "racer:write",
+            Self::PuzzleRead => "puzzle:read",
+            Self::PuzzleWrite => "puzzle:write",
+            Self::TeamRead => "team:read",
+            Self::TeamWrite => "team:write",
+            Self::TeamLead => "team:lead",
+            Self::FollowRead => "follow:read",
+            Self::FollowWrite => "follow:write",
+            Self::MsgWrite => "msg:write",
+            Self::BoardPlay => "board:play",
+            Self::BotPlay => "bot:play",
+            Self::WebLogin => "web:login",
+            Self::WebMod => "web:mod",
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| de::Error::custom(format!("unknown scope: {s}")))
+    }
+}
+
+impl Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = Cow::<str>::deserialize(deserializer)?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+#[skip_serializing_none]
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, Scope>")]
+    pub scopes: Vec<Scope>,
+    pub user_id: String,
+    #[serde_as(as = "Option<TimestampMilliSeconds>")]
+    pub expires: Option<PrimitiveDateTime>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct AccessToken {
+    pub token_type: String,
+    pub access_token: String,
+    pub expires_in: Option<u64>,
+}
@@ -2,6 +2,10 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use super::game::Speed;
+use crate::{
+    error::Result,
+    pgn::{PgnGame, PgnMove},
+};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -49,6 +53,44 @@ pub struct OpeningMove {
     pub opening: Option<OpeningDetails>,
 }
 
+impl OpeningMove {
+    /// Returns the number of games the move was played in.
+    #[must_use]
+    pub fn games(&self) -> u64 {
+        u64::from(self.white) + u64::from(self.black) + u64::from(self.draws)
+    }
+}
+
+impl From<PlayerOpeningMove> for OpeningMove {
+    fn from(value: PlayerOpeningMove) -> Self {
+        Self {
+            uci: value.uci,
+            san: value.san,
+            average_rating: value.average_opponent_rating,
+            white: value.white,
+            black: value.black,
+            draws: value.draws,
+            game: value.game,
+            opening: value.opening,
+        }
+    }
+}
+
+impl From<PlayerOpening> for Opening {
+    fn from(value: PlayerOpening) -> Self {
+        Self {
+            white: value.white,
+            black: value.black,
+            draws: value.draws,
+            moves: value.moves.into_iter().map(OpeningMove::from).collect(),
+            top_games: Vec::new(),
+            recent_games: value.recent_games,
+            opening: value.opening,
+            history: Vec::new(),
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
@@ -117,3 +159,138 @@ pub enum OpeningRatings {
     TwentyTwoHundred = 2200,
     TwentyFiveHundred = 2500,
 }
+
+/// A tree of opening explorer statistics, crawled using [`openings().crawl()`](fn@crate::client::OpeningsApi::crawl).
+/// The moves of every position are ordered from the most to the least played.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpeningTree {
+    pub fen: Option<String>,
+    pub white: u32,
+    pub black: u32,
+    pub draws: u32,
+    pub opening: Option<OpeningDetails>,
+    pub children: Vec<OpeningNode>,
+}
+
+/// A move of an [`OpeningTree`] with the statistics of the move and the moves played after it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct OpeningNode {
+    pub stats: OpeningMove,
+    pub children: Vec<OpeningNode>,
+}
+
+impl OpeningTree {
+    /// Returns the number of games played from the root position.
+    #[must_use]
+    pub fn games(&self) -> u64 {
+        u64::from(self.white) + u64::from(self.black) + u64::from(self.draws)
+    }
+
+    /// Returns the number of moves in the tree.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        fn count(nodes: &[OpeningNode]) -> usize {
+            nodes.iter().map(|node| 1 + count(&node.children)).sum()
+        }
+        count(&self.children)
+    }
+
+    /// Returns whether the tree has no moves.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Converts the tree into a PGN game, where the most played moves form the mainline
+    /// and the other moves their variations. Every move is commented with its statistics.
+    #[must_use]
+    pub fn to_pgn(&self) -> PgnGame {
+        let mut tags = Vec::new();
+        if let Some(fen) = &self.fen {
+            tags.push(("FEN".to_string(), fen.clone()));
+            tags.push(("SetUp".to_string(), "1".to_string()));
+        }
+
+        PgnGame {
+            tags,
+            comment: Some(stats_comment(
+                self.opening.as_ref(),
+                self.white,
+                self.black,
+                self.draws,
+            )),
+            moves: pgn_line(&self.children),
+            result: Some("*".to_string()),
+        }
+    }
+
+    /// Serializes the tree into JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the tree cannot be serialized.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+// Convert a list of alternative moves into the mainline of the most played move with the others as variations
+fn pgn_line(nodes: &[OpeningNode]) -> Vec<PgnMove> {
+    let Some((main, alternatives)) = nodes.split_first() else {
+        return Vec::new();
+    };
+
+    let mut first = pgn_move(main);
+    first.variations = alternatives
+        .iter()
+        .map(|alternative| {
+            let mut variation = vec![pgn_move(alternative)];
+            variation.extend(pgn_line(&alternative.children));
+            variation
+        })
+        .collect();
+
+    let mut moves = vec![first];
+    moves.extend(pgn_line(&main.children));
+    moves
+}
+
+// Convert a single move of the tree into a PGN move commented with its statistics
+fn pgn_move(node: &OpeningNode) -> PgnMove {
+    let stats = &node.stats;
+    PgnMove {
+        san: stats.san.clone(),
+        comment: Some(stats_comment(
+            stats.opening.as_ref(),
+            stats.white,
+            stats.black,
+            stats.draws,
+        )),
+        ..PgnMove::default()
+    }
+}
+
+// Describe the results of a position, e.g. `B20 Sicilian Defense, 100 games: 40% white, 30% draws, 30% black`
+fn stats_comment(opening: Option<&OpeningDetails>, white: u32, black: u32, draws: u32) -> String {
+    let games = u64::from(white) + u64::from(black) + u64::from(draws);
+    let percent = |count: u32| {
+        (u64::from(count) * 100 + games / 2)
+            .checked_div(games)
+            .unwrap_or(0)
+    };
+
+    let mut comment = String::new();
+    if let Some(opening) = opening {
+        comment.push_str(&format!("{} {}, ", opening.eco, opening.name));
+    }
+    comment.push_str(&format!(
+        "{games} games: {}% white, {}% draws, {}% black",
+        percent(white),
+        percent(draws),
+        percent(black)
+    ));
+    comment
+}
@@ -0,0 +1,155 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::Neg,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::common::FinalColor;
+
+/// An engine evaluation of a position, either in centipawns or as a forced mate.
+///
+/// The evaluations of games and positions returned by Lichess are from the perspective of white,
+/// i.e. positive values favour white. [`Score::for_color()`] converts them to the perspective of either side.
+/// Scores are ordered from the worst to the best outcome for the side whose perspective they are from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Score {
+    /// An evaluation in centipawns.
+    #[serde(rename = "cp")]
+    Centipawns(i32),
+    /// A forced mate in the given number of moves, negative if the opponent is mating.
+    #[serde(rename = "mate")]
+    Mate(i32),
+}
+
+impl Score {
+    /// Returns the evaluation in centipawns, or `None` if the score is a forced mate.
+    #[must_use]
+    pub fn centipawns(self) -> Option<i32> {
+        match self {
+            Self::Centipawns(cp) => Some(cp),
+            Self::Mate(_) => None,
+        }
+    }
+
+    /// Returns the number of moves until mate, or `None` if the score is in centipawns.
+    #[must_use]
+    pub fn mate(self) -> Option<i32> {
+        match self {
+            Self::Centipawns(_) => None,
+            Self::Mate(moves) => Some(moves),
+        }
+    }
+
+    /// Returns whether the score is a forced mate for either side.
+    #[must_use]
+    pub fn is_mate(self) -> bool {
+        matches!(self, Self::Mate(_))
+    }
+
+    /// Converts a score from the perspective of white to the perspective of the given side.
+    /// Converting a score from the perspective of black back to white works the same way.
+    #[must_use]
+    pub fn for_color(self, color: FinalColor) -> Self {
+        match color {
+            FinalColor::White => self,
+            FinalColor::Black => -self,
+        }
+    }
+
+    /// Returns the probability of winning between 0 and 1, using the same model as the Lichess analysis board.
+    /// A forced mate is a certain win or loss, where a mate in zero moves means the side has been mated.
+    #[must_use]
+    pub fn win_probability(self) -> f64 {
+        match self {
+            Self::Centipawns(cp) => {
+                let cp = f64::from(cp.clamp(-1000, 1000));
+                1.0 / (1.0 + (-0.003_682_08 * cp).exp())
+            }
+            Self::Mate(moves) if moves > 0 => 1.0,
+            Self::Mate(_) => 0.0,
+        }
+    }
+
+    // Rank the outcomes from the worst to the best, quicker mates being better and slower being worse
+    fn rank(self) -> (u8, i64) {
+        match self {
+            Self::Mate(moves) if moves > 0 => (2, -i64::from(moves)),
+            Self::Centipawns(cp) => (1, i64::from(cp)),
+            Self::Mate(moves) => (0, -i64::from(moves)),
+        }
+    }
+}
+
+impl Neg for Score {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::Centipawns(cp) => Self::Centipawns(-cp),
+            Self::Mate(moves) => Self::Mate(-moves),
+        }
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Centipawns(cp) => {
+                let sign = if *cp < 0 { "-" } else { "+" };
+                write!(f, "{sign}{}.{:02}", cp.abs() / 100, cp.abs() % 100)
+            }
+            Self::Mate(moves) => write!(f, "#{moves}"),
+        }
+    }
+}
+
+// Combine the separate centipawn and mate fields sent by Lichess into a score, a mate taking precedence.
+// Models with a score are deserialized through a raw struct with these fields instead of flattening the score,
+// as flattened fields cannot be combined with rejecting unknown fields.
+pub(crate) fn from_fields(cp: Option<i32>, mate: Option<i32>) -> Option<Score> {
+    match (mate, cp) {
+        (Some(moves), _) => Some(Score::Mate(moves)),
+        (None, Some(cp)) => Some(Score::Centipawns(cp)),
+        (None, None) => None,
+    }
+}
+
+// Lichess names the centipawn evaluations of analysed games `eval` instead of `cp`
+pub(crate) mod eval {
+    use serde::{Serialize, Serializer};
+    use serde_with::skip_serializing_none;
+
+    use super::Score;
+
+    #[skip_serializing_none]
+    #[derive(Serialize)]
+    struct RawScore {
+        eval: Option<i32>,
+        mate: Option<i32>,
+    }
+
+    pub(crate) fn serialize<S>(score: &Option<Score>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawScore {
+            eval: score.and_then(Score::centipawns),
+            mate: score.and_then(Score::mate),
+        }
+        .serialize(serializer)
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, TimestampMilliSeconds};
+use time::PrimitiveDateTime;
+
+use crate::models::game::Variant;
+
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct StudyMetadata {
+    pub id: String,
+    pub name: String,
+    #[serde_as(as = "TimestampMilliSeconds")]
+    pub created_at: PrimitiveDateTime,
+    #[serde_as(as = "TimestampMilliSeconds")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct StudyImport {
+    pub chapters: Vec<StudyChapter>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct StudyChapter {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub players: Vec<StudyPlayer>,
+    pub status: Option<String>,
+    pub variant: Option<Variant>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct StudyPlayer {
+    pub name: Option<String>,
+    pub rating: Option<u16>,
+}
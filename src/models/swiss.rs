@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use time::OffsetDateTime;
+
+use crate::models::{common::Title, game::VariantMode};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SwissTournament {
+    pub id: String,
+    pub created_by: String,
+    #[serde(with = "time::serde::iso8601")]
+    pub starts_at: OffsetDateTime,
+    pub name: String,
+    pub clock: SwissClock,
+    pub variant: VariantMode,
+    pub round: u8,
+    pub nb_rounds: u8,
+    pub nb_players: u32,
+    pub nb_ongoing: u32,
+    pub status: SwissStatus,
+    pub stats: Option<SwissStats>,
+    pub rated: bool,
+    pub verdicts: Option<SwissVerdicts>,
+    pub next_round: Option<SwissNextRound>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SwissStatus {
+    Created,
+    Started,
+    Finished,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum SwissChat {
+    Nobody = 0,
+    Leaders = 10,
+    Members = 20,
+    Everyone = 30,
+}
+
+impl TryFrom<u8> for SwissChat {
+    type Error = String;
+
+    fn try_from(chat: u8) -> Result<Self, Self::Error> {
+        match chat {
+            0 => Ok(Self::Nobody),
+            10 => Ok(Self::Leaders),
+            20 => Ok(Self::Members),
+            30 => Ok(Self::Everyone),
+            _ => Err(format!("unknown swiss chat setting: {chat}")),
+        }
+    }
+}
+
+impl From<SwissChat> for u8 {
+    fn from(chat: SwissChat) -> Self {
+        chat as u8
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct SwissClock {
+    pub limit: u32,
+    pub increment: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SwissStats {
+    pub games: u32,
+    pub white_wins: u32,
+    pub black_wins: u32,
+    pub draws: u32,
+    pub byes: u32,
+    pub absences: u32,
+    pub average_rating: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct SwissVerdicts {
+    pub list: Vec<SwissVerdict>,
+    pub accepted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct SwissVerdict {
+    pub condition: String,
+    pub verdict: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct SwissNextRound {
+    #[serde(with = "time::serde::iso8601")]
+    pub at: OffsetDateTime,
+    #[serde(rename = "in")]
+    pub in_seconds: u32,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SwissResult {
+    pub rank: u32,
+    pub points: f32,
+    pub tie_break: f32,
+    pub rating: u16,
+    pub username: String,
+    pub title: Option<Title>,
+    pub performance: Option<u16>,
+    #[serde(default)]
+    pub absent: bool,
+}
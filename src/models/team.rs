@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, TimestampMilliSeconds};
+use time::PrimitiveDateTime;
+
+use crate::models::{
+    common::{PatronTier, Title},
+    user::{MinimalUser, User},
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub flair: Option<String>,
+    pub leader: Option<MinimalUser>,
+    #[serde(default)]
+    pub leaders: Vec<MinimalUser>,
+    pub nb_members: u32,
+    #[serde(default)]
+    pub open: bool,
+    #[serde(default)]
+    pub joined: bool,
+    #[serde(default)]
+    pub requested: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TeamPaginator {
+    pub current_page: u32,
+    pub max_per_page: u32,
+    pub current_page_results: Vec<Team>,
+    pub previous_page: Option<u32>,
+    pub next_page: Option<u32>,
+    pub nb_results: u32,
+    pub nb_pages: u32,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMember {
+    pub id: String,
+    pub name: String,
+    pub title: Option<Title>,
+    #[serde(default)]
+    pub patron: bool,
+    pub patron_tier: Option<PatronTier>,
+    pub patron_color: Option<u8>,
+    pub flair: Option<String>,
+    #[serde_as(as = "TimestampMilliSeconds")]
+    pub joined_team_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TeamRequest {
+    pub request: TeamRequestDetails,
+    pub user: User,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRequestDetails {
+    pub team_id: String,
+    pub user_id: String,
+    #[serde_as(as = "TimestampMilliSeconds")]
+    pub date: PrimitiveDateTime,
+    pub message: Option<String>,
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, TimestampMilliSeconds};
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::models::{
+    common::Title,
+    game::{Perf, Variant, VariantMode},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct ArenaTournaments {
+    pub created: Vec<ArenaTournament>,
+    pub started: Vec<ArenaTournament>,
+    pub finished: Vec<ArenaTournament>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaTournament {
+    pub id: String,
+    pub created_by: String,
+    pub system: String,
+    pub minutes: u16,
+    pub clock: TournamentClock,
+    pub rated: bool,
+    pub full_name: String,
+    pub nb_players: u32,
+    pub variant: Variant,
+    #[serde_as(as = "TimestampMilliSeconds")]
+    pub starts_at: PrimitiveDateTime,
+    #[serde_as(as = "TimestampMilliSeconds")]
+    pub finishes_at: PrimitiveDateTime,
+    pub status: ArenaStatus,
+    pub perf: Perf,
+    pub seconds_to_start: Option<u32>,
+    #[serde(default)]
+    pub has_max_rating: bool,
+    pub max_rating: Option<RatingCondition>,
+    pub min_rating: Option<RatingCondition>,
+    pub min_rated_games: Option<GamesCondition>,
+    #[serde(default)]
+    pub bots_allowed: bool,
+    pub min_account_age_in_days: Option<u16>,
+    #[serde(default)]
+    pub only_titled: bool,
+    pub team_member: Option<String>,
+    #[serde(default)]
+    pub private: bool,
+    pub position: Option<TournamentPosition>,
+    pub schedule: Option<TournamentSchedule>,
+    pub team_battle: Option<TeamBattle>,
+    pub winner: Option<TournamentWinner>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaTournamentFull {
+    pub id: String,
+    pub created_by: String,
+    #[serde(with = "time::serde::iso8601")]
+    pub starts_at: OffsetDateTime,
+    pub system: String,
+    pub full_name: String,
+    pub minutes: u16,
+    pub perf: Perf,
+    pub clock: TournamentClock,
+    pub variant: VariantMode,
+    pub rated: bool,
+    #[serde(default)]
+    pub berserkable: bool,
+    pub nb_players: u32,
+    #[serde(default)]
+    pub is_started: bool,
+    #[serde(default)]
+    pub is_finished: bool,
+    #[serde(default)]
+    pub is_recently_finished: bool,
+    #[serde(default)]
+    pub pairings_closed: bool,
+    pub description: Option<String>,
+    pub seconds_to_start: Option<u32>,
+    pub seconds_to_finish: Option<u32>,
+    pub verdicts: Option<TournamentVerdicts>,
+    #[serde(default)]
+    pub duels: Vec<TournamentDuel>,
+    pub standing: Option<TournamentStanding>,
+    #[serde(default)]
+    pub podium: Vec<TournamentPodiumPlayer>,
+    pub stats: Option<TournamentStats>,
+    pub position: Option<TournamentPosition>,
+    pub team_battle: Option<TeamBattleInfo>,
+    #[serde(default)]
+    pub team_standing: Vec<TeamStanding>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum ArenaStatus {
+    Created = 10,
+    Started = 20,
+    Finished = 30,
+}
+
+impl TryFrom<u8> for ArenaStatus {
+    type Error = String;
+
+    fn try_from(status: u8) -> Result<Self, Self::Error> {
+        match status {
+            10 => Ok(Self::Created),
+            20 => Ok(Self::Started),
+            30 => Ok(Self::Finished),
+            _ => Err(format!("unknown arena status: {status}")),
+        }
+    }
+}
+
+impl From<ArenaStatus> for u8 {
+    fn from(status: ArenaStatus) -> Self {
+        status as u8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentClock {
+    pub limit: u32,
+    pub increment: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct RatingCondition {
+    pub perf: String,
+    pub rating: u16,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct GamesCondition {
+    pub nb: u16,
+    pub perf: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentPosition {
+    pub eco: Option<String>,
+    pub name: Option<String>,
+    pub wiki_path: Option<String>,
+    pub fen: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentSchedule {
+    pub freq: String,
+    pub speed: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TeamBattle {
+    pub teams: Vec<String>,
+    pub nb_leaders: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TeamBattleInfo {
+    pub teams: BTreeMap<String, Vec<String>>,
+    pub nb_leaders: u8,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentWinner {
+    pub id: String,
+    pub name: String,
+    pub title: Option<Title>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentVerdicts {
+    pub list: Vec<TournamentVerdict>,
+    pub accepted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentVerdict {
+    pub condition: String,
+    pub verdict: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentDuel {
+    pub id: String,
+    #[serde(rename = "p")]
+    pub players: Vec<TournamentDuelPlayer>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentDuelPlayer {
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "r")]
+    pub rating: u16,
+    #[serde(rename = "k")]
+    pub rank: Option<u32>,
+    #[serde(rename = "t")]
+    pub title: Option<Title>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentStanding {
+    pub page: u32,
+    pub players: Vec<TournamentStandingPlayer>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentStandingPlayer {
+    pub name: String,
+    pub title: Option<Title>,
+    pub rank: u32,
+    pub rating: u16,
+    pub score: u32,
+    #[serde(default)]
+    pub provisional: bool,
+    #[serde(default)]
+    pub withdraw: bool,
+    pub team: Option<String>,
+    pub flair: Option<String>,
+    pub sheet: Option<TournamentSheet>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentSheet {
+    pub scores: String,
+    #[serde(default)]
+    pub fire: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentPodiumPlayer {
+    pub name: String,
+    pub title: Option<Title>,
+    pub rank: u32,
+    pub rating: u16,
+    pub score: u32,
+    pub nb: TournamentPodiumStats,
+    pub performance: Option<u16>,
+    pub flair: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TournamentPodiumStats {
+    pub game: u32,
+    pub berserk: u32,
+    pub win: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentStats {
+    pub games: u32,
+    pub moves: u32,
+    pub white_wins: u32,
+    pub black_wins: u32,
+    pub draws: u32,
+    pub berserks: u32,
+    pub average_rating: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct ArenaTeamStanding {
+    pub id: String,
+    pub teams: Vec<TeamStanding>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TeamStanding {
+    pub rank: u32,
+    pub id: String,
+    pub score: u32,
+    pub players: Vec<TeamStandingPlayer>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TeamStandingPlayer {
+    pub user: TeamStandingUser,
+    pub score: u32,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct TeamStandingUser {
+    pub id: String,
+    pub name: String,
+    pub title: Option<Title>,
+    pub flair: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct ArenaResult {
+    pub rank: u32,
+    pub score: u32,
+    pub rating: u16,
+    pub username: String,
+    pub title: Option<Title>,
+    pub performance: Option<u16>,
+    pub team: Option<String>,
+    pub flair: Option<String>,
+    pub sheet: Option<TournamentSheet>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct PlayedArenaTournament {
+    pub tournament: ArenaTournament,
+    pub player: PlayedArenaPlayer,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde-strict", serde(deny_unknown_fields))]
+pub struct PlayedArenaPlayer {
+    pub games: u32,
+    pub score: u32,
+    pub rank: u32,
+    pub performance: Option<u16>,
+}
@@ -0,0 +1,182 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use reqwest::{Client, IntoUrl, Url};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    client::{LicheszterBuilder, BASE_URL, USER_AGENT},
+    error::{LichessError, Result},
+    models::oauth::{AccessToken, Scope},
+};
+
+/// [`OAuthClient`] obtains access tokens using the OAuth2 authorization code flow with PKCE.
+///
+/// The user is first sent to the URL of an [`AuthorizationRequest`] created by [`OAuthClient::authorize()`],
+/// after which Lichess redirects them back to the redirect URI with a `code` and a `state` query parameter.
+/// The code can then be exchanged for an [`AccessToken`] with [`OAuthClient::exchange_code()`].
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    client: Client,
+    base_url: Url,
+    client_id: String,
+    redirect_uri: String,
+}
+
+impl OAuthClient {
+    /// Constructs a new [`OAuthClient`] for the official Lichess server.
+    /// No registration is required, the client ID can be any string identifying your application.
+    ///
+    /// # Panics
+    /// Panics if the HTTP client cannot be initialized (extremely rare).
+    #[must_use]
+    pub fn new(client_id: &str, redirect_uri: &str) -> Self {
+        Self {
+            client: Client::builder()
+                .user_agent(USER_AGENT)
+                .tls_backend_rustls()
+                .build()
+                .expect("Failed to build HTTP client - this should never fail with default configuration"),
+            base_url: Url::parse(BASE_URL).expect("BASE_URL constant is not a valid URL"),
+            client_id: client_id.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+        }
+    }
+
+    /// Insert a valid base URL of a custom Lichess server.
+    /// The URL is also used by the builders returned from [`OAuthClient::builder()`].
+    ///
+    /// # Errors
+    /// Returns an error if the given URL cannot be converted into a [`reqwest::Url`].
+    pub fn with_base_url(mut self, url: impl IntoUrl) -> Result<Self> {
+        self.base_url = url.into_url()?;
+        Ok(self)
+    }
+
+    /// Create a new [`AuthorizationRequest`] asking the user to grant the given scopes.
+    /// A new random code verifier and state are generated for every request.
+    ///
+    /// # Errors
+    /// Returns an error if the random values cannot be generated.
+    pub fn authorize(&self, scopes: &[Scope]) -> Result<AuthorizationRequest> {
+        let code_verifier = random_string()?;
+        let state = random_string()?;
+
+        // The challenge is the SHA-256 hash of the verifier
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+        let scope = scopes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut url = self.url("oauth");
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("code_challenge_method", "S256")
+            .append_pair("code_challenge", &code_challenge)
+            .append_pair("scope", &scope)
+            .append_pair("state", &state);
+
+        Ok(AuthorizationRequest {
+            url,
+            state,
+            code_verifier,
+        })
+    }
+
+    /// Exchange the authorization code received at the redirect URI for an access token.
+    /// The code verifier must be the one of the [`AuthorizationRequest`] that was used to obtain the code.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails or the response cannot be deserialized.
+    pub async fn exchange_code(&self, code: &str, code_verifier: &str) -> Result<AccessToken> {
+        let builder = self.client.post(self.url("api/token")).form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("code_verifier", code_verifier),
+            ("redirect_uri", &self.redirect_uri),
+            ("client_id", &self.client_id),
+        ]);
+
+        let response = builder.send().await?;
+        if !response.status().is_success() {
+            return Err(LichessError::from_response(response).await?.into());
+        }
+
+        Ok(serde_json::from_slice(&response.bytes().await?)?)
+    }
+
+    /// Revoke an access token, making it unusable for any further requests.
+    ///
+    /// # Errors
+    /// Returns an error if the API request fails.
+    pub async fn revoke(&self, access_token: &str) -> Result<()> {
+        let builder = self
+            .client
+            .delete(self.url("api/token"))
+            .bearer_auth(access_token);
+
+        let response = builder.send().await?;
+        if !response.status().is_success() {
+            return Err(LichessError::from_response(response).await?.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns a [`LicheszterBuilder`] that is authenticated with the given access token
+    /// and uses the same base URL as this [`OAuthClient`].
+    ///
+    /// # Errors
+    /// Returns an error if the access token contains invalid characters or the HTTP client fails to initialize.
+    pub fn builder(&self, token: &AccessToken) -> Result<LicheszterBuilder> {
+        LicheszterBuilder::new()
+            .with_base_url(self.base_url.clone())?
+            .with_authentication(&token.access_token)
+    }
+
+    // Create a URL pointing to the given path of the Lichess server
+    fn url(&self, path: &str) -> Url {
+        let mut url = self.base_url.clone();
+        url.set_path(path);
+        url
+    }
+}
+
+/// [`AuthorizationRequest`] contains the URL the user should visit to authorize the application,
+/// along with the values required to complete the authorization afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationRequest {
+    url: Url,
+    state: String,
+    code_verifier: String,
+}
+
+impl AuthorizationRequest {
+    /// The URL of the Lichess authorization page.
+    #[must_use]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The state that Lichess returns to the redirect URI.
+    /// It should be compared with the received `state` query parameter to prevent CSRF attacks.
+    #[must_use]
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// The code verifier that must be passed to [`OAuthClient::exchange_code()`].
+    #[must_use]
+    pub fn code_verifier(&self) -> &str {
+        &self.code_verifier
+    }
+}
+
+// Generate a random, URL-safe string of 43 characters
+fn random_string() -> Result<String> {
+    let mut bytes = [0; 32];
+    getrandom::getrandom(&mut bytes).map_err(std::io::Error::from)?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
@@ -0,0 +1,716 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    models::{
+        common::FinalColor,
+        game::{Game, GameStatus, Player, VariantMode},
+        score::Score,
+    },
+};
+
+/// A game parsed from PGN, e.g. from the PGN exports of Lichess.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PgnGame {
+    /// The tags of the game in their original order, e.g. `("White", "DrNykterstein")`.
+    pub tags: Vec<(String, String)>,
+    /// The comment before the first move, if any.
+    pub comment: Option<String>,
+    /// The moves of the mainline.
+    pub moves: Vec<PgnMove>,
+    /// The result written after the moves, e.g. `1-0` or `*`.
+    pub result: Option<String>,
+}
+
+/// A move parsed from PGN, including its annotations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PgnMove {
+    /// The move in SAN format, e.g. `Nf3`.
+    pub san: String,
+    /// The numeric annotation glyphs of the move, e.g. `2` for `?`.
+    pub nags: Vec<u8>,
+    /// The comment before the move, only present on the first move of a variation.
+    pub starting_comment: Option<String>,
+    /// The comment after the move, without the `%clk` and `%eval` commands.
+    pub comment: Option<String>,
+    /// The remaining clock time after the move, from the `%clk` command.
+    pub clock: Option<Duration>,
+    /// The evaluation after the move, from the `%eval` command.
+    pub eval: Option<PgnEval>,
+    /// The alternatives to this move, each one a sequence of moves.
+    pub variations: Vec<Vec<PgnMove>>,
+}
+
+/// An evaluation from the `%eval` command of a PGN comment, from the perspective of white.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PgnEval {
+    /// An evaluation in centipawns.
+    Centipawns(i32),
+    /// A forced mate in the given number of moves, negative if black is mating.
+    Mate(i32),
+}
+
+impl PgnGame {
+    /// Parse a single game from PGN.
+    ///
+    /// # Errors
+    /// Returns an error if the PGN is malformed.
+    pub fn parse(pgn: &str) -> Result<Self> {
+        let mut game = Self::default();
+        let mut movetext = String::new();
+
+        for line in pgn.lines() {
+            let line = line.trim();
+            if movetext.trim().is_empty() && line.starts_with('[') {
+                game.tags.push(parse_tag(line)?);
+            } else if !line.starts_with('%') {
+                movetext.push_str(line);
+                movetext.push('\n');
+            }
+        }
+
+        let mut tokens = Tokens::new(&movetext);
+        game.moves = parse_moves(&mut tokens, Some(&mut game.comment), &mut game.result)?;
+        match tokens.next() {
+            None => Ok(game),
+            Some(token) => Err(pgn_error(&format!("unexpected token: {token}"))),
+        }
+    }
+
+    /// Returns the value of the given tag, if it exists.
+    #[must_use]
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Replay the mainline from the `FEN` tag (or the standard starting position) and convert the moves into UCI format.
+    ///
+    /// # Errors
+    /// Returns an error if the FEN is invalid or any of the moves is illegal.
+    #[cfg(feature = "position")]
+    pub fn uci_moves(&self) -> Result<Vec<String>> {
+        use crate::position::Position;
+
+        let mut position = match self.tag("FEN") {
+            Some(fen) => Position::from_fen(fen)?,
+            None => Position::new(),
+        };
+        if self.tag("Variant") == Some("Chess960") {
+            position.set_chess960(true);
+        }
+
+        self.moves
+            .iter()
+            .map(|m| {
+                let parsed = position.parse_san(&m.san)?;
+                let uci = position.uci(&parsed);
+                position.play(&parsed)?;
+                Ok(uci)
+            })
+            .collect()
+    }
+
+    // The number of half-moves played before the first move, based on the `FEN` tag
+    fn start_ply(&self) -> usize {
+        let Some(fen) = self.tag("FEN") else {
+            return 0;
+        };
+
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let fullmoves = fields
+            .get(5)
+            .and_then(|n| n.parse::<usize>().ok())
+            .unwrap_or(1);
+        (fullmoves.max(1) - 1) * 2 + usize::from(fields.get(1) == Some(&"b"))
+    }
+}
+
+impl FromStr for PgnGame {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl Display for PgnGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.tags {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(f, "[{name} \"{value}\"]")?;
+        }
+        if !self.tags.is_empty() {
+            writeln!(f)?;
+        }
+
+        let mut movetext = String::new();
+        if let Some(comment) = &self.comment {
+            write!(movetext, "{{ {comment} }} ")?;
+        }
+        write_moves(&mut movetext, &self.moves, self.start_ply())?;
+        movetext.push_str(self.result.as_deref().unwrap_or("*"));
+
+        writeln!(f, "{movetext}")
+    }
+}
+
+impl From<&Game> for PgnGame {
+    fn from(game: &Game) -> Self {
+        let mut tags = Vec::new();
+        let mut tag = |name: &str, value: String| tags.push((name.to_string(), value));
+        let date = game.created_at.date();
+        let time = game.created_at.time();
+        let result = pgn_result(game);
+
+        let event = if game.rated { "Rated" } else { "Casual" };
+        tag("Event", format!("{event} {:?} game", game.speed));
+        tag("Site", format!("https://lichess.org/{}", game.id));
+        let date = format!(
+            "{:04}.{:02}.{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        );
+        tag("Date", date.clone());
+        tag("White", player_name(&game.players.white));
+        tag("Black", player_name(&game.players.black));
+        tag("Result", result.to_string());
+        tag("UTCDate", date);
+        tag(
+            "UTCTime",
+            format!(
+                "{:02}:{:02}:{:02}",
+                time.hour(),
+                time.minute(),
+                time.second()
+            ),
+        );
+
+        for (color, player) in [
+            ("White", &game.players.white),
+            ("Black", &game.players.black),
+        ] {
+            match player {
+                Player::Human(human) => {
+                    tag(&format!("{color}Elo"), human.rating.to_string());
+                    if let Some(diff) = human.rating_diff {
+                        tag(&format!("{color}RatingDiff"), format!("{diff:+}"));
+                    }
+                    if let Some(title) = human.user.title {
+                        tag(&format!("{color}Title"), format!("{title:?}"));
+                    }
+                }
+                Player::Simple { rating, .. } => tag(&format!("{color}Elo"), rating.to_string()),
+                Player::Computer(_) => tag(&format!("{color}Elo"), String::from("?")),
+            }
+        }
+
+        tag("Variant", variant_name(game.variant).to_string());
+        let time_control = match &game.clock {
+            Some(clock) => format!("{}+{}", clock.initial, clock.increment),
+            None => String::from("-"),
+        };
+        tag("TimeControl", time_control);
+        if let Some(opening) = &game.opening {
+            tag("ECO", opening.eco.clone());
+            tag("Opening", opening.name.clone());
+        }
+        tag("Termination", termination(game.status).to_string());
+        if let Some(fen) = &game.initial_fen {
+            tag("FEN", fen.clone());
+            tag("SetUp", String::from("1"));
+        }
+
+        // Clocks are given in centiseconds and evaluations in centipawns
+        let sans = game.moves.as_deref().unwrap_or_default().split_whitespace();
+        let moves = sans
+            .enumerate()
+            .map(|(i, san)| PgnMove {
+                san: san.to_string(),
+                clock: game
+                    .clocks
+                    .get(i)
+                    .map(|cs| Duration::from_millis(u64::from(*cs) * 10)),
+                eval: game
+                    .analysis
+                    .get(i)
+                    .and_then(|analysis| analysis.score)
+                    .map(PgnEval::from),
+                ..PgnMove::default()
+            })
+            .collect();
+
+        Self {
+            tags,
+            comment: None,
+            moves,
+            result: Some(result.to_string()),
+        }
+    }
+}
+
+impl From<Score> for PgnEval {
+    fn from(score: Score) -> Self {
+        match score {
+            Score::Centipawns(cp) => Self::Centipawns(cp),
+            Score::Mate(moves) => Self::Mate(moves),
+        }
+    }
+}
+
+impl From<PgnEval> for Score {
+    fn from(eval: PgnEval) -> Self {
+        match eval {
+            PgnEval::Centipawns(cp) => Self::Centipawns(cp),
+            PgnEval::Mate(moves) => Self::Mate(moves),
+        }
+    }
+}
+
+impl Display for PgnEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Centipawns(cp) => {
+                let sign = if *cp < 0 { "-" } else { "" };
+                write!(f, "{sign}{}.{:02}", cp.abs() / 100, cp.abs() % 100)
+            }
+            Self::Mate(moves) => write!(f, "#{moves}"),
+        }
+    }
+}
+
+/// [`PgnReader`] splits a PGN text that arrives in chunks, e.g. a streamed PGN export, into individual games.
+///
+/// Feed the chunks to [`push()`](fn@PgnReader::push) and take the completed games with
+/// [`next_game()`](fn@PgnReader::next_game). Once all chunks are fed, call [`finish()`](fn@PgnReader::finish)
+/// to complete the last game.
+#[derive(Clone, Debug, Default)]
+pub struct PgnReader {
+    buffer: String,
+    current: String,
+    in_movetext: bool,
+    in_comment: bool,
+    games: VecDeque<String>,
+}
+
+impl PgnReader {
+    /// Create a new [`PgnReader`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next chunk of PGN text.
+    pub fn push(&mut self, chunk: &str) {
+        self.buffer.push_str(chunk);
+        while let Some(end) = self.buffer.find('\n') {
+            let line: String = self.buffer.drain(..=end).collect();
+            self.push_line(&line);
+        }
+    }
+
+    /// Mark the end of the PGN text, completing the last game.
+    pub fn finish(&mut self) {
+        let line = std::mem::take(&mut self.buffer);
+        self.push_line(&line);
+        self.complete();
+    }
+
+    /// Returns the PGN text of the next completed game, if any.
+    pub fn next_pgn(&mut self) -> Option<String> {
+        self.games.pop_front()
+    }
+
+    /// Parse and return the next completed game, if any.
+    pub fn next_game(&mut self) -> Option<Result<PgnGame>> {
+        self.next_pgn().map(|pgn| PgnGame::parse(&pgn))
+    }
+
+    // A tag after the moves starts a new game, unless it is inside a comment
+    fn push_line(&mut self, line: &str) {
+        let trimmed = line.trim();
+        if !self.in_comment && trimmed.starts_with('[') && self.in_movetext {
+            self.complete();
+        }
+        if !self.in_comment && !trimmed.is_empty() && !trimmed.starts_with('[') {
+            self.in_movetext = true;
+        }
+
+        for c in trimmed.chars() {
+            match c {
+                '{' => self.in_comment = true,
+                '}' => self.in_comment = false,
+                _ => {}
+            }
+        }
+        self.current.push_str(line);
+    }
+
+    fn complete(&mut self) {
+        let game = std::mem::take(&mut self.current);
+        if !game.trim().is_empty() {
+            self.games.push_back(format!("{}\n", game.trim()));
+        }
+        self.in_movetext = false;
+        self.in_comment = false;
+    }
+}
+
+/// Parse all games from a PGN text that contains one or more games.
+///
+/// # Errors
+/// Returns an error if any of the games is malformed.
+pub fn parse_all(pgn: &str) -> Result<Vec<PgnGame>> {
+    let mut reader = PgnReader::new();
+    reader.push(pgn);
+    reader.finish();
+
+    std::iter::from_fn(|| reader.next_game()).collect()
+}
+
+// A tokenizer for the movetext of a game
+struct Tokens<'a> {
+    text: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        Self { text: self.text }.next()
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.text = self.text.trim_start();
+        let first = self.text.chars().next()?;
+
+        let len = match first {
+            '{' => self.text.find('}').map_or(self.text.len(), |end| end + 1),
+            ';' => self.text.find('\n').unwrap_or(self.text.len()),
+            '(' | ')' => 1,
+            _ => self
+                .text
+                .find(|c: char| c.is_whitespace() || matches!(c, '{' | '}' | '(' | ')' | ';'))
+                .unwrap_or(self.text.len()),
+        };
+
+        let (token, rest) = self.text.split_at(len);
+        self.text = rest;
+        Some(token)
+    }
+}
+
+// Parse a sequence of moves until the end of the movetext or the end of a variation
+fn parse_moves(
+    tokens: &mut Tokens,
+    mut comment: Option<&mut Option<String>>,
+    result: &mut Option<String>,
+) -> Result<Vec<PgnMove>> {
+    let mut moves: Vec<PgnMove> = Vec::new();
+    let mut starting_comment = None;
+
+    while let Some(token) = tokens.peek() {
+        if token == ")" {
+            break;
+        }
+        tokens.next();
+
+        if let Some(text) = token.strip_prefix('{') {
+            let text = text
+                .strip_suffix('}')
+                .ok_or_else(|| pgn_error("unterminated comment"))?;
+            match moves.last_mut() {
+                Some(last) => parse_comment(last, text)?,
+                // A comment before the first move belongs to the game, or to the first move of a variation
+                None => match comment.as_deref_mut() {
+                    Some(comment) => *comment = clean_comment(text),
+                    None => starting_comment = clean_comment(text),
+                },
+            }
+        } else if token.starts_with(';') {
+            continue;
+        } else if token == "(" {
+            let variation = parse_moves(tokens, None, &mut None)?;
+            if tokens.next() != Some(")") {
+                return Err(pgn_error("unterminated variation"));
+            }
+            let last = moves
+                .last_mut()
+                .ok_or_else(|| pgn_error("variation without a move"))?;
+            last.variations.push(variation);
+        } else if let Some(nag) = token.strip_prefix('$') {
+            let nag = nag
+                .parse()
+                .map_err(|_| pgn_error(&format!("invalid NAG: {token}")))?;
+            if let Some(last) = moves.last_mut() {
+                last.nags.push(nag);
+            }
+        } else if matches!(token, "1-0" | "0-1" | "1/2-1/2" | "*") {
+            *result = Some(token.to_string());
+        } else {
+            // Skip move numbers, which may be attached to the move, e.g. `1.e4`
+            let san = token.rsplit('.').next().unwrap_or_default();
+            if san.is_empty() {
+                continue;
+            }
+
+            let (san, nag) = split_suffix(san);
+            moves.push(PgnMove {
+                san: san.to_string(),
+                nags: nag.into_iter().collect(),
+                starting_comment: starting_comment.take(),
+                ..PgnMove::default()
+            });
+        }
+    }
+
+    Ok(moves)
+}
+
+// Split the move annotation suffix, e.g. `!?`, from a SAN move and convert it into a NAG
+fn split_suffix(san: &str) -> (&str, Option<u8>) {
+    let suffixes = [
+        ("!!", 3),
+        ("??", 4),
+        ("!?", 5),
+        ("?!", 6),
+        ("!", 1),
+        ("?", 2),
+    ];
+    suffixes
+        .into_iter()
+        .find_map(|(suffix, nag)| Some((san.strip_suffix(suffix)?, Some(nag))))
+        .unwrap_or((san, None))
+}
+
+// Extract the `%clk` and `%eval` commands from a comment, keeping the rest as text
+fn parse_comment(m: &mut PgnMove, text: &str) -> Result<()> {
+    let mut rest = String::new();
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find("[%") {
+        rest.push_str(&remaining[..start]);
+        let end = remaining[start..]
+            .find(']')
+            .ok_or_else(|| pgn_error("unterminated comment command"))?;
+        let command = &remaining[start + 2..start + end];
+        remaining = &remaining[start + end + 1..];
+
+        let (name, value) = command.split_once(' ').unwrap_or((command, ""));
+        match name {
+            "clk" => m.clock = Some(parse_clock(value.trim())?),
+            "eval" => m.eval = Some(parse_eval(value.trim())?),
+            _ => {
+                rest.push_str("[%");
+                rest.push_str(command);
+                rest.push(']');
+            }
+        }
+    }
+    rest.push_str(remaining);
+
+    if let Some(text) = clean_comment(&rest) {
+        m.comment = Some(match m.comment.take() {
+            Some(previous) => format!("{previous} {text}"),
+            None => text,
+        });
+    }
+
+    Ok(())
+}
+
+fn clean_comment(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+// Parse a clock time in the format `h:mm:ss`, optionally with fractions of a second
+fn parse_clock(value: &str) -> Result<Duration> {
+    let error = || pgn_error(&format!("invalid clock: {value}"));
+    let mut parts = value.split(':').rev();
+    let seconds: f64 = parts
+        .next()
+        .ok_or_else(error)?
+        .parse()
+        .map_err(|_| error())?;
+    let minutes: u64 = parts.next().unwrap_or("0").parse().map_err(|_| error())?;
+    let hours: u64 = parts.next().unwrap_or("0").parse().map_err(|_| error())?;
+
+    if seconds < 0.0 || parts.next().is_some() {
+        return Err(error());
+    }
+    Ok(Duration::from_secs(hours * 3600 + minutes * 60) + Duration::from_secs_f64(seconds))
+}
+
+// Parse an evaluation in pawns, e.g. `0.17`, or a mate, e.g. `#-3`, ignoring the search depth if it is given
+fn parse_eval(value: &str) -> Result<PgnEval> {
+    let error = || pgn_error(&format!("invalid evaluation: {value}"));
+    let value = value.split(',').next().unwrap_or_default();
+
+    match value.strip_prefix('#') {
+        Some(mate) => Ok(PgnEval::Mate(mate.parse().map_err(|_| error())?)),
+        None => {
+            let pawns: f64 = value.parse().map_err(|_| error())?;
+            Ok(PgnEval::Centipawns((pawns * 100.0).round() as i32))
+        }
+    }
+}
+
+// Parse a tag in the format `[Name "Value"]`
+fn parse_tag(line: &str) -> Result<(String, String)> {
+    let error = || pgn_error(&format!("invalid tag: {line}"));
+    let inner = line
+        .strip_prefix('[')
+        .and_then(|line| line.strip_suffix(']'))
+        .ok_or_else(error)?;
+    let (name, value) = inner.split_once(char::is_whitespace).ok_or_else(error)?;
+    let value = value
+        .trim()
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(error)?;
+
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+
+    Ok((name.to_string(), unescaped))
+}
+
+// Write a sequence of moves, numbering them from the given ply
+fn write_moves(out: &mut String, moves: &[PgnMove], ply: usize) -> fmt::Result {
+    let mut numbered = false;
+
+    for (i, m) in moves.iter().enumerate() {
+        let ply = ply + i;
+        if let Some(comment) = &m.starting_comment {
+            write!(out, "{{ {comment} }} ")?;
+            numbered = false;
+        }
+        if ply % 2 == 0 {
+            write!(out, "{}. ", ply / 2 + 1)?;
+        } else if !numbered {
+            write!(out, "{}... ", ply / 2 + 1)?;
+        }
+        numbered = true;
+
+        out.push_str(&m.san);
+        for nag in &m.nags {
+            write!(out, " ${nag}")?;
+        }
+        out.push(' ');
+
+        if m.eval.is_some() || m.clock.is_some() || m.comment.is_some() {
+            out.push_str("{ ");
+            if let Some(eval) = m.eval {
+                write!(out, "[%eval {eval}] ")?;
+            }
+            if let Some(clock) = m.clock {
+                let seconds = clock.as_secs();
+                write!(
+                    out,
+                    "[%clk {}:{:02}:{:02}",
+                    seconds / 3600,
+                    seconds / 60 % 60,
+                    seconds % 60
+                )?;
+                // Only write tenths of a second if there are any
+                let tenths = clock.subsec_millis() / 100;
+                if tenths > 0 {
+                    write!(out, ".{tenths}")?;
+                }
+                out.push_str("] ");
+            }
+            if let Some(comment) = &m.comment {
+                write!(out, "{comment} ")?;
+            }
+            out.push_str("} ");
+            numbered = false;
+        }
+
+        for variation in &m.variations {
+            out.push('(');
+            write_moves(out, variation, ply)?;
+            // Remove the space after the last move of the variation
+            if out.ends_with(' ') {
+                out.pop();
+            }
+            out.push_str(") ");
+            numbered = false;
+        }
+    }
+
+    Ok(())
+}
+
+fn pgn_result(game: &Game) -> &'static str {
+    match (game.winner, game.status) {
+        (Some(FinalColor::White), _) => "1-0",
+        (Some(FinalColor::Black), _) => "0-1",
+        (
+            None,
+            GameStatus::Created
+            | GameStatus::Started
+            | GameStatus::Aborted
+            | GameStatus::NoStart
+            | GameStatus::UnknownFinish,
+        ) => "*",
+        (None, _) => "1/2-1/2",
+    }
+}
+
+fn player_name(player: &Player) -> String {
+    match player {
+        Player::Human(human) => human.user.name.clone(),
+        Player::Computer(computer) => format!("lichess AI level {}", computer.ai_level),
+        Player::Simple { name, .. } => name.clone(),
+    }
+}
+
+fn variant_name(variant: VariantMode) -> &'static str {
+    match variant {
+        VariantMode::Standard => "Standard",
+        VariantMode::Chess960 => "Chess960",
+        VariantMode::Crazyhouse => "Crazyhouse",
+        VariantMode::Antichess => "Antichess",
+        VariantMode::Atomic => "Atomic",
+        VariantMode::Horde => "Horde",
+        VariantMode::KingOfTheHill => "King of the Hill",
+        VariantMode::RacingKings => "Racing Kings",
+        VariantMode::ThreeCheck => "Three-check",
+        VariantMode::FromPosition => "From Position",
+    }
+}
+
+fn termination(status: GameStatus) -> &'static str {
+    match status {
+        GameStatus::Created | GameStatus::Started => "Unterminated",
+        GameStatus::Aborted | GameStatus::NoStart => "Abandoned",
+        GameStatus::Timeout | GameStatus::OutOfTime => "Time forfeit",
+        GameStatus::Cheat => "Rules infraction",
+        _ => "Normal",
+    }
+}
+
+fn pgn_error(message: &str) -> Error {
+    Error::new(ErrorKind::Pgn, message.to_string())
+}
@@ -0,0 +1,1070 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    models::{common::Color, game::VariantMode},
+};
+
+const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+const KINGSIDE: usize = 0;
+const QUEENSIDE: usize = 1;
+
+/// A square on the chess board, e.g. `e4`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Square(u8);
+
+impl Square {
+    /// Create a new [`Square`] from a zero-based file (a = 0) and rank (1 = 0).
+    /// Returns `None` if either is out of bounds.
+    #[must_use]
+    pub fn new(file: u8, rank: u8) -> Option<Self> {
+        (file < 8 && rank < 8).then_some(Self(rank * 8 + file))
+    }
+
+    /// Returns the zero-based file of the square (a = 0).
+    #[must_use]
+    pub fn file(self) -> u8 {
+        self.0 % 8
+    }
+
+    /// Returns the zero-based rank of the square (1 = 0).
+    #[must_use]
+    pub fn rank(self) -> u8 {
+        self.0 / 8
+    }
+
+    fn at(file: u8, rank: u8) -> Self {
+        Self(rank * 8 + file)
+    }
+
+    fn offset(self, file: i8, rank: i8) -> Option<Self> {
+        let file = u8::try_from(self.file() as i8 + file).ok()?;
+        let rank = u8::try_from(self.rank() as i8 + rank).ok()?;
+        Self::new(file, rank)
+    }
+
+    fn index(self) -> usize {
+        usize::from(self.0)
+    }
+}
+
+impl FromStr for Square {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        let square = match (chars.next(), chars.next(), chars.next()) {
+            (Some(file @ 'a'..='h'), Some(rank @ '1'..='8'), None) => {
+                Self::new(file as u8 - b'a', rank as u8 - b'1')
+            }
+            _ => None,
+        };
+        square.ok_or_else(|| Error::new(ErrorKind::InvalidFen, format!("invalid square: {s}")))
+    }
+}
+
+impl Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            (b'a' + self.file()) as char,
+            (b'1' + self.rank()) as char
+        )
+    }
+}
+
+/// The role of a chess piece.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    Pawn,
+    Knight,
+    Bishop,
+    Rook,
+    Queen,
+    King,
+}
+
+impl Role {
+    // The uppercase letter of the role as used in FEN and SAN
+    fn char(self) -> char {
+        match self {
+            Self::Pawn => 'P',
+            Self::Knight => 'N',
+            Self::Bishop => 'B',
+            Self::Rook => 'R',
+            Self::Queen => 'Q',
+            Self::King => 'K',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'P' => Some(Self::Pawn),
+            'N' => Some(Self::Knight),
+            'B' => Some(Self::Bishop),
+            'R' => Some(Self::Rook),
+            'Q' => Some(Self::Queen),
+            'K' => Some(Self::King),
+            _ => None,
+        }
+    }
+}
+
+/// A chess piece of a specific color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Piece {
+    pub color: Color,
+    pub role: Role,
+}
+
+impl Piece {
+    // The letter of the piece as used in FEN, uppercase for white
+    fn char(self) -> char {
+        match self.color {
+            Color::White => self.role.char(),
+            _ => self.role.char().to_ascii_lowercase(),
+        }
+    }
+}
+
+/// A legal move in a [`Position`].
+///
+/// Moves are obtained from a [`Position`], e.g. with [`parse_uci()`](fn@Position::parse_uci),
+/// and can be converted back to UCI with [`Position::uci()`](fn@Position::uci)
+/// or to SAN with [`Position::san()`](fn@Position::san).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Move {
+    from: Square,
+    to: Square,
+    promotion: Option<Role>,
+    castle: bool,
+}
+
+impl Move {
+    /// Returns the square the piece moves from.
+    #[must_use]
+    pub fn from(&self) -> Square {
+        self.from
+    }
+
+    /// Returns the square the piece moves to.
+    /// For castling moves, this is the square of the rook.
+    #[must_use]
+    pub fn to(&self) -> Square {
+        self.to
+    }
+
+    /// Returns the role a pawn is promoted to, if any.
+    #[must_use]
+    pub fn promotion(&self) -> Option<Role> {
+        self.promotion
+    }
+
+    /// Returns true if the move is castling.
+    #[must_use]
+    pub fn is_castle(&self) -> bool {
+        self.castle
+    }
+
+    // The king and rook destination squares of a castling move
+    fn castle_squares(&self) -> (Square, Square) {
+        let rank = self.from.rank();
+        if self.to.file() > self.from.file() {
+            (Square::at(6, rank), Square::at(5, rank))
+        } else {
+            (Square::at(2, rank), Square::at(3, rank))
+        }
+    }
+}
+
+/// [`Position`] is a chess position that follows the rules of standard chess or Chess960.
+///
+/// Positions can be built from a FEN, e.g. [`GameFull.initial_fen`](field@crate::models::game::GameFull::initial_fen),
+/// and updated by replaying the UCI moves sent by Lichess, e.g. [`GameState.moves`](field@crate::models::game::GameState::moves).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    board: [Option<Piece>; 64],
+    turn: Color,
+    castling: [[Option<u8>; 2]; 2],
+    ep_square: Option<Square>,
+    halfmoves: u32,
+    fullmoves: u32,
+    chess960: bool,
+}
+
+impl Position {
+    /// Create a new [`Position`] with the standard starting position.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_fen(STARTING_FEN).expect("STARTING_FEN constant is not a valid FEN")
+    }
+
+    /// Create a new [`Position`] from a FEN.
+    /// Castling rights can be given as `KQkq`, Shredder-FEN or X-FEN, so Chess960 positions are supported as well.
+    ///
+    /// # Errors
+    /// Returns an error if the FEN is invalid.
+    pub fn from_fen(fen: &str) -> Result<Self> {
+        let mut parts = fen.split_whitespace();
+        let placement = parts.next().ok_or_else(|| invalid_fen(fen))?;
+
+        // Parse the piece placement, starting from the 8th rank
+        let mut board = [None; 64];
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != 8 {
+            return Err(invalid_fen(fen));
+        }
+        for (rank, pieces) in (0..8).rev().zip(ranks) {
+            let mut file = 0;
+            for c in pieces.chars() {
+                // Runs of empty squares must stay within the rank
+                if let Some(empty) = c.to_digit(10) {
+                    let empty = u8::try_from(empty).map_err(|_| invalid_fen(fen))?;
+                    if !(1..=8 - file).contains(&empty) {
+                        return Err(invalid_fen(fen));
+                    }
+                    file += empty;
+                    continue;
+                }
+
+                let role = Role::from_char(c).ok_or_else(|| invalid_fen(fen))?;
+                if file >= 8 {
+                    return Err(invalid_fen(fen));
+                }
+                let color = if c.is_ascii_uppercase() {
+                    Color::White
+                } else {
+                    Color::Black
+                };
+                board[Square::at(file, rank).index()] = Some(Piece { color, role });
+                file += 1;
+            }
+            if file != 8 {
+                return Err(invalid_fen(fen));
+            }
+        }
+
+        let turn = match parts.next() {
+            Some("w") | None => Color::White,
+            Some("b") => Color::Black,
+            Some(_) => return Err(invalid_fen(fen)),
+        };
+
+        let mut position = Self {
+            board,
+            turn,
+            castling: [[None; 2]; 2],
+            ep_square: None,
+            halfmoves: 0,
+            fullmoves: 1,
+            chess960: false,
+        };
+
+        for color in [Color::White, Color::Black] {
+            let kings = position
+                .pieces(color)
+                .filter(|(_, piece)| piece.role == Role::King);
+            if kings.count() != 1 {
+                return Err(invalid_fen(fen));
+            }
+        }
+
+        match parts.next() {
+            Some("-") | None => {}
+            Some(castling) => {
+                for c in castling.chars() {
+                    position
+                        .add_castling_right(c)
+                        .ok_or_else(|| invalid_fen(fen))?;
+                }
+            }
+        }
+
+        position.ep_square = match parts.next() {
+            Some("-") | None => None,
+            Some(square) => Some(square.parse()?),
+        };
+        position.halfmoves = match parts.next() {
+            Some(halfmoves) => halfmoves.parse().map_err(|_| invalid_fen(fen))?,
+            None => 0,
+        };
+        position.fullmoves = match parts.next() {
+            Some(fullmoves) => fullmoves.parse().map_err(|_| invalid_fen(fen))?,
+            None => 1,
+        };
+
+        Ok(position)
+    }
+
+    /// Create a new [`Position`] for a Lichess game from its initial FEN (or `startpos`) and variant.
+    ///
+    /// # Errors
+    /// Returns an error if the FEN is invalid or the variant is neither standard chess nor Chess960.
+    pub fn from_game(initial_fen: &str, variant: VariantMode) -> Result<Self> {
+        let mut position = match initial_fen {
+            "" | "startpos" => Self::new(),
+            fen => Self::from_fen(fen)?,
+        };
+
+        match variant {
+            VariantMode::Standard | VariantMode::FromPosition => {}
+            VariantMode::Chess960 => position.chess960 = true,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::UnsupportedVariant,
+                    format!("unsupported variant: {variant:?}"),
+                ))
+            }
+        }
+
+        Ok(position)
+    }
+
+    /// Returns the FEN of the position.
+    #[must_use]
+    pub fn fen(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the color whose turn it is.
+    #[must_use]
+    pub fn turn(&self) -> Color {
+        self.turn
+    }
+
+    /// Returns the piece on the given square, if any.
+    #[must_use]
+    pub fn piece_at(&self, square: Square) -> Option<Piece> {
+        self.board[square.index()]
+    }
+
+    /// Returns true if castling moves use the Chess960 notation, where the king moves to the square of the rook.
+    #[must_use]
+    pub fn is_chess960(&self) -> bool {
+        self.chess960
+    }
+
+    /// Determines whether castling moves use the Chess960 notation, where the king moves to the square of the rook.
+    pub fn set_chess960(&mut self, chess960: bool) {
+        self.chess960 = chess960;
+    }
+
+    /// Returns true if the side to move is in check.
+    #[must_use]
+    pub fn is_check(&self) -> bool {
+        self.king(self.turn)
+            .is_some_and(|king| self.is_attacked(king, opposite(self.turn)))
+    }
+
+    /// Returns true if the side to move is checkmated.
+    #[must_use]
+    pub fn is_checkmate(&self) -> bool {
+        self.is_check() && self.legal_moves().is_empty()
+    }
+
+    /// Returns true if the side to move is stalemated.
+    #[must_use]
+    pub fn is_stalemate(&self) -> bool {
+        !self.is_check() && self.legal_moves().is_empty()
+    }
+
+    /// Returns all legal moves in the position.
+    #[must_use]
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut moves = self.pseudo_legal_moves();
+        moves.retain(|m| {
+            let mut after = self.clone();
+            after.play_unchecked(m);
+            after
+                .king(self.turn)
+                .is_some_and(|king| !after.is_attacked(king, after.turn))
+        });
+        moves
+    }
+
+    /// Parse a move in UCI format, e.g. `e2e4` or `e7e8q`, and check that it is legal.
+    /// Castling is accepted both as the king moving two squares and as the king moving to the square of the rook.
+    ///
+    /// # Errors
+    /// Returns an error if the move is invalid or illegal in the position.
+    pub fn parse_uci(&self, uci: &str) -> Result<Move> {
+        let (from, to, promotion) = match uci.len() {
+            4 | 5 if uci.is_ascii() => {
+                let promotion = match uci[4..].chars().next() {
+                    Some(c) if c.is_ascii_lowercase() => {
+                        Some(Role::from_char(c).ok_or_else(|| illegal_move(uci))?)
+                    }
+                    Some(_) => return Err(illegal_move(uci)),
+                    None => None,
+                };
+                let from = uci[0..2].parse::<Square>().map_err(|_| illegal_move(uci))?;
+                let to = uci[2..4].parse::<Square>().map_err(|_| illegal_move(uci))?;
+                (from, to, promotion)
+            }
+            _ => return Err(illegal_move(uci)),
+        };
+
+        let legal = self.legal_moves();
+        let exact = legal
+            .iter()
+            .find(|m| m.from == from && m.to == to && m.promotion == promotion);
+
+        // The king moving two squares means castling in standard chess,
+        // but in Chess960 it may also be a regular king move
+        let castle = || {
+            legal.iter().find(|m| {
+                m.castle && m.from == from && m.castle_squares().0 == to && !self.chess960
+            })
+        };
+
+        exact
+            .or_else(castle)
+            .copied()
+            .ok_or_else(|| illegal_move(uci))
+    }
+
+    /// Returns true if the given move in UCI format is legal in the position.
+    #[must_use]
+    pub fn is_legal_uci(&self, uci: &str) -> bool {
+        self.parse_uci(uci).is_ok()
+    }
+
+    /// Parse a move in SAN format, e.g. `Nf3` or `exd8=Q+`, and check that it is legal.
+    ///
+    /// # Errors
+    /// Returns an error if the move is invalid or illegal in the position.
+    pub fn parse_san(&self, san: &str) -> Result<Move> {
+        let trimmed = san
+            .trim()
+            .trim_end_matches(['+', '#', '!', '?'])
+            .replace('0', "O");
+        let relaxed = |san: &str| san.replace(['x', '='], "");
+
+        let legal = self.legal_moves();
+        let found = legal
+            .iter()
+            .find(|m| self.san_plain(m, &legal) == trimmed)
+            .or_else(|| {
+                legal
+                    .iter()
+                    .find(|m| relaxed(&self.san_plain(m, &legal)) == relaxed(&trimmed))
+            });
+
+        found.copied().ok_or_else(|| illegal_move(san))
+    }
+
+    /// Returns the given move in UCI format.
+    #[must_use]
+    pub fn uci(&self, m: &Move) -> String {
+        let to = if m.castle && !self.chess960 {
+            m.castle_squares().0
+        } else {
+            m.to
+        };
+
+        match m.promotion {
+            Some(role) => format!("{}{to}{}", m.from, role.char().to_ascii_lowercase()),
+            None => format!("{}{to}", m.from),
+        }
+    }
+
+    /// Returns the given move in SAN format, including the check or checkmate suffix.
+    #[must_use]
+    pub fn san(&self, m: &Move) -> String {
+        let mut san = self.san_plain(m, &self.legal_moves());
+
+        let mut after = self.clone();
+        after.play_unchecked(m);
+        if after.is_checkmate() {
+            san.push('#');
+        } else if after.is_check() {
+            san.push('+');
+        }
+
+        san
+    }
+
+    /// Convert a move in UCI format to SAN format.
+    ///
+    /// # Errors
+    /// Returns an error if the move is invalid or illegal in the position.
+    pub fn uci_to_san(&self, uci: &str) -> Result<String> {
+        Ok(self.san(&self.parse_uci(uci)?))
+    }
+
+    /// Play a legal move.
+    ///
+    /// # Errors
+    /// Returns an error if the move is illegal in the position.
+    pub fn play(&mut self, m: &Move) -> Result<()> {
+        if !self.legal_moves().contains(m) {
+            return Err(illegal_move(&self.uci(m)));
+        }
+
+        self.play_unchecked(m);
+        Ok(())
+    }
+
+    /// Play a move in UCI format.
+    ///
+    /// # Errors
+    /// Returns an error if the move is invalid or illegal in the position.
+    pub fn play_uci(&mut self, uci: &str) -> Result<()> {
+        let m = self.parse_uci(uci)?;
+        self.play_unchecked(&m);
+        Ok(())
+    }
+
+    /// Play a space-separated list of moves in UCI format, e.g. [`GameState.moves`](field@crate::models::game::GameState::moves).
+    /// The position is left unchanged if any of the moves is illegal.
+    ///
+    /// # Errors
+    /// Returns an error if any of the moves is invalid or illegal.
+    pub fn play_uci_moves(&mut self, moves: &str) -> Result<()> {
+        let mut position = self.clone();
+        for uci in moves.split_whitespace() {
+            position.play_uci(uci)?;
+        }
+
+        *self = position;
+        Ok(())
+    }
+
+    /// Convert a space-separated list of moves in UCI format into SAN format, starting from this position.
+    ///
+    /// # Errors
+    /// Returns an error if any of the moves is invalid or illegal.
+    pub fn uci_moves_to_san(&self, moves: &str) -> Result<Vec<String>> {
+        let mut position = self.clone();
+        moves
+            .split_whitespace()
+            .map(|uci| {
+                let m = position.parse_uci(uci)?;
+                let san = position.san(&m);
+                position.play_unchecked(&m);
+                Ok(san)
+            })
+            .collect()
+    }
+
+    // Iterate over the pieces of the given color
+    fn pieces(&self, color: Color) -> impl Iterator<Item = (Square, Piece)> + '_ {
+        (0..64)
+            .map(Square)
+            .filter_map(|square| Some((square, self.board[square.index()]?)))
+            .filter(move |(_, piece)| piece.color == color)
+    }
+
+    fn king(&self, color: Color) -> Option<Square> {
+        self.pieces(color)
+            .find(|(_, piece)| piece.role == Role::King)
+            .map(|(square, _)| square)
+    }
+
+    fn is_piece(&self, square: Option<Square>, color: Color, roles: &[Role]) -> bool {
+        square
+            .and_then(|square| self.board[square.index()])
+            .is_some_and(|piece| piece.color == color && roles.contains(&piece.role))
+    }
+
+    // Check whether a square is attacked by any piece of the given color
+    fn is_attacked(&self, square: Square, by: Color) -> bool {
+        let forward = if by == Color::White { 1 } else { -1 };
+        if [-1, 1]
+            .into_iter()
+            .any(|file| self.is_piece(square.offset(file, -forward), by, &[Role::Pawn]))
+        {
+            return true;
+        }
+
+        let leapers = [(KNIGHT_OFFSETS, Role::Knight), (KING_OFFSETS, Role::King)];
+        for (offsets, role) in leapers {
+            if offsets
+                .into_iter()
+                .any(|(file, rank)| self.is_piece(square.offset(file, rank), by, &[role]))
+            {
+                return true;
+            }
+        }
+
+        let sliders = [
+            (ROOK_DIRECTIONS, Role::Rook),
+            (BISHOP_DIRECTIONS, Role::Bishop),
+        ];
+        for (directions, role) in sliders {
+            for (file, rank) in directions {
+                let mut current = square.offset(file, rank);
+                while let Some(next) = current {
+                    if self.board[next.index()].is_some() {
+                        if self.is_piece(current, by, &[role, Role::Queen]) {
+                            return true;
+                        }
+                        break;
+                    }
+                    current = next.offset(file, rank);
+                }
+            }
+        }
+
+        false
+    }
+
+    // Generate all moves without checking whether the king is left in check
+    fn pseudo_legal_moves(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let push = |moves: &mut Vec<Move>, from: Square, to: Square| {
+            moves.push(Move {
+                from,
+                to,
+                promotion: None,
+                castle: false,
+            });
+        };
+
+        for (from, piece) in self.pieces(self.turn) {
+            match piece.role {
+                Role::Pawn => self.pawn_moves(from, &mut moves),
+                Role::Knight | Role::King => {
+                    let offsets = if piece.role == Role::Knight {
+                        KNIGHT_OFFSETS
+                    } else {
+                        KING_OFFSETS
+                    };
+                    for (file, rank) in offsets {
+                        if let Some(to) = from.offset(file, rank) {
+                            if self.board[to.index()].is_none_or(|target| target.color != self.turn)
+                            {
+                                push(&mut moves, from, to);
+                            }
+                        }
+                    }
+                }
+                Role::Bishop | Role::Rook | Role::Queen => {
+                    let directions = match piece.role {
+                        Role::Bishop => &BISHOP_DIRECTIONS[..],
+                        Role::Rook => &ROOK_DIRECTIONS[..],
+                        _ => &[ROOK_DIRECTIONS, BISHOP_DIRECTIONS].concat(),
+                    };
+                    for &(file, rank) in directions {
+                        let mut current = from.offset(file, rank);
+                        while let Some(to) = current {
+                            match self.board[to.index()] {
+                                None => push(&mut moves, from, to),
+                                Some(target) => {
+                                    if target.color != self.turn {
+                                        push(&mut moves, from, to);
+                                    }
+                                    break;
+                                }
+                            }
+                            current = to.offset(file, rank);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.castling_moves(&mut moves);
+        moves
+    }
+
+    fn pawn_moves(&self, from: Square, moves: &mut Vec<Move>) {
+        let (forward, start_rank) = if self.turn == Color::White {
+            (1, 1)
+        } else {
+            (-1, 6)
+        };
+        let mut push = |to: Square| {
+            if to.rank() == 0 || to.rank() == 7 {
+                for role in [Role::Queen, Role::Rook, Role::Bishop, Role::Knight] {
+                    moves.push(Move {
+                        from,
+                        to,
+                        promotion: Some(role),
+                        castle: false,
+                    });
+                }
+            } else {
+                moves.push(Move {
+                    from,
+                    to,
+                    promotion: None,
+                    castle: false,
+                });
+            }
+        };
+
+        if let Some(to) = from
+            .offset(0, forward)
+            .filter(|to| self.board[to.index()].is_none())
+        {
+            push(to);
+            if from.rank() == start_rank {
+                if let Some(to) = to
+                    .offset(0, forward)
+                    .filter(|to| self.board[to.index()].is_none())
+                {
+                    push(to);
+                }
+            }
+        }
+
+        for file in [-1, 1] {
+            if let Some(to) = from.offset(file, forward) {
+                let capture =
+                    self.board[to.index()].is_some_and(|target| target.color != self.turn);
+                if capture || self.ep_square == Some(to) {
+                    push(to);
+                }
+            }
+        }
+    }
+
+    fn castling_moves(&self, moves: &mut Vec<Move>) {
+        let rank = back_rank(self.turn);
+        let Some(king) = self.king(self.turn).filter(|king| king.rank() == rank) else {
+            return;
+        };
+        if self.is_check() {
+            return;
+        }
+
+        for (side, rook_file) in self.castling[side_index(self.turn)].into_iter().enumerate() {
+            let Some(rook_file) = rook_file else {
+                continue;
+            };
+            let rook = Square::at(rook_file, rank);
+            if !self.is_piece(Some(rook), self.turn, &[Role::Rook]) {
+                continue;
+            }
+
+            let (king_to, rook_to) = if side == KINGSIDE { (6, 5) } else { (2, 3) };
+
+            // Every square the king and the rook pass must be empty, apart from the king and the rook themselves
+            let files = [king.file(), king_to, rook_file, rook_to];
+            let (low, high) = (*files.iter().min().unwrap(), *files.iter().max().unwrap());
+            let blocked = (low..=high).any(|file| {
+                let square = Square::at(file, rank);
+                square != king && square != rook && self.board[square.index()].is_some()
+            });
+
+            // The king may not pass through attacked squares
+            let (low, high) = (king.file().min(king_to), king.file().max(king_to));
+            let attacked = (low..=high)
+                .any(|file| self.is_attacked(Square::at(file, rank), opposite(self.turn)));
+
+            if !blocked && !attacked {
+                moves.push(Move {
+                    from: king,
+                    to: rook,
+                    promotion: None,
+                    castle: true,
+                });
+            }
+        }
+    }
+
+    // Play a move without checking whether it is legal
+    fn play_unchecked(&mut self, m: &Move) {
+        let color = self.turn;
+        let ep_square = self.ep_square.take();
+        let Some(piece) = self.board[m.from.index()].take() else {
+            return;
+        };
+        let mut reset_halfmoves = piece.role == Role::Pawn || self.board[m.to.index()].is_some();
+
+        if m.castle {
+            let rook = self.board[m.to.index()].take();
+            let (king_to, rook_to) = m.castle_squares();
+            self.board[king_to.index()] = Some(piece);
+            self.board[rook_to.index()] = rook;
+            reset_halfmoves = false;
+        } else {
+            if piece.role == Role::Pawn {
+                // Capture en passant
+                if Some(m.to) == ep_square && m.from.file() != m.to.file() {
+                    self.board[Square::at(m.to.file(), m.from.rank()).index()] = None;
+                }
+                // Allow capturing en passant after a double push
+                if m.from.rank().abs_diff(m.to.rank()) == 2 {
+                    self.ep_square =
+                        Some(Square::at(m.from.file(), (m.from.rank() + m.to.rank()) / 2));
+                }
+            }
+
+            let role = m.promotion.unwrap_or(piece.role);
+            self.board[m.to.index()] = Some(Piece { color, role });
+        }
+
+        // Moving the king or a rook, or capturing a rook, removes castling rights
+        if piece.role == Role::King {
+            self.castling[side_index(color)] = [None; 2];
+        }
+        for color in [Color::White, Color::Black] {
+            for right in &mut self.castling[side_index(color)] {
+                if right.is_some_and(|file| {
+                    let rook = Square::at(file, back_rank(color));
+                    rook == m.from || rook == m.to
+                }) {
+                    *right = None;
+                }
+            }
+        }
+
+        self.halfmoves = if reset_halfmoves {
+            0
+        } else {
+            self.halfmoves + 1
+        };
+        if color != Color::White {
+            self.fullmoves += 1;
+        }
+        self.turn = opposite(color);
+    }
+
+    // The SAN of a move without the check or checkmate suffix
+    fn san_plain(&self, m: &Move, legal: &[Move]) -> String {
+        if m.castle {
+            return String::from(if m.to.file() > m.from.file() {
+                "O-O"
+            } else {
+                "O-O-O"
+            });
+        }
+
+        let Some(piece) = self.board[m.from.index()] else {
+            return self.uci(m);
+        };
+        let capture = self.board[m.to.index()].is_some()
+            || (piece.role == Role::Pawn && m.from.file() != m.to.file());
+        let mut san = String::new();
+
+        if piece.role == Role::Pawn {
+            if capture {
+                san.push((b'a' + m.from.file()) as char);
+            }
+        } else {
+            san.push(piece.role.char());
+
+            // Disambiguate between pieces of the same role that can move to the same square
+            let others: Vec<&Move> = legal
+                .iter()
+                .filter(|other| {
+                    !other.castle
+                        && other.to == m.to
+                        && other.from != m.from
+                        && self.board[other.from.index()]
+                            .is_some_and(|other| other.role == piece.role)
+                })
+                .collect();
+            if !others.is_empty() {
+                let file = (b'a' + m.from.file()) as char;
+                let rank = (b'1' + m.from.rank()) as char;
+                if others
+                    .iter()
+                    .all(|other| other.from.file() != m.from.file())
+                {
+                    san.push(file);
+                } else if others
+                    .iter()
+                    .all(|other| other.from.rank() != m.from.rank())
+                {
+                    san.push(rank);
+                } else {
+                    san.push(file);
+                    san.push(rank);
+                }
+            }
+        }
+
+        if capture {
+            san.push('x');
+        }
+        san.push_str(&m.to.to_string());
+        if let Some(role) = m.promotion {
+            san.push('=');
+            san.push(role.char());
+        }
+
+        san
+    }
+
+    // Add a castling right from a FEN character
+    fn add_castling_right(&mut self, c: char) -> Option<()> {
+        let color = if c.is_ascii_uppercase() {
+            Color::White
+        } else {
+            Color::Black
+        };
+        let rank = back_rank(color);
+        let king = self.king(color).filter(|king| king.rank() == rank)?;
+        let is_rook =
+            |file: &u8| self.is_piece(Some(Square::at(*file, rank)), color, &[Role::Rook]);
+
+        // Standard notation refers to the outermost rook on each side of the king
+        let file = match c.to_ascii_lowercase() {
+            'k' => (king.file() + 1..8).rev().find(is_rook)?,
+            'q' => (0..king.file()).find(is_rook)?,
+            file @ 'a'..='h' => Some(file as u8 - b'a').filter(is_rook)?,
+            _ => return None,
+        };
+
+        let side = if file > king.file() {
+            KINGSIDE
+        } else {
+            QUEENSIDE
+        };
+        self.castling[side_index(color)][side] = Some(file);
+        if king.file() != 4 || (file != 0 && file != 7) {
+            self.chess960 = true;
+        }
+
+        Some(())
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for Position {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_fen(s)
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rank in (0..8).rev() {
+            let mut empty = 0;
+            for file in 0..8 {
+                match self.board[Square::at(file, rank).index()] {
+                    Some(piece) => {
+                        if empty > 0 {
+                            write!(f, "{empty}")?;
+                            empty = 0;
+                        }
+                        write!(f, "{}", piece.char())?;
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                write!(f, "{empty}")?;
+            }
+            if rank > 0 {
+                write!(f, "/")?;
+            }
+        }
+
+        write!(f, " {} ", if self.turn == Color::White { 'w' } else { 'b' })?;
+
+        // Use X-FEN, which only names the file of the rook if it is not the outermost one
+        let mut castling = String::new();
+        for color in [Color::White, Color::Black] {
+            let rank = back_rank(color);
+            for (side, file) in self.castling[side_index(color)].into_iter().enumerate() {
+                let Some(file) = file else {
+                    continue;
+                };
+                let outermost = if side == KINGSIDE {
+                    (file + 1..8).all(|other| {
+                        !self.is_piece(Some(Square::at(other, rank)), color, &[Role::Rook])
+                    })
+                } else {
+                    (0..file).all(|other| {
+                        !self.is_piece(Some(Square::at(other, rank)), color, &[Role::Rook])
+                    })
+                };
+                let c = match (outermost, side) {
+                    (true, KINGSIDE) => 'K',
+                    (true, _) => 'Q',
+                    (false, _) => (b'A' + file) as char,
+                };
+                castling.push(if color == Color::White {
+                    c
+                } else {
+                    c.to_ascii_lowercase()
+                });
+            }
+        }
+        if castling.is_empty() {
+            castling.push('-');
+        }
+        write!(f, "{castling} ")?;
+
+        // Only show the en passant square if the capture is actually legal
+        let ep_square = self.ep_square.filter(|ep_square| {
+            self.legal_moves().iter().any(|m| {
+                m.to == *ep_square
+                    && self.board[m.from.index()].is_some_and(|piece| piece.role == Role::Pawn)
+            })
+        });
+        match ep_square {
+            Some(square) => write!(f, "{square} ")?,
+            None => write!(f, "- ")?,
+        }
+
+        write!(f, "{} {}", self.halfmoves, self.fullmoves)
+    }
+}
+
+fn opposite(color: Color) -> Color {
+    if color == Color::White {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
+fn side_index(color: Color) -> usize {
+    usize::from(color != Color::White)
+}
+
+fn back_rank(color: Color) -> u8 {
+    if color == Color::White {
+        0
+    } else {
+        7
+    }
+}
+
+fn invalid_fen(fen: &str) -> Error {
+    Error::new(ErrorKind::InvalidFen, format!("invalid FEN: {fen}"))
+}
+
+fn illegal_move(m: &str) -> Error {
+    Error::new(ErrorKind::IllegalMove, format!("illegal move: {m}"))
+}
@@ -0,0 +1,436 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use futures_util::StreamExt;
+use serde::Deserialize;
+use tokio::task::JoinSet;
+
+use crate::{
+    client::{BotApi, ChallengesApi, Licheszter, LicheszterInner, ResilientItem, UrlBase},
+    config::client::ReconnectOptions,
+    error::{Error, Result},
+    models::{
+        board::{BoardState, Event},
+        challenge::{Challenge, ChallengeDecision, ChallengeDeclineReason},
+        chat::ChatLine,
+        common::Color,
+        game::{GameFull, GameState},
+    },
+    tracker,
+};
+
+const DEFAULT_MAX_GAMES: usize = 1;
+
+// How long an accepted challenge counts towards the maximum number of games if its game never starts
+const ACCEPTED_TIMEOUT: Duration = Duration::from_secs(30);
+
+// How often a move is attempted before waiting for the next game state, and how long to wait between
+// attempts, as Lichess sends nothing after rejecting a move
+const MAX_MOVE_ATTEMPTS: u32 = 3;
+const MOVE_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// A bot implementation driven by [`BotRunner`].
+///
+/// Only [`on_challenge()`](fn@BotHandler::on_challenge) and [`on_my_turn()`](fn@BotHandler::on_my_turn)
+/// need to be implemented, the other callbacks do nothing by default.
+pub trait BotHandler: Send + Sync + 'static {
+    /// Decide whether to accept an incoming challenge.
+    /// Not called if the bot is already playing the maximum number of games.
+    fn on_challenge(&self, challenge: &Challenge)
+        -> impl Future<Output = ChallengeDecision> + Send;
+
+    /// Choose the next move when it is the bot's turn.
+    /// Returning `None` skips the move for now, e.g. if the move has already been played some other way.
+    /// The handler is asked again when the next game state of the same turn is received.
+    /// If Lichess rejects the move, the handler is asked again shortly after a few times.
+    fn on_my_turn(&self, game: &BotGame) -> impl Future<Output = Option<BotMove>> + Send;
+
+    /// Called once when a game starts, before any moves are requested.
+    fn on_game_start(&self, game: &BotGame) -> impl Future<Output = ()> + Send {
+        let _ = game;
+        async {}
+    }
+
+    /// Called when a chat message is received in a game.
+    fn on_chat(&self, game: Option<&BotGame>, line: &ChatLine) -> impl Future<Output = ()> + Send {
+        let _ = (game, line);
+        async {}
+    }
+
+    /// Called once when a game is over.
+    fn on_game_end(&self, game: &BotGame) -> impl Future<Output = ()> + Send {
+        let _ = game;
+        async {}
+    }
+
+    /// Called when an error occurs while running the bot.
+    /// The game ID is present if the error is related to a specific game.
+    fn on_error(&self, game_id: Option<&str>, error: &Error) -> impl Future<Output = ()> + Send {
+        let _ = (game_id, error);
+        async {}
+    }
+}
+
+/// A move returned by [`BotHandler::on_my_turn()`](fn@BotHandler::on_my_turn).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BotMove {
+    /// The move in UCI format, e.g. `e2e4`.
+    pub uci: String,
+    /// Whether to offer (or agree to) a draw along with the move.
+    pub draw_offer: bool,
+}
+
+impl BotMove {
+    /// Create a new [`BotMove`] without a draw offer.
+    #[must_use]
+    pub fn new(uci: impl Into<String>) -> Self {
+        Self {
+            uci: uci.into(),
+            draw_offer: false,
+        }
+    }
+
+    /// Offer (or agree to) a draw along with the move.
+    #[must_use]
+    pub fn with_draw_offer(mut self) -> Self {
+        self.draw_offer = true;
+        self
+    }
+}
+
+impl From<String> for BotMove {
+    fn from(uci: String) -> Self {
+        Self::new(uci)
+    }
+}
+
+impl From<&str> for BotMove {
+    fn from(uci: &str) -> Self {
+        Self::new(uci)
+    }
+}
+
+/// A game played by [`BotRunner`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BotGame {
+    /// The color the bot is playing with.
+    pub color: Color,
+    /// The game as it was when the stream was opened.
+    pub full: GameFull,
+    /// The latest state of the game.
+    pub state: GameState,
+}
+
+impl BotGame {
+    fn new(full: GameFull, color: Color) -> Self {
+        Self {
+            color,
+            state: full.state.clone(),
+            full,
+        }
+    }
+
+    /// Returns the ID of the game.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.full.id
+    }
+
+    /// Returns the moves played so far in UCI format.
+    pub fn moves(&self) -> impl Iterator<Item = &str> {
+        self.state.moves.split_whitespace()
+    }
+
+    /// Returns the number of half-moves played so far.
+    #[must_use]
+    pub fn ply(&self) -> usize {
+        self.moves().count()
+    }
+
+    /// Returns the last move played in UCI format.
+    #[must_use]
+    pub fn last_move(&self) -> Option<&str> {
+        self.moves().last()
+    }
+
+    /// Returns the color whose turn it is.
+    #[must_use]
+    pub fn turn(&self) -> Color {
+        tracker::side_to_move(&self.full.initial_fen, self.ply())
+    }
+
+    /// Returns true if it is the bot's turn to move.
+    #[must_use]
+    pub fn is_my_turn(&self) -> bool {
+        !self.is_over() && self.turn() == self.color
+    }
+
+    /// Returns true if the game is over.
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        !tracker::is_ongoing(self.state.status)
+    }
+}
+
+/// [`BotRunner`] runs a bot using the Bot API.
+///
+/// The runner listens to incoming events, accepts or declines challenges,
+/// plays each game in its own task and submits the moves chosen by the [`BotHandler`].
+/// The event and game streams reconnect automatically when the connection is lost.
+#[derive(Debug)]
+pub struct BotRunner<H> {
+    inner: Arc<LicheszterInner>,
+    handler: Arc<H>,
+    max_games: usize,
+    reconnect: ReconnectOptions,
+}
+
+impl<H: BotHandler> BotRunner<H> {
+    /// Create a new [`BotRunner`] that uses the given client and handler.
+    /// The client must be authenticated with a token of a bot account.
+    #[must_use]
+    pub fn new(client: &Licheszter, handler: H) -> Self {
+        Self {
+            inner: Arc::clone(&client.inner),
+            handler: Arc::new(handler),
+            max_games: DEFAULT_MAX_GAMES,
+            reconnect: ReconnectOptions::default(),
+        }
+    }
+
+    /// Determines how many games can be played at the same time.
+    /// Challenges received while playing the maximum number of games are declined.
+    /// Defaults to 1.
+    #[must_use]
+    pub fn max_games(mut self, max_games: usize) -> Self {
+        self.max_games = max_games;
+        self
+    }
+
+    /// Determines how the event and game streams reconnect after the connection is lost.
+    #[must_use]
+    pub fn reconnect_options(mut self, options: ReconnectOptions) -> Self {
+        self.reconnect = options;
+        self
+    }
+
+    /// Run the bot until the event stream ends, then wait for the ongoing games to finish.
+    ///
+    /// # Errors
+    /// Returns an error if the account cannot be fetched, the event stream cannot be opened,
+    /// or the event stream gives up reconnecting.
+    pub async fn run(self) -> Result<()> {
+        let url = self.inner.req_url(UrlBase::Lichess, "api/account");
+        let account = self
+            .inner
+            .to_model::<Account>(self.inner.client.get(url))
+            .await?;
+
+        let url = self.inner.req_url(UrlBase::Lichess, "api/stream/event");
+        let mut events = self
+            .inner
+            .to_resilient_stream::<Event>(url, self.reconnect.clone())
+            .await?;
+
+        let slots = Arc::new(Mutex::new(Slots::default()));
+        let mut tasks = JoinSet::new();
+        let mut error = None;
+
+        while let Some(event) = events.next().await {
+            // Clean up the tasks of the games that are over
+            while tasks.try_join_next().is_some() {}
+
+            match event {
+                Ok(ResilientItem::Item(Event::Challenge { challenge })) => {
+                    // Outgoing challenges are reported in the event stream as well
+                    if challenge.challenger.id != account.id {
+                        self.handle_challenge(&challenge, &slots).await;
+                    }
+                }
+                Ok(ResilientItem::Item(Event::ChallengeCanceled { challenge })) => {
+                    lock(&slots).accepted.remove(&challenge.id);
+                }
+                Ok(ResilientItem::Item(Event::GameStart { game })) => {
+                    // Games started from a challenge keep the ID of the challenge
+                    let mut started = lock(&slots);
+                    started.accepted.remove(&game.id);
+                    if started.games.insert(game.id.clone()) {
+                        drop(started);
+                        let game = GameTask {
+                            bot: BotApi {
+                                inner: Arc::clone(&self.inner),
+                            },
+                            handler: Arc::clone(&self.handler),
+                            slots: Arc::clone(&slots),
+                            reconnect: self.reconnect.clone(),
+                            id: game.id,
+                            color: game.color,
+                        };
+                        tasks.spawn(game.run());
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.handler.on_error(None, &e).await;
+                    error = Some(e);
+                    continue;
+                }
+            }
+            error = None;
+        }
+
+        while tasks.join_next().await.is_some() {}
+
+        error.map_or(Ok(()), Err)
+    }
+
+    // Accept or decline an incoming challenge
+    async fn handle_challenge(&self, challenge: &Challenge, slots: &Mutex<Slots>) {
+        let decision = if lock(slots).used() >= self.max_games {
+            ChallengeDecision::Decline(ChallengeDeclineReason::Later)
+        } else {
+            self.handler.on_challenge(challenge).await
+        };
+
+        // Reserve a slot right away, as the game only starts some time after accepting the challenge
+        let accept = decision == ChallengeDecision::Accept;
+        if accept {
+            lock(slots)
+                .accepted
+                .insert(challenge.id.clone(), Instant::now());
+        }
+
+        let challenges = ChallengesApi {
+            inner: Arc::clone(&self.inner),
+        };
+        if let Err(e) = challenges.respond(&challenge.id, decision).await {
+            if accept {
+                lock(slots).accepted.remove(&challenge.id);
+            }
+            self.handler.on_error(None, &e).await;
+        }
+    }
+}
+
+// The games being played, along with the accepted challenges whose games have not started yet
+#[derive(Debug, Default)]
+struct Slots {
+    games: HashSet<String>,
+    accepted: HashMap<String, Instant>,
+}
+
+impl Slots {
+    // Count the games being played or about to start, forgetting challenges whose games never started
+    fn used(&mut self) -> usize {
+        self.accepted
+            .retain(|_, accepted| accepted.elapsed() < ACCEPTED_TIMEOUT);
+        self.games.len() + self.accepted.len()
+    }
+}
+
+// A single game played in its own task
+struct GameTask<H> {
+    bot: BotApi,
+    handler: Arc<H>,
+    slots: Arc<Mutex<Slots>>,
+    reconnect: ReconnectOptions,
+    id: String,
+    color: Color,
+}
+
+impl<H: BotHandler> GameTask<H> {
+    async fn run(self) {
+        self.play().await;
+        lock(&self.slots).games.remove(&self.id);
+    }
+
+    async fn play(&self) {
+        let mut stream = match self
+            .bot
+            .game_connect_resilient(&self.id, self.reconnect.clone())
+            .await
+        {
+            Ok(stream) => stream,
+            Err(e) => return self.handler.on_error(Some(&self.id), &e).await,
+        };
+
+        let mut game: Option<BotGame> = None;
+        let mut last_turn = None;
+        while let Some(state) = stream.next().await {
+            match state {
+                Ok(ResilientItem::Item(BoardState::GameFull(full))) => match &mut game {
+                    // The full game is only sent again if the game progressed while reconnecting
+                    Some(game) => game.state = full.state,
+                    None => {
+                        let new = game.insert(BotGame::new(*full, self.color));
+                        self.handler.on_game_start(new).await;
+                    }
+                },
+                Ok(ResilientItem::Item(BoardState::GameState(state))) => match &mut game {
+                    Some(game) => game.state = state,
+                    None => continue,
+                },
+                Ok(ResilientItem::Item(BoardState::ChatLine(line))) => {
+                    self.handler.on_chat(game.as_ref(), &line).await;
+                    continue;
+                }
+                Ok(_) => continue,
+                Err(e) => {
+                    self.handler.on_error(Some(&self.id), &e).await;
+                    continue;
+                }
+            }
+
+            let Some(game) = &game else {
+                continue;
+            };
+
+            if game.is_over() {
+                self.handler.on_game_end(game).await;
+                return;
+            }
+
+            // Game states are also sent for e.g. draw offers, so only ask for a move once per turn
+            // The turn is only done once the move has been played successfully
+            if game.is_my_turn() && last_turn != Some(game.ply()) && self.play_turn(game).await {
+                last_turn = Some(game.ply());
+            }
+        }
+    }
+
+    // Ask the handler for a move and play it, asking again if the move is rejected
+    async fn play_turn(&self, game: &BotGame) -> bool {
+        for attempt in 1..=MAX_MOVE_ATTEMPTS {
+            let Some(bot_move) = self.handler.on_my_turn(game).await else {
+                return false;
+            };
+            let result = self
+                .bot
+                .play_move(&self.id, &bot_move.uci, bot_move.draw_offer)
+                .await;
+            match result {
+                Ok(()) => return true,
+                Err(e) => self.handler.on_error(Some(&self.id), &e).await,
+            }
+            if attempt < MAX_MOVE_ATTEMPTS {
+                tokio::time::sleep(MOVE_RETRY_DELAY * attempt).await;
+            }
+        }
+        false
+    }
+}
+
+// The account of the bot, only the ID is needed
+#[derive(Deserialize)]
+struct Account {
+    id: String,
+}
+
+// Lock the ongoing games, even if another task panicked while holding the lock
+fn lock(slots: &Mutex<Slots>) -> MutexGuard<'_, Slots> {
+    slots.lock().unwrap_or_else(PoisonError::into_inner)
+}
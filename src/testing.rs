@@ -0,0 +1,533 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use reqwest::{Method, StatusCode, Url};
+use serde::Serialize;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, Mutex as AsyncMutex},
+    task::JoinHandle,
+};
+
+use crate::{client::LicheszterBuilder, error::Result};
+
+// Canned responses for endpoints that only acknowledge the request
+const OK_ROUTES: [(&str, &str); 27] = [
+    ("POST", "/api/bot/game/*/move/*"),
+    ("POST", "/api/bot/game/*/chat"),
+    ("POST", "/api/bot/game/*/abort"),
+    ("POST", "/api/bot/game/*/resign"),
+    ("POST", "/api/bot/game/*/draw/*"),
+    ("POST", "/api/bot/game/*/takeback/*"),
+    ("POST", "/api/bot/game/*/claim-victory"),
+    ("POST", "/api/bot/game/*/claim-draw"),
+    ("POST", "/api/board/game/*/move/*"),
+    ("POST", "/api/board/game/*/chat"),
+    ("POST", "/api/board/game/*/abort"),
+    ("POST", "/api/board/game/*/resign"),
+    ("POST", "/api/board/game/*/draw/*"),
+    ("POST", "/api/board/game/*/takeback/*"),
+    ("POST", "/api/board/game/*/claim-victory"),
+    ("POST", "/api/board/game/*/claim-draw"),
+    ("POST", "/api/board/game/*/berserk"),
+    ("POST", "/api/challenge/*/accept"),
+    ("POST", "/api/challenge/*/decline"),
+    ("POST", "/api/challenge/*/cancel"),
+    ("POST", "/api/challenge/*/start-clocks"),
+    ("POST", "/api/round/*/add-time/*"),
+    ("POST", "/api/account/kid"),
+    ("POST", "/api/rel/follow/*"),
+    ("POST", "/api/rel/unfollow/*"),
+    ("POST", "/api/rel/block/*"),
+    ("POST", "/api/rel/unblock/*"),
+];
+
+// Canned responses for reading game chats, which are empty by default
+const CHAT_ROUTES: [(&str, &str); 2] = [
+    ("GET", "/api/bot/game/*/chat"),
+    ("GET", "/api/board/game/*/chat"),
+];
+
+/// [`FakeLichess`] is an in-process HTTP server imitating the Lichess API, meant for testing code that uses Licheszter
+/// without a network connection or a real Lichess instance.
+///
+/// Endpoints that only acknowledge requests, such as playing moves or accepting challenges, respond successfully by default.
+/// Other responses are configured with [`FakeLichess::route()`] and [`FakeLichess::json()`], and streams are scripted with
+/// the [`FakeStream`] handles returned from e.g. [`FakeLichess::event_stream()`].
+/// Requests to unknown endpoints receive a `404 Not Found` response.
+/// The server is shut down when the [`FakeLichess`] is dropped.
+#[derive(Debug)]
+pub struct FakeLichess {
+    url: Url,
+    state: Arc<ServerState>,
+    task: JoinHandle<()>,
+}
+
+impl FakeLichess {
+    /// Start a new fake server listening on a random local port.
+    ///
+    /// # Errors
+    /// Returns an error if the server cannot be bound to a local port.
+    pub async fn start() -> Result<Self> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
+        let url = Url::parse(&format!("http://{}", listener.local_addr()?))
+            .expect("local address is not a valid URL");
+
+        let state = Arc::new(ServerState::default());
+        for (method, path) in OK_ROUTES {
+            state.add(method, path, Handler::Fixed(FakeResponse::ok()));
+        }
+        for (method, path) in CHAT_ROUTES {
+            state.add(method, path, Handler::Fixed(FakeResponse::json_text("[]")));
+        }
+
+        let task = tokio::spawn(serve(listener, Arc::clone(&state)));
+        Ok(Self { url, state, task })
+    }
+
+    /// The base URL of the server.
+    #[must_use]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns a [`LicheszterBuilder`] that sends all requests to this server.
+    #[must_use]
+    pub fn builder(&self) -> LicheszterBuilder {
+        let builder = LicheszterBuilder::new()
+            .with_base_url(self.url.clone())
+            .expect("local URL is not a valid base URL");
+
+        #[cfg(feature = "openings")]
+        let builder = builder
+            .with_openings_url(self.url.clone())
+            .expect("local URL is not a valid base URL");
+
+        #[cfg(feature = "tablebase")]
+        let builder = builder
+            .with_tablebase_url(self.url.clone())
+            .expect("local URL is not a valid base URL");
+
+        #[cfg(feature = "external_engine")]
+        let builder = builder
+            .with_engine_url(self.url.clone())
+            .expect("local URL is not a valid base URL");
+
+        builder
+    }
+
+    /// Respond to the requests matching the method and the path with the given response.
+    /// A `*` segment in the path matches any single segment, e.g. `/api/game/*`.
+    /// Routes added later take precedence over the earlier ones and the default responses.
+    pub fn route(&self, method: Method, path: &str, response: FakeResponse) {
+        self.state
+            .add(method.as_str(), path, Handler::Fixed(response));
+    }
+
+    /// Respond to the requests matching the method and the path with the given value serialized as JSON.
+    ///
+    /// # Panics
+    /// Panics if the value cannot be serialized.
+    pub fn json<T>(&self, method: Method, path: &str, body: &T)
+    where
+        T: Serialize + ?Sized,
+    {
+        self.route(method, path, FakeResponse::json(StatusCode::OK, body));
+    }
+
+    /// Respond to the requests matching the method and the path with a scriptable ndjson stream.
+    /// Every connection to the stream receives the items sent with the returned [`FakeStream`]
+    /// until [`FakeStream::disconnect()`] is called or the handle is dropped.
+    pub fn stream(&self, method: Method, path: &str) -> FakeStream {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let receiver = Arc::new(AsyncMutex::new(receiver));
+        self.state
+            .add(method.as_str(), path, Handler::Stream(receiver));
+
+        FakeStream { sender }
+    }
+
+    /// Script the stream of incoming events, opened with [`Licheszter::connect()`](fn@crate::client::Licheszter::connect).
+    pub fn event_stream(&self) -> FakeStream {
+        self.stream(Method::GET, "/api/stream/event")
+    }
+
+    /// Script the game state stream of a bot game, opened with [`bot().game_connect()`](fn@crate::client::BotApi::game_connect).
+    pub fn bot_game_stream(&self, game_id: &str) -> FakeStream {
+        self.stream(Method::GET, &format!("/api/bot/game/stream/{game_id}"))
+    }
+
+    /// Script the game state stream of a board game, opened with [`board().game_connect()`](fn@crate::client::BoardApi::game_connect).
+    pub fn board_game_stream(&self, game_id: &str) -> FakeStream {
+        self.stream(Method::GET, &format!("/api/board/game/stream/{game_id}"))
+    }
+
+    /// Script the stream of the current TV game, opened with [`tv().connect()`](fn@crate::client::TvApi::connect).
+    pub fn tv_feed(&self) -> FakeStream {
+        self.stream(Method::GET, "/api/tv/feed")
+    }
+
+    /// Returns all requests received by the server so far, in the order they were received.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Drop for FakeLichess {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// A canned HTTP response of a [`FakeLichess`] server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FakeResponse {
+    status: StatusCode,
+    content_type: String,
+    body: String,
+}
+
+impl FakeResponse {
+    /// Create a response with the given status code, content type and body.
+    #[must_use]
+    pub fn new(status: StatusCode, content_type: &str, body: &str) -> Self {
+        Self {
+            status,
+            content_type: content_type.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    /// Create a response with the given status code and value serialized as JSON.
+    ///
+    /// # Panics
+    /// Panics if the value cannot be serialized.
+    #[must_use]
+    pub fn json<T>(status: StatusCode, body: &T) -> Self
+    where
+        T: Serialize + ?Sized,
+    {
+        let body = serde_json::to_string(body).expect("failed to serialize the response body");
+        Self::new(status, "application/json", &body)
+    }
+
+    /// Create a successful `{"ok":true}` response, returned by Lichess for most actions.
+    #[must_use]
+    pub fn ok() -> Self {
+        Self::json_text(r#"{"ok":true}"#)
+    }
+
+    /// Create an error response in the format used by Lichess, e.g. `{"error":"Not your turn"}`.
+    #[must_use]
+    pub fn error(status: StatusCode, message: &str) -> Self {
+        Self::json(status, &serde_json::json!({ "error": message }))
+    }
+
+    // Create a successful JSON response from raw JSON text
+    fn json_text(body: &str) -> Self {
+        Self::new(StatusCode::OK, "application/json", body)
+    }
+}
+
+/// A handle for scripting an ndjson stream of a [`FakeLichess`] server.
+///
+/// Items can be sent before the stream is connected, in which case they are delivered once it is.
+#[derive(Clone, Debug)]
+pub struct FakeStream {
+    sender: mpsc::UnboundedSender<StreamMessage>,
+}
+
+impl FakeStream {
+    /// Send an item serialized as a single line of JSON, e.g. an [`Event`](enum@crate::models::board::Event).
+    ///
+    /// # Panics
+    /// Panics if the item cannot be serialized.
+    pub fn send<T>(&self, item: &T)
+    where
+        T: Serialize + ?Sized,
+    {
+        let line = serde_json::to_string(item).expect("failed to serialize the stream item");
+        self.send_raw(&line);
+    }
+
+    /// Send a raw line of text, which can be used to test the handling of malformed data.
+    pub fn send_raw(&self, line: &str) {
+        let _ = self.sender.send(StreamMessage::Line(line.to_string()));
+    }
+
+    /// Send an empty line, like Lichess does periodically to keep the connection alive.
+    pub fn keep_alive(&self) {
+        self.send_raw("");
+    }
+
+    /// Close the current connection after the items sent so far have been delivered.
+    /// The stream can be connected again, receiving the items sent after the disconnection.
+    pub fn disconnect(&self) {
+        let _ = self.sender.send(StreamMessage::Disconnect);
+    }
+}
+
+/// A request received by a [`FakeLichess`] server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The path of the request, e.g. `/api/account`.
+    pub path: String,
+    /// The query string of the request, if present.
+    pub query: Option<String>,
+    /// The headers of the request with lowercase names.
+    pub headers: Vec<(String, String)>,
+    /// The body of the request.
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Returns the value of the header with the given name, if present.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// A message passed from a FakeStream handle to the connected stream
+#[derive(Debug)]
+enum StreamMessage {
+    Line(String),
+    Disconnect,
+}
+
+// The way a route responds to the requests
+#[derive(Debug)]
+enum Handler {
+    Fixed(FakeResponse),
+    Stream(Arc<AsyncMutex<mpsc::UnboundedReceiver<StreamMessage>>>),
+}
+
+// A route of the server, matching requests by the method and the path
+#[derive(Debug)]
+struct Route {
+    method: String,
+    path: Vec<String>,
+    handler: Arc<Handler>,
+}
+
+impl Route {
+    // Check whether the request matches the route
+    fn matches(&self, method: &str, path: &str) -> bool {
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+        self.method == method
+            && self.path.len() == segments.len()
+            && self
+                .path
+                .iter()
+                .zip(segments)
+                .all(|(pattern, segment)| pattern == "*" || pattern == segment)
+    }
+}
+
+// The state shared between the server task and the FakeLichess handle
+#[derive(Debug, Default)]
+struct ServerState {
+    routes: Mutex<Vec<Route>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl ServerState {
+    // Add a new route that takes precedence over the existing ones
+    fn add(&self, method: &str, path: &str, handler: Handler) {
+        let route = Route {
+            method: method.to_string(),
+            path: path
+                .trim_matches('/')
+                .split('/')
+                .map(String::from)
+                .collect(),
+            handler: Arc::new(handler),
+        };
+        self.routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(route);
+    }
+
+    // Find the most recently added route matching the request
+    fn find(&self, method: &str, path: &str) -> Option<Arc<Handler>> {
+        self.routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .rev()
+            .find(|route| route.matches(method, path))
+            .map(|route| Arc::clone(&route.handler))
+    }
+}
+
+// Accept connections until the server is shut down
+async fn serve(listener: TcpListener, state: Arc<ServerState>) {
+    while let Ok((socket, _)) = listener.accept().await {
+        tokio::spawn(handle(socket, Arc::clone(&state)));
+    }
+}
+
+// Handle a single connection, which serves exactly one request
+async fn handle(socket: TcpStream, state: Arc<ServerState>) -> std::io::Result<()> {
+    let mut socket = BufReader::new(socket);
+    let Some(request) = read_request(&mut socket).await? else {
+        return Ok(());
+    };
+
+    let handler = state.find(request.method.as_str(), &request.path);
+    state
+        .requests
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(request);
+
+    let (mut reader, mut writer) = socket.into_inner().into_split();
+    match handler.as_deref() {
+        Some(Handler::Fixed(response)) => {
+            let head = format!(
+                "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                response.status,
+                response.content_type,
+                response.body.len()
+            );
+            writer.write_all(head.as_bytes()).await?;
+            writer.write_all(response.body.as_bytes()).await?;
+        }
+        Some(Handler::Stream(receiver)) => {
+            let head =
+                "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n";
+            writer.write_all(head.as_bytes()).await?;
+
+            // Only one connection at a time can receive the scripted items
+            let mut receiver = receiver.lock().await;
+            let mut buf = [0; 64];
+            loop {
+                tokio::select! {
+                    message = receiver.recv() => match message {
+                        Some(StreamMessage::Line(line)) => {
+                            writer.write_all(format!("{line}\n").as_bytes()).await?;
+                        }
+                        Some(StreamMessage::Disconnect) | None => break,
+                    },
+                    // Stop serving the items once the client has disconnected
+                    read = reader.read(&mut buf) => if matches!(read, Ok(0) | Err(_)) {
+                        return Ok(());
+                    },
+                }
+            }
+        }
+        None => {
+            let body = r#"{"error":"Not found"}"#;
+            let head = format!(
+                "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            writer.write_all(head.as_bytes()).await?;
+            writer.write_all(body.as_bytes()).await?;
+        }
+    }
+
+    writer.shutdown().await
+}
+
+// Read the request line, the headers and the body of a request
+async fn read_request(
+    socket: &mut BufReader<TcpStream>,
+) -> std::io::Result<Option<RecordedRequest>> {
+    let mut line = String::new();
+    if socket.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+
+    let mut parts = line.split_whitespace();
+    let method = parts
+        .next()
+        .and_then(|m| Method::from_bytes(m.as_bytes()).ok());
+    let target = parts.next().unwrap_or("/");
+    let Some(method) = method else {
+        return Ok(None);
+    };
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if socket.read_line(&mut line).await? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let chunked = headers
+        .iter()
+        .any(|(name, value)| name == "transfer-encoding" && value.contains("chunked"));
+    let body = if chunked {
+        read_chunked(socket).await?
+    } else {
+        let length = headers
+            .iter()
+            .find(|(name, _)| name == "content-length")
+            .and_then(|(_, value)| value.parse().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        socket.read_exact(&mut body).await?;
+        body
+    };
+
+    Ok(Some(RecordedRequest {
+        method,
+        path,
+        query,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
+
+// Read a body sent with the chunked transfer encoding, e.g. a streamed request body
+async fn read_chunked(socket: &mut BufReader<TcpStream>) -> std::io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if socket.read_line(&mut line).await? == 0 {
+            return Ok(body);
+        }
+
+        // Chunk extensions after the size are not used by reqwest
+        let size = line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).unwrap_or(0);
+        if size == 0 {
+            // Skip the trailers until the final empty line
+            loop {
+                line.clear();
+                if socket.read_line(&mut line).await? == 0 || line.trim().is_empty() {
+                    return Ok(body);
+                }
+            }
+        }
+
+        let start = body.len();
+        body.resize(start + size + 2, 0);
+        socket.read_exact(&mut body[start..]).await?;
+        body.truncate(start + size);
+    }
+}
@@ -0,0 +1,307 @@
+use std::time::{Duration, Instant};
+
+use crate::models::{
+    board::BoardState,
+    chat::ChatLine,
+    common::Color,
+    game::{GameFull, GameState, GameStatus},
+};
+
+/// [`GameTracker`] folds the states streamed by [`bot().game_connect()`](fn@crate::client::BotApi::game_connect)
+/// or [`board().game_connect()`](fn@crate::client::BoardApi::game_connect) into a consistent view of the game.
+///
+/// Feed every received [`BoardState`] to [`update()`](fn@GameTracker::update),
+/// which reports what changed, and read the current view of the game with [`snapshot()`](fn@GameTracker::snapshot).
+#[derive(Clone, Debug, Default)]
+pub struct GameTracker {
+    user_id: Option<String>,
+    color: Option<Color>,
+    game: Option<GameFull>,
+    state: Option<GameState>,
+    updated_at: Option<Instant>,
+    opponent_gone: Option<(Option<u16>, Instant)>,
+}
+
+/// A change reported by [`GameTracker::update()`](fn@GameTracker::update).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameChange {
+    /// The full game was received for the first time.
+    Started,
+    /// A move was played, in UCI format.
+    Move(String),
+    /// Moves were taken back, leaving the given number of half-moves.
+    TakenBack(usize),
+    /// The status of the game changed.
+    Status { from: GameStatus, to: GameStatus },
+    /// A player offered a draw.
+    DrawOffer(Color),
+    /// A player proposed a takeback.
+    TakebackProposal(Color),
+    /// The opponent left the game, and a win can be claimed after the given time if it is known.
+    OpponentGone(Option<Duration>),
+    /// The opponent came back to the game.
+    OpponentBack,
+    /// A chat message was received.
+    Chat(ChatLine),
+}
+
+/// A view of a game tracked by [`GameTracker`] at a specific moment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameSnapshot {
+    /// The ID of the game.
+    pub id: String,
+    /// The color of the current user, if they are playing the game.
+    pub color: Option<Color>,
+    /// The color whose turn it is.
+    pub turn: Color,
+    /// The number of half-moves played.
+    pub ply: usize,
+    /// The last move played, in UCI format.
+    pub last_move: Option<String>,
+    /// The remaining time of white, counting down while it is white's turn.
+    pub white_time: Duration,
+    /// The remaining time of black, counting down while it is black's turn.
+    pub black_time: Duration,
+    /// The clock increment of white.
+    pub white_increment: Duration,
+    /// The clock increment of black.
+    pub black_increment: Duration,
+    /// The color that is offering a draw, if any.
+    pub draw_offer: Option<Color>,
+    /// The color that is proposing a takeback, if any.
+    pub takeback_proposal: Option<Color>,
+    /// The status of the game.
+    pub status: GameStatus,
+    /// The winner of the game, if the game is over and was not drawn.
+    pub winner: Option<Color>,
+    /// Whether the opponent has left the game.
+    pub opponent_gone: bool,
+    /// How long until a win can be claimed because the opponent has left, if it is known.
+    pub claim_win_in: Option<Duration>,
+}
+
+impl GameSnapshot {
+    /// Returns true if it is the current user's turn to move.
+    #[must_use]
+    pub fn is_my_turn(&self) -> bool {
+        !self.is_over() && self.color == Some(self.turn)
+    }
+
+    /// Returns true if the game is over.
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        !is_ongoing(self.status)
+    }
+
+    /// Returns true if a win can be claimed right now because the opponent has left.
+    #[must_use]
+    pub fn can_claim_win(&self) -> bool {
+        self.opponent_gone && self.claim_win_in == Some(Duration::ZERO)
+    }
+}
+
+impl GameTracker {
+    /// Create a new [`GameTracker`] for a game played by the given user.
+    /// The color of the user is determined from the full game once it is received.
+    #[must_use]
+    pub fn new(user_id: &str) -> Self {
+        Self {
+            user_id: Some(user_id.to_lowercase()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a new [`GameTracker`] for a game played with the given color.
+    #[must_use]
+    pub fn with_color(color: Color) -> Self {
+        Self {
+            color: Some(color),
+            ..Self::default()
+        }
+    }
+
+    /// Create a new [`GameTracker`] for a game that is only watched.
+    #[must_use]
+    pub fn spectator() -> Self {
+        Self::default()
+    }
+
+    /// Fold a received state into the tracked game and report what changed.
+    /// Game states received before the full game are ignored.
+    pub fn update(&mut self, state: &BoardState) -> Vec<GameChange> {
+        let mut changes = Vec::new();
+
+        match state {
+            BoardState::GameFull(game) => {
+                if self.game.is_none() {
+                    changes.push(GameChange::Started);
+                }
+                if self.color.is_none() {
+                    self.color = self.user_id.as_deref().and_then(|id| {
+                        if game.white.id.eq_ignore_ascii_case(id) {
+                            Some(Color::White)
+                        } else if game.black.id.eq_ignore_ascii_case(id) {
+                            Some(Color::Black)
+                        } else {
+                            None
+                        }
+                    });
+                }
+                self.game = Some(game.as_ref().clone());
+                self.update_state(&game.state, &mut changes);
+            }
+            BoardState::GameState(state) => {
+                if self.game.is_some() {
+                    self.update_state(state, &mut changes);
+                }
+            }
+            BoardState::ChatLine(line) => changes.push(GameChange::Chat(line.clone())),
+            BoardState::OpponentGone(gone) => {
+                let was_gone = self.opponent_gone.is_some();
+                if gone.gone {
+                    self.opponent_gone = Some((gone.claim_win_in_seconds, Instant::now()));
+                    let claim_win_in = gone
+                        .claim_win_in_seconds
+                        .map(|s| Duration::from_secs(s.into()));
+                    changes.push(GameChange::OpponentGone(claim_win_in));
+                } else {
+                    self.opponent_gone = None;
+                    if was_gone {
+                        changes.push(GameChange::OpponentBack);
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Returns the current view of the game, or `None` if the full game has not been received yet.
+    #[must_use]
+    pub fn snapshot(&self) -> Option<GameSnapshot> {
+        let game = self.game.as_ref()?;
+        let state = self.state.as_ref()?;
+        let now = Instant::now();
+
+        let ply = state.moves.split_whitespace().count();
+        let turn = side_to_move(&game.initial_fen, ply);
+
+        // The clock of the player to move keeps running after the state was received,
+        // but Lichess only starts the clocks once both players have moved
+        let elapsed = match self.updated_at {
+            Some(updated_at) if is_ongoing(state.status) && ply >= 2 => now - updated_at,
+            _ => Duration::ZERO,
+        };
+        let mut white_time = Duration::from_millis(state.wtime);
+        let mut black_time = Duration::from_millis(state.btime);
+        match turn {
+            Color::Black => black_time = black_time.saturating_sub(elapsed),
+            _ => white_time = white_time.saturating_sub(elapsed),
+        }
+
+        let claim_win_in = self.opponent_gone.and_then(|(seconds, since)| {
+            let seconds = Duration::from_secs(seconds?.into());
+            Some(seconds.saturating_sub(now - since))
+        });
+
+        Some(GameSnapshot {
+            id: game.id.clone(),
+            color: self.color,
+            turn,
+            ply,
+            last_move: state
+                .moves
+                .split_whitespace()
+                .last()
+                .map(ToString::to_string),
+            white_time,
+            black_time,
+            white_increment: Duration::from_millis(state.winc.into()),
+            black_increment: Duration::from_millis(state.binc.into()),
+            draw_offer: offer(state.wdraw, state.bdraw),
+            takeback_proposal: offer(state.wtakeback, state.btakeback),
+            status: state.status,
+            winner: state.winner,
+            opponent_gone: self.opponent_gone.is_some(),
+            claim_win_in,
+        })
+    }
+
+    /// Returns the full game as it was last received.
+    #[must_use]
+    pub fn game(&self) -> Option<&GameFull> {
+        self.game.as_ref()
+    }
+
+    /// Returns the latest state of the game.
+    #[must_use]
+    pub fn state(&self) -> Option<&GameState> {
+        self.state.as_ref()
+    }
+
+    // Replace the latest state, comparing it to the previous one
+    fn update_state(&mut self, state: &GameState, changes: &mut Vec<GameChange>) {
+        let previous = self.state.replace(state.clone());
+        self.updated_at = Some(Instant::now());
+
+        let old_moves: Vec<&str> = previous
+            .as_ref()
+            .map(|previous| previous.moves.split_whitespace().collect())
+            .unwrap_or_default();
+        let new_moves: Vec<&str> = state.moves.split_whitespace().collect();
+
+        if new_moves.starts_with(&old_moves) {
+            let played = new_moves[old_moves.len()..].iter();
+            changes.extend(played.map(|uci| GameChange::Move((*uci).to_string())));
+        } else {
+            changes.push(GameChange::TakenBack(new_moves.len()));
+        }
+
+        let (old_draw, old_takeback) = previous.as_ref().map_or((None, None), |previous| {
+            (
+                offer(previous.wdraw, previous.bdraw),
+                offer(previous.wtakeback, previous.btakeback),
+            )
+        });
+        if let Some(color) = offer(state.wdraw, state.bdraw).filter(|_| old_draw.is_none()) {
+            changes.push(GameChange::DrawOffer(color));
+        }
+        if let Some(color) =
+            offer(state.wtakeback, state.btakeback).filter(|_| old_takeback.is_none())
+        {
+            changes.push(GameChange::TakebackProposal(color));
+        }
+
+        if let Some(from) = previous.map(|previous| previous.status) {
+            if from != state.status {
+                changes.push(GameChange::Status {
+                    from,
+                    to: state.status,
+                });
+            }
+        }
+    }
+}
+
+// Find the color whose turn it is after the given number of half-moves
+pub(crate) fn side_to_move(initial_fen: &str, ply: usize) -> Color {
+    // The side to move is the second field of the FEN, Lichess uses `startpos` for the standard starting position
+    let white_starts = initial_fen.split_whitespace().nth(1) != Some("b");
+    if white_starts == (ply % 2 == 0) {
+        Color::White
+    } else {
+        Color::Black
+    }
+}
+
+pub(crate) fn is_ongoing(status: GameStatus) -> bool {
+    matches!(status, GameStatus::Created | GameStatus::Started)
+}
+
+fn offer(white: bool, black: bool) -> Option<Color> {
+    match (white, black) {
+        (true, _) => Some(Color::White),
+        (_, true) => Some(Color::Black),
+        _ => None,
+    }
+}
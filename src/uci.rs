@@ -0,0 +1,258 @@
+use std::{ffi::OsStr, fmt::Display, process::Stdio};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    process::{Child, ChildStdin, ChildStdout, Command},
+};
+
+use crate::{
+    config::uci::GoOptions,
+    error::{Error, ErrorKind, Result},
+    models::game::{GameFull, GameState, VariantMode},
+};
+
+/// [`UciEngine`] is a local chess engine process that speaks the UCI protocol.
+///
+/// The engine can be used to choose moves for bots, e.g. from the states received
+/// by [`bot().game_connect()`](fn@crate::client::BotApi::game_connect).
+/// The process is killed when the [`UciEngine`] is dropped.
+#[derive(Debug)]
+pub struct UciEngine {
+    process: Child,
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+    name: Option<String>,
+    chess960: bool,
+}
+
+impl UciEngine {
+    /// Spawn a new UCI engine process and wait until it is ready to use.
+    ///
+    /// # Errors
+    /// Returns an error if the process cannot be spawned or it does not respond to the UCI handshake.
+    pub async fn spawn(program: impl AsRef<OsStr>) -> Result<Self> {
+        Self::spawn_with_args(program, Vec::<&OsStr>::new()).await
+    }
+
+    /// Spawn a new UCI engine process with command line arguments and wait until it is ready to use.
+    ///
+    /// # Errors
+    /// Returns an error if the process cannot be spawned or it does not respond to the UCI handshake.
+    pub async fn spawn_with_args<I, S>(program: impl AsRef<OsStr>, args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut process = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()?;
+
+        let stdin = process
+            .stdin
+            .take()
+            .ok_or_else(|| engine_error("stdin is not available"))?;
+        let stdout = process
+            .stdout
+            .take()
+            .ok_or_else(|| engine_error("stdout is not available"))?;
+
+        let mut engine = Self {
+            process,
+            stdin,
+            stdout: BufReader::new(stdout).lines(),
+            name: None,
+            chess960: false,
+        };
+
+        // Perform the UCI handshake, picking up the engine name on the way
+        engine.send("uci").await?;
+        loop {
+            let line = engine.read_line().await?;
+            if let Some(name) = line.strip_prefix("id name ") {
+                engine.name = Some(name.trim().to_string());
+            } else if line.trim() == "uciok" {
+                break;
+            }
+        }
+
+        engine.is_ready().await?;
+        Ok(engine)
+    }
+
+    /// Returns the name reported by the engine, if any.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Set an engine option, e.g. `Threads` or `Hash`.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails.
+    pub async fn set_option(&mut self, name: &str, value: impl Display) -> Result<()> {
+        self.send(&format!("setoption name {name} value {value}"))
+            .await?;
+        self.is_ready().await
+    }
+
+    /// Tell the engine that the next position is from a different game.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails.
+    pub async fn new_game(&mut self) -> Result<()> {
+        self.send("ucinewgame").await?;
+        self.is_ready().await
+    }
+
+    /// Wait until the engine is ready to receive more commands.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails.
+    pub async fn is_ready(&mut self) -> Result<()> {
+        self.send("isready").await?;
+        while self.read_line().await?.trim() != "readyok" {}
+        Ok(())
+    }
+
+    /// Choose the best move in a position given as an initial FEN (or `startpos`) and a space-separated list of UCI moves.
+    /// The returned move is in UCI format.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails or the engine does not find a move.
+    pub async fn best_move(
+        &mut self,
+        initial_fen: &str,
+        moves: &str,
+        options: &GoOptions,
+    ) -> Result<String> {
+        self.send(&position(initial_fen, moves)).await?;
+        self.send(&options.to_string()).await?;
+
+        loop {
+            let line = self.read_line().await?;
+            let mut parts = line.split_whitespace();
+            if parts.next() != Some("bestmove") {
+                continue;
+            }
+
+            return match parts.next() {
+                Some("(none)" | "0000") | None => {
+                    Err(engine_error("the engine did not find a move"))
+                }
+                Some(uci_move) => Ok(uci_move.to_string()),
+            };
+        }
+    }
+
+    /// Choose the best move in a game streamed from Lichess, using the remaining clock times as search limits.
+    /// Chess960 mode is toggled automatically based on the variant of the game.
+    /// The returned move can be passed directly to [`bot().play_move()`](fn@crate::client::BotApi::play_move).
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails or the engine does not find a move.
+    pub async fn best_move_for_game(
+        &mut self,
+        game: &GameFull,
+        state: &GameState,
+    ) -> Result<String> {
+        self.set_chess960(game.variant.key == VariantMode::Chess960)
+            .await?;
+        self.best_move(
+            &game.initial_fen,
+            &state.moves,
+            &GoOptions::from_state(state),
+        )
+        .await
+    }
+
+    /// Analyse a position given as an initial FEN (or `startpos`) and a space-separated list of UCI moves,
+    /// passing every `info` line written by the engine to the callback until the search finishes.
+    /// If the callback returns `false`, the search is stopped early.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails.
+    pub async fn analyse<F>(
+        &mut self,
+        initial_fen: &str,
+        moves: &str,
+        options: &GoOptions,
+        mut on_info: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.send(&position(initial_fen, moves)).await?;
+        self.send(&options.to_string()).await?;
+
+        // Keep reading after stopping the search, the engine always finishes with a best move
+        let mut stopped = false;
+        loop {
+            let line = self.read_line().await?;
+            if line.starts_with("bestmove") {
+                return Ok(());
+            }
+            if !stopped && line.starts_with("info") && !on_info(&line) {
+                self.send("stop").await?;
+                stopped = true;
+            }
+        }
+    }
+
+    /// Ask the engine to quit and wait for the process to exit.
+    ///
+    /// # Errors
+    /// Returns an error if communicating with the engine fails.
+    pub async fn quit(mut self) -> Result<()> {
+        self.send("quit").await?;
+        self.process.wait().await?;
+        Ok(())
+    }
+
+    // Toggle the Chess960 mode of the engine if it differs from the current mode
+    pub(crate) async fn set_chess960(&mut self, chess960: bool) -> Result<()> {
+        if chess960 != self.chess960 {
+            self.set_option("UCI_Chess960", chess960).await?;
+            self.chess960 = chess960;
+        }
+        Ok(())
+    }
+
+    // Send a single command to the engine
+    async fn send(&mut self, command: &str) -> Result<()> {
+        self.stdin.write_all(command.as_bytes()).await?;
+        self.stdin.write_all(b"\n").await?;
+        self.stdin.flush().await?;
+        Ok(())
+    }
+
+    // Read a single line of output from the engine
+    async fn read_line(&mut self) -> Result<String> {
+        self.stdout
+            .next_line()
+            .await?
+            .ok_or_else(|| engine_error("the engine process exited unexpectedly"))
+    }
+}
+
+// Construct the UCI position command, Lichess uses `startpos` for the standard starting position
+fn position(initial_fen: &str, moves: &str) -> String {
+    let mut command = match initial_fen {
+        "" | "startpos" => String::from("position startpos"),
+        fen => format!("position fen {fen}"),
+    };
+
+    if !moves.trim().is_empty() {
+        command.push_str(" moves ");
+        command.push_str(moves.trim());
+    }
+
+    command
+}
+
+fn engine_error(message: &str) -> Error {
+    Error::new(ErrorKind::Engine, message)
+}
@@ -59,3 +59,96 @@ async fn analysis_cloud() {
         result.unwrap()
     );
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn analysis_cloud_batch() {
+    use licheszter::{
+        config::analysis::{CloudBatchOptions, CloudEvalCache, CloudEvalKey, MemoryCloudEvalCache},
+        models::score::Score,
+        testing::{FakeLichess, FakeResponse},
+    };
+    use reqwest::{Method, StatusCode};
+    use std::time::Duration;
+
+    // Set up a server without any cloud evaluations
+    let server = FakeLichess::start().await.unwrap();
+    let not_found = FakeResponse::error(
+        StatusCode::NOT_FOUND,
+        "No cloud evaluation available for that position",
+    );
+    server.route(Method::GET, "/api/cloud-eval", not_found);
+
+    let client = server.builder().build();
+    let cache = MemoryCloudEvalCache::new();
+    let options = CloudBatchOptions::new().multi_pv(2).concurrency(1);
+    let fens = [
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 4 9",
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2",
+    ];
+
+    // Run some test cases
+    let result = client
+        .analysis()
+        .cloud_batch(fens, Some(&options), Some(&cache))
+        .await
+        .unwrap();
+    assert_eq!(result, [None, None, None]);
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(cache.len(), 2);
+
+    let analysis = r#"{"fen":"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -","knodes":1000,"depth":40,"pvs":[{"moves":"c7c5 g1f3","cp":25}]}"#;
+    server.route(
+        Method::GET,
+        "/api/cloud-eval",
+        FakeResponse::new(StatusCode::OK, "application/json", analysis),
+    );
+    let result = client
+        .analysis()
+        .cloud_batch(fens, Some(&options), Some(&cache))
+        .await
+        .unwrap();
+    assert_eq!(result, [None, None, None]);
+    assert_eq!(server.requests().len(), 2);
+
+    let expiring = MemoryCloudEvalCache::with_capacity(1).missing_ttl(Duration::ZERO);
+    expiring.insert(CloudEvalKey::new(fens[0], Some(2), None), None);
+    let result = client
+        .analysis()
+        .cloud_batch(&fens[..2], Some(&options), Some(&expiring))
+        .await
+        .unwrap();
+    assert!(result.iter().all(Option::is_some));
+    assert_eq!(server.requests().len(), 3);
+    assert_eq!(expiring.len(), 1);
+
+    cache.clear();
+    let result = client
+        .analysis()
+        .cloud_batch(&fens[..1], None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        result[0].as_ref().unwrap().pvs[0].score,
+        Score::Centipawns(25)
+    );
+    assert!(cache.is_empty());
+
+    let request = server.requests().pop().unwrap();
+    assert_eq!(
+        request.query.as_deref(),
+        Some("fen=rnbqkbnr%2Fpppppppp%2F8%2F8%2F4P3%2F8%2FPPPP1PPP%2FRNBQKBNR_b_KQkq_-_0_1")
+    );
+
+    let unauthorized = FakeResponse::error(StatusCode::UNAUTHORIZED, "No such token");
+    server.route(Method::GET, "/api/cloud-eval", unauthorized);
+    let result = client.analysis().cloud_batch(fens, None, None).await;
+    assert!(result.is_err(), "Looking up cloud evaluations did not fail");
+
+    let key = CloudEvalKey::new(fens[1], None, Some(VariantMode::Standard));
+    assert_eq!(
+        key.fen,
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -"
+    );
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "broadcasts")]
+
+use std::{error::Error, sync::LazyLock};
+
+use futures_util::StreamExt;
+use licheszter::{
+    client::Licheszter,
+    config::broadcasts::{BroadcastOptions, BroadcastRoundOptions},
+    models::broadcast::{BroadcastFideTimeControl, BroadcastRoundStatus, BroadcastVisibility},
+};
+
+// A game to push to broadcast rounds
+const PGN: &str = r#"[White "Player A"]
+[Black "Player B"]
+[Result "*"]
+
+1. e4 e5 2. Nf3 Nc6 *
+"#;
+
+// Connect to test clients
+static LI: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_li")
+        .unwrap()
+        .build()
+});
+
+static BOT0: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build()
+});
+
+#[tokio::test]
+async fn broadcasts_list() {
+    // Run some test cases
+    let mut result = LI.broadcasts().official(Some(5)).await.unwrap();
+    while let Some(broadcast) = result.next().await {
+        assert!(
+            broadcast.is_ok(),
+            "Failed to get official broadcasts: {:?}",
+            broadcast.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = LI.broadcasts().top(None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get top broadcasts: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.broadcasts().by_user("li", Some(1)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get user broadcasts: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.broadcasts().search("Licheszter", None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to search broadcasts: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn broadcasts_create_update() {
+    // Create options for testing
+    let options = BroadcastOptions::new()
+        .name("Licheszter Open")
+        .format("Swiss")
+        .location("Helsinki")
+        .time_control("90+30")
+        .fide_time_control(BroadcastFideTimeControl::Standard)
+        .time_zone("Europe/Helsinki")
+        .featured_players(&["Player A", "Player B"])
+        .markdown("Created by Licheszter")
+        .visibility(BroadcastVisibility::Unlisted);
+
+    // Run some test cases
+    let result = LI.broadcasts().create(&options).await;
+    assert!(
+        result.is_ok(),
+        "Failed to create broadcast: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let broadcast = result.unwrap();
+    let result = LI.broadcasts().show(&broadcast.tour.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get broadcast: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI
+        .broadcasts()
+        .update(&broadcast.tour.id, &options.show_scores(false))
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to update broadcast: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0
+        .broadcasts()
+        .update(
+            &broadcast.tour.id,
+            &BroadcastOptions::new().name("Licheszter"),
+        )
+        .await;
+    assert!(
+        result.is_err(),
+        "Updating broadcast did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = LI.broadcasts().create(&BroadcastOptions::new()).await;
+    assert!(
+        result.is_err(),
+        "Creating broadcast did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn broadcasts_rounds() {
+    // Create a broadcast for testing
+    let broadcast = LI
+        .broadcasts()
+        .create(&BroadcastOptions::new().name("Licheszter Open"))
+        .await
+        .unwrap();
+
+    // Run some test cases
+    let result = LI
+        .broadcasts()
+        .create_round(
+            &broadcast.tour.id,
+            &BroadcastRoundOptions::new().name("Round 1").rated(false),
+        )
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to create broadcast round: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let round = result.unwrap();
+    let result = LI
+        .broadcasts()
+        .round(&round.tour.slug, &round.round.slug, &round.round.id)
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to get broadcast round: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let options = BroadcastRoundOptions::new()
+        .name("Round 1")
+        .status(BroadcastRoundStatus::Started);
+    let result = LI
+        .broadcasts()
+        .update_round(&round.round.id, &options)
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to update broadcast round: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let mut result = LI.broadcasts().my_rounds(Some(5)).await.unwrap();
+    while let Some(round) = result.next().await {
+        assert!(
+            round.is_ok(),
+            "Failed to get own broadcast rounds: {:?}",
+            round.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = BOT0
+        .broadcasts()
+        .create_round(&broadcast.tour.id, &options)
+        .await;
+    assert!(
+        result.is_err(),
+        "Creating broadcast round did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn broadcasts_push_export() {
+    // Create a broadcast round for testing
+    let broadcast = LI
+        .broadcasts()
+        .create(&BroadcastOptions::new().name("Licheszter Open"))
+        .await
+        .unwrap();
+    let round = LI
+        .broadcasts()
+        .create_round(
+            &broadcast.tour.id,
+            &BroadcastRoundOptions::new().name("Round 1"),
+        )
+        .await
+        .unwrap();
+
+    // Run some test cases
+    let result = LI.broadcasts().push_pgn(&round.round.id, PGN).await;
+    assert!(
+        result.is_ok(),
+        "Failed to push PGN to broadcast round: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+    assert!(result
+        .unwrap()
+        .games
+        .iter()
+        .all(|game| game.error.is_none()));
+
+    let mut result = LI
+        .broadcasts()
+        .export_round_pgn(&round.round.id)
+        .await
+        .unwrap();
+    while let Some(pgn) = result.next().await {
+        assert!(
+            pgn.is_ok(),
+            "Failed to export broadcast round: {:?}",
+            pgn.unwrap_err().source().unwrap()
+        );
+    }
+
+    let mut result = LI
+        .broadcasts()
+        .export_pgn(&broadcast.tour.id)
+        .await
+        .unwrap();
+    while let Some(pgn) = result.next().await {
+        assert!(
+            pgn.is_ok(),
+            "Failed to export broadcast: {:?}",
+            pgn.unwrap_err().source().unwrap()
+        );
+    }
+
+    let mut result = LI
+        .broadcasts()
+        .stream_round_pgn(&round.round.id)
+        .await
+        .unwrap();
+    let pgn = result.next().await.unwrap();
+    assert!(
+        pgn.is_ok(),
+        "Failed to stream broadcast round: {:?}",
+        pgn.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.broadcasts().reset_round(&round.round.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to reset broadcast round: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.broadcasts().push_pgn(&round.round.id, PGN).await;
+    assert!(
+        result.is_err(),
+        "Pushing PGN to broadcast round did not fail: {:?}",
+        result.unwrap()
+    );
+}
@@ -5,11 +5,13 @@ use std::{error::Error, sync::LazyLock};
 use futures_util::StreamExt;
 use licheszter::{
     client::Licheszter,
-    config::challenges::{AIChallengeOptions, ChallengeOptions, OpenChallengeOptions},
+    config::challenges::{
+        AIChallengeOptions, ChallengeOptions, ChallengePolicy, OpenChallengeOptions, OpponentKind,
+    },
     models::{
-        challenge::{ChallengeComplete, ChallengeDeclineReason},
+        challenge::{Challenge, ChallengeComplete, ChallengeDecision, ChallengeDeclineReason},
         common::Color,
-        game::{AILevel, CorrespondenceDays, Rules, VariantMode},
+        game::{AILevel, CorrespondenceDays, Rules, Speed, VariantMode},
     },
 };
 use tokio::time::{sleep, Duration};
@@ -411,3 +413,87 @@ async fn challenge_opponent_clock_increment() {
         result.unwrap()
     );
 }
+
+#[tokio::test]
+async fn challenge_policy() {
+    // Create a challenge for testing
+    let challenge: Challenge = serde_json::from_str(
+        r#"{"id":"chal1","url":"http://localhost/chal1","status":"created","challenger":{"id":"bot1","name":"Bot1","title":"BOT","rating":1500},"destUser":{"id":"bot0","name":"Bot0","title":"BOT","rating":1500},"variant":{"key":"chess960","name":"Chess960"},"rated":true,"speed":"blitz","timeControl":{"type":"clock","limit":300,"increment":0,"show":"5+0"},"color":"random","finalColor":"white","perf":{"name":"Chess960"}}"#,
+    )
+    .unwrap();
+
+    // Run some test cases
+    let cases = [
+        (ChallengePolicy::new(), ChallengeDecision::Accept),
+        (
+            ChallengePolicy::new().blocklist(&["Bot1"]),
+            ChallengeDecision::Decline(ChallengeDeclineReason::Generic),
+        ),
+        (
+            ChallengePolicy::new().max_games(1),
+            ChallengeDecision::Decline(ChallengeDeclineReason::Later),
+        ),
+        (
+            ChallengePolicy::new().opponents(OpponentKind::Humans),
+            ChallengeDecision::Decline(ChallengeDeclineReason::NoBot),
+        ),
+        (
+            ChallengePolicy::new().variants(&[VariantMode::Standard]),
+            ChallengeDecision::Decline(ChallengeDeclineReason::Standard),
+        ),
+        (
+            ChallengePolicy::new().variants(&[VariantMode::Standard, VariantMode::Atomic]),
+            ChallengeDecision::Decline(ChallengeDeclineReason::Variant),
+        ),
+        (
+            ChallengePolicy::new().speeds(Speed::Rapid, Speed::Classical),
+            ChallengeDecision::Decline(ChallengeDeclineReason::TooFast),
+        ),
+        (
+            ChallengePolicy::new().speeds(Speed::Bullet, Speed::Bullet),
+            ChallengeDecision::Decline(ChallengeDeclineReason::TooSlow),
+        ),
+        (
+            ChallengePolicy::new().rated(false),
+            ChallengeDecision::Decline(ChallengeDeclineReason::Casual),
+        ),
+        (
+            ChallengePolicy::new().rating(1600, 2000),
+            ChallengeDecision::Decline(ChallengeDeclineReason::Generic),
+        ),
+        (
+            ChallengePolicy::new()
+                .opponents(OpponentKind::Bots)
+                .variants(&[VariantMode::Chess960])
+                .speeds(Speed::Bullet, Speed::Rapid)
+                .rated(true)
+                .rating(1000, 2000)
+                .max_games(2),
+            ChallengeDecision::Accept,
+        ),
+    ];
+
+    for (policy, expected) in cases {
+        assert_eq!(policy.evaluate(&challenge, 1), expected, "{policy:?}");
+    }
+}
+
+#[tokio::test]
+async fn challenge_apply_policy() {
+    // Create a challenge for testing
+    let challenge = LI.challenges().create("Bot0", None).await.unwrap();
+    let challenge = BOT0.challenges().show(&challenge.id).await.unwrap();
+
+    // Run some test cases
+    let policy = ChallengePolicy::new().opponents(OpponentKind::Bots);
+    let result = BOT0.challenges().apply_policy(&challenge, &policy, 0).await;
+    assert!(
+        result.is_ok(),
+        "Failed to apply challenge policy: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+    assert_eq!(
+        result.unwrap(),
+        ChallengeDecision::Decline(ChallengeDeclineReason::OnlyBot)
+    );
+}
@@ -0,0 +1,776 @@
+#![cfg(feature = "bot")]
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures_util::StreamExt;
+use licheszter::{
+    client::{Licheszter, ResilientItem},
+    config::client::{
+        Middleware, RateLimitEvent, RateLimitOptions, ReconnectOptions, RequestEvent, RetryPolicy,
+    },
+    error::ErrorKind,
+    models::{board::BoardState, game::GameStatus, oauth::Scope},
+};
+
+#[cfg(feature = "games")]
+use licheszter::pgn::PgnGame;
+#[cfg(feature = "testing")]
+use licheszter::testing::{FakeLichess, FakeResponse};
+#[cfg(feature = "testing")]
+use reqwest::Method;
+use reqwest::{header, Request, Response, StatusCode};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    time::Instant,
+};
+
+const RATE_LIMITED: &str = "HTTP/1.1 429 Too Many Requests\r\nContent-Type: application/json\r\nContent-Length: 31\r\nConnection: close\r\n\r\n{\"error\":\"Too many requests.\"}\n";
+const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\nContent-Type: application/json\r\nContent-Length: 31\r\nConnection: close\r\n\r\n{\"error\":\"Service unavailable\"}";
+const CHAT: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]";
+const OK: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 11\r\nConnection: close\r\n\r\n{\"ok\":true}";
+
+const NDJSON: &str =
+    "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n";
+const GAME_FULL: &str = r#"{"type":"gameFull","id":"abcdefgh","rated":false,"variant":{"key":"standard","name":"Standard","short":"Std"},"clock":{"initial":300000,"increment":0},"speed":"blitz","perf":{"name":"Blitz"},"createdAt":1700000000000,"white":{"id":"bot0","name":"Bot0","title":"BOT","rating":1500},"black":{"id":"bot1","name":"Bot1","title":"BOT","rating":1500},"initialFen":"startpos","state":{"type":"gameState","moves":"e2e4","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"started"}}"#;
+const GAME_STATE: &str = r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"resign","winner":"white"}"#;
+
+// Start a local server that answers each connection with the next scripted response
+async fn serve(responses: &[&str]) -> String {
+    let responses: Vec<String> = responses.iter().map(ToString::to_string).collect();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 4096];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn rate_limit_retry() {
+    // Set up a server that rate limits the first request
+    let url = serve(&[RATE_LIMITED, OK]).await;
+    let events = Arc::new(Mutex::new(Vec::<RateLimitEvent>::new()));
+    let hook_events = Arc::clone(&events);
+
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_rate_limit(
+            RateLimitOptions::new()
+                .cooldown(Duration::from_millis(200))
+                .on_rate_limit(move |event| hook_events.lock().unwrap().push(event.clone())),
+        )
+        .build();
+
+    // Run some test cases
+    let start = Instant::now();
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_ok(),
+        "Failed to retry a rate limited request: {:?}",
+        result.unwrap_err()
+    );
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].attempt, 1);
+    assert_eq!(events[0].cooldown, Some(Duration::from_millis(200)));
+    assert_eq!(events[0].url.path(), "/api/bot/game/abcdefgh/move/e2e4");
+}
+
+#[tokio::test]
+async fn rate_limit_exhausted() {
+    // Set up a server that rate limits every request
+    let url = serve(&[RATE_LIMITED, RATE_LIMITED]).await;
+    let events = Arc::new(Mutex::new(Vec::<RateLimitEvent>::new()));
+    let hook_events = Arc::clone(&events);
+
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_rate_limit(
+            RateLimitOptions::new()
+                .cooldown(Duration::from_millis(50))
+                .max_retries(1)
+                .on_rate_limit(move |event| hook_events.lock().unwrap().push(event.clone())),
+        )
+        .build();
+
+    // Run some test cases
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(result.unwrap_err().is_lichess());
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].attempt, 2);
+    assert_eq!(events[1].cooldown, None);
+}
+
+#[tokio::test]
+async fn rate_limit_disabled() {
+    // Without rate limit handling, 429 responses are returned as errors right away
+    let url = serve(&[RATE_LIMITED]).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(result.unwrap_err().is_lichess());
+}
+
+#[tokio::test]
+async fn retry_idempotent() {
+    // Set up a server that fails the first two requests
+    let url = serve(&[UNAVAILABLE, UNAVAILABLE, CHAT]).await;
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_retry_policy(
+            RetryPolicy::new()
+                .initial_backoff(Duration::from_millis(10))
+                .jitter(false),
+        )
+        .build();
+
+    // Run some test cases
+    let result = client.bot().chat_read("abcdefgh").await;
+    assert!(
+        result.is_ok(),
+        "Failed to retry a failed request: {:?}",
+        result.unwrap_err()
+    );
+}
+
+#[tokio::test]
+async fn retry_exhausted() {
+    // Set up a server that fails every request
+    let url = serve(&[UNAVAILABLE, UNAVAILABLE]).await;
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_retry_policy(
+            RetryPolicy::new()
+                .max_attempts(2)
+                .initial_backoff(Duration::from_millis(10)),
+        )
+        .build();
+
+    // Run some test cases
+    let result = client.bot().chat_read("abcdefgh").await;
+    assert!(result.unwrap_err().is_lichess());
+}
+
+#[tokio::test]
+async fn retry_non_idempotent() {
+    // POST requests are not retried by default
+    let url = serve(&[UNAVAILABLE, OK]).await;
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_retry_policy(RetryPolicy::new().initial_backoff(Duration::from_millis(10)))
+        .build();
+
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(result.unwrap_err().is_lichess());
+
+    // Unless explicitly allowed
+    let url = serve(&[UNAVAILABLE, OK]).await;
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_retry_policy(
+            RetryPolicy::new()
+                .initial_backoff(Duration::from_millis(10))
+                .retry_non_idempotent(true),
+        )
+        .build();
+
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_ok(),
+        "Failed to retry a failed request: {:?}",
+        result.unwrap_err()
+    );
+}
+
+#[tokio::test]
+async fn resilient_game_stream() {
+    // Set up a server that drops the connection after the first item and replays the game state afterwards
+    let first = format!("{NDJSON}{GAME_FULL}\n");
+    let second = format!("{NDJSON}{GAME_FULL}\n{GAME_STATE}\n");
+    let url = serve(&[&first, &second]).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let options = ReconnectOptions::new()
+        .initial_backoff(Duration::from_millis(10))
+        .max_attempts(3);
+    let stream = client
+        .bot()
+        .game_connect_resilient("abcdefgh", options)
+        .await
+        .unwrap();
+    let items: Vec<_> = stream.map(Result::unwrap).collect().await;
+
+    assert_eq!(items.len(), 3, "Unexpected stream items: {items:?}");
+    assert!(matches!(
+        &items[0],
+        ResilientItem::Item(BoardState::GameFull(_))
+    ));
+    assert_eq!(items[1], ResilientItem::Reconnected);
+    assert!(matches!(
+        &items[2],
+        ResilientItem::Item(BoardState::GameState(state)) if state.status == GameStatus::Resign
+    ));
+}
+
+#[tokio::test]
+async fn resilient_stream_unavailable() {
+    // Set up a server that is briefly unavailable while the stream is reopened
+    let first = format!("{NDJSON}{GAME_FULL}\n");
+    let second = format!("{NDJSON}{GAME_STATE}\n");
+    let rate_limited =
+        RATE_LIMITED.replace("Connection: close", "Retry-After: 0\r\nConnection: close");
+    let url = serve(&[&first, UNAVAILABLE, &rate_limited, &second]).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let options = ReconnectOptions::new()
+        .initial_backoff(Duration::from_millis(10))
+        .max_attempts(3);
+    let stream = client
+        .bot()
+        .game_connect_resilient("abcdefgh", options)
+        .await
+        .unwrap();
+    let items: Vec<_> = stream.map(Result::unwrap).collect().await;
+
+    assert_eq!(items.len(), 3, "Unexpected stream items: {items:?}");
+    assert_eq!(items[1], ResilientItem::Reconnected);
+    assert!(matches!(
+        &items[2],
+        ResilientItem::Item(BoardState::GameState(_))
+    ));
+}
+
+#[tokio::test]
+async fn resilient_stream_exhausted() {
+    // Set up a server that only accepts a single connection
+    let first = format!("{NDJSON}{GAME_FULL}\n");
+    let url = serve(&[&first]).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let options = ReconnectOptions::new()
+        .initial_backoff(Duration::from_millis(10))
+        .max_attempts(2);
+    let mut stream = client
+        .bot()
+        .game_connect_resilient("abcdefgh", options)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        stream.next().await,
+        Some(Ok(ResilientItem::Item(_)))
+    ));
+    assert!(stream.next().await.unwrap().unwrap_err().is_reqwest());
+    assert!(stream.next().await.is_none());
+}
+
+#[cfg(feature = "games")]
+#[tokio::test]
+async fn pgn_export_stream() {
+    // Set up a server that returns two games in PGN format
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/x-chess-pgn\r\nConnection: close\r\n\r\n{}",
+        "[Event \"Rated Blitz game\"]\n[Site \"https://lichess.org/abcdefgh\"]\n\n1. e4 { [%clk 0:05:00] } 1... e5 { [%clk 0:05:00] } 1-0\n\n\n[Event \"Casual Blitz game\"]\n[Site \"https://lichess.org/ijklmnop\"]\n\n1. d4 d5 *\n\n\n"
+    );
+    let url = serve(&[&response]).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let stream = client
+        .games()
+        .export_pgn(&["abcdefgh", "ijklmnop"], None)
+        .await
+        .unwrap();
+    let games: Vec<String> = stream.map(Result::unwrap).collect().await;
+    assert_eq!(games.len(), 2);
+    assert!(games[0].ends_with("1... e5 { [%clk 0:05:00] } 1-0\n"));
+    assert_eq!(
+        games[1],
+        "[Event \"Casual Blitz game\"]\n[Site \"https://lichess.org/ijklmnop\"]\n\n1. d4 d5 *\n"
+    );
+
+    let game = PgnGame::parse(&games[0]).unwrap();
+    assert_eq!(game.tag("Site"), Some("https://lichess.org/abcdefgh"));
+    assert_eq!(game.moves.len(), 2);
+}
+
+#[tokio::test]
+async fn missing_scope() {
+    // Set up a server that would accept the request
+    let url = serve(&[OK]).await;
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_scopes(&[Scope::BoardPlay])
+        .build();
+
+    // Run some test cases
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_err(),
+        "Playing a move without the required scope did not fail: {:?}",
+        result.unwrap()
+    );
+    assert!(result.unwrap_err().is_missing_scope());
+
+    let client = Licheszter::builder()
+        .with_base_url(serve(&[OK]).await)
+        .unwrap()
+        .with_scopes(&[Scope::BotPlay, Scope::ChallengeRead])
+        .build();
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_ok(),
+        "Failed to play a move with the required scope: {:?}",
+        result.unwrap_err()
+    );
+
+    let client = Licheszter::builder()
+        .with_base_url(serve(&[OK]).await)
+        .unwrap()
+        .with_scopes(&[Scope::ChallengeRead])
+        .build();
+    let result = client.challenges().accept("abcdefgh").await;
+    let error = result.unwrap_err();
+    assert!(error.is_missing_scope());
+    assert!(
+        error
+            .to_string()
+            .contains("challenge:write, bot:play, board:play"),
+        "Not all acceptable scopes were listed: {error}"
+    );
+}
+
+#[tokio::test]
+async fn token_test() {
+    // Set up a server that knows one of the tokens
+    let body = r#"{"lip_bot0":{"scopes":"bot:play,challenge:read","userId":"bot0","expires":null},"lip_li":{"scopes":"","userId":"li","expires":1700000000000},"notvalid":null}"#;
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let url = serve(&[&response]).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let result = client
+        .test_tokens(&["lip_bot0", "lip_li", "notvalid"])
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to test tokens: {:?}",
+        result.unwrap_err()
+    );
+
+    let tokens = result.unwrap();
+    let bot0 = tokens["lip_bot0"].as_ref().unwrap();
+    assert_eq!(bot0.scopes, [Scope::BotPlay, Scope::ChallengeRead]);
+    assert_eq!(bot0.user_id, "bot0");
+    assert!(bot0.expires.is_none());
+
+    let li = tokens["lip_li"].as_ref().unwrap();
+    assert!(li.scopes.is_empty());
+    assert!(li.expires.is_some());
+    assert!(tokens["notvalid"].is_none());
+}
+
+#[tokio::test]
+async fn lichess_errors() {
+    // Set up a server that responds with different kinds of errors
+    let bad_request =
+        r#"{"error":{"clock.limit":["This field is required"],"variant":["Invalid value"]}}"#;
+    let bad_request = format!(
+        "HTTP/1.1 400 Bad Request\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{bad_request}",
+        bad_request.len()
+    );
+    let not_your_turn = r#"{"error":"Not your turn, or game already over"}"#;
+    let not_your_turn = format!(
+        "HTTP/1.1 400 Bad Request\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{not_your_turn}",
+        not_your_turn.len()
+    );
+    let not_found = "HTTP/1.1 404 Not Found\r\nContent-Type: text/html\r\nContent-Length: 13\r\nConnection: close\r\n\r\n<html></html>";
+    let rate_limited = "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 30\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    let url = serve(&[
+        &bad_request,
+        &not_your_turn,
+        not_found,
+        rate_limited,
+        RATE_LIMITED,
+    ])
+    .await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let error = client
+        .bot()
+        .play_move("abcdefgh", "e2e4", false)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::BadRequest);
+    assert_eq!(error.status(), Some(StatusCode::BAD_REQUEST));
+    let details = error.lichess().unwrap();
+    assert_eq!(
+        details.field_errors()["clock.limit"],
+        ["This field is required"]
+    );
+    assert_eq!(
+        details.message(),
+        "clock.limit: This field is required; variant: Invalid value"
+    );
+    assert!(error.payload().unwrap()["error"].is_object());
+
+    let error = client
+        .bot()
+        .play_move("abcdefgh", "e2e4", false)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::BadRequest);
+    assert_eq!(
+        error.lichess().unwrap().message(),
+        "Not your turn, or game already over"
+    );
+    assert!(error.lichess().unwrap().field_errors().is_empty());
+
+    let error = client
+        .bot()
+        .play_move("abcdefgh", "e2e4", false)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert!(error.is_lichess());
+    assert!(error.payload().is_none());
+    assert_eq!(error.lichess().unwrap().message(), "Not found");
+
+    let error = client
+        .bot()
+        .play_move("abcdefgh", "e2e4", false)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RateLimited);
+    assert_eq!(
+        error.lichess().unwrap().retry_after(),
+        Some(Duration::from_secs(30))
+    );
+
+    let error = client
+        .bot()
+        .play_move("abcdefgh", "e2e4", false)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.lichess().unwrap().retry_after(),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(error.lichess().unwrap().message(), "Too many requests.");
+
+    let error = Licheszter::builder()
+        .with_authentication("lip_\n")
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidAuthToken);
+    assert!(error.status().is_none());
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn fake_lichess_errors() {
+    // Set up a fake server that rejects a move with form errors and rate limits the chat
+    let server = FakeLichess::start().await.unwrap();
+    server.route(
+        Method::POST,
+        "/api/bot/game/*/move/*",
+        FakeResponse::new(
+            StatusCode::BAD_REQUEST,
+            "application/json",
+            r#"{"error":{"field":["msg"]}}"#,
+        ),
+    );
+    server.route(
+        Method::GET,
+        "/api/bot/game/*/chat",
+        FakeResponse::error(StatusCode::TOO_MANY_REQUESTS, "Too many requests."),
+    );
+    let client = server.builder().build();
+
+    // Run some test cases
+    let error = client
+        .bot()
+        .play_move("abcdefgh", "e2e4", false)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::BadRequest);
+    let details = error.lichess().unwrap();
+    assert_eq!(details.field_errors()["field"], ["msg"]);
+    assert_eq!(details.message(), "field: msg");
+    assert!(details.retry_after().is_none());
+
+    let error = client.bot().chat_read("abcdefgh").await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RateLimited);
+    assert_eq!(
+        error.lichess().unwrap().retry_after(),
+        Some(Duration::from_secs(60))
+    );
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn fake_lichess_headers() {
+    // Set up a fake server that records the headers of the requests
+    let server = FakeLichess::start().await.unwrap();
+    let url = server.url().join("api/bot/game/abcdefgh/chat").unwrap();
+    let client = server
+        .builder()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build();
+
+    // Run some test cases
+    let result = client.client().get(url.clone()).send().await;
+    assert!(
+        result.is_ok(),
+        "Failed to send a request with the raw client"
+    );
+
+    let custom = server
+        .builder()
+        .with_http_client(reqwest::Client::new())
+        .with_authentication("lip_bot1")
+        .unwrap()
+        .build();
+    let result = custom.bot().chat_read("abcdefgh").await;
+    assert!(
+        result.is_ok(),
+        "Failed to send a request with a custom client: {:?}",
+        result.unwrap_err()
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("Authorization"), Some("Bearer lip_bot0"));
+    assert_eq!(requests[1].header("Authorization"), Some("Bearer lip_bot1"));
+    let user_agent = requests[1].header("User-Agent").unwrap();
+    assert!(
+        user_agent.starts_with("licheszter/"),
+        "Unexpected user agent: {user_agent}"
+    );
+}
+
+// A middleware that records everything it sees and tags the requests with a custom header
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Middleware for Recorder {
+    fn on_request(&self, request: &mut Request) {
+        let auth = request.headers().contains_key(header::AUTHORIZATION);
+        request
+            .headers_mut()
+            .insert("x-licheszter", header::HeaderValue::from_static("test"));
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("request {} auth={auth}", request.url().path()));
+    }
+
+    fn on_response(&self, _event: &RequestEvent, response: &Response) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("response {}", response.status().as_u16()));
+    }
+
+    fn on_error(&self, event: &RequestEvent, _error: &reqwest::Error) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("error {}", event.method));
+    }
+}
+
+#[tokio::test]
+async fn middleware() {
+    // Set up a server that rate limits the first request
+    let url = serve(&[RATE_LIMITED, OK]).await;
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .with_rate_limit(RateLimitOptions::new().cooldown(Duration::from_millis(10)))
+        .with_middleware(Recorder(Arc::clone(&events)))
+        .build();
+
+    // Run some test cases
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_ok(),
+        "Failed to send a request through middleware: {:?}",
+        result.unwrap_err()
+    );
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "request /api/bot/game/abcdefgh/move/e2e4 auth=true",
+            "response 429",
+            "request /api/bot/game/abcdefgh/move/e2e4 auth=true",
+            "response 200",
+        ]
+    );
+
+    let auth = client.auth_header().unwrap();
+    assert_eq!(auth.to_str().unwrap(), "Bearer lip_bot0");
+    assert!(auth.is_sensitive());
+
+    // Set up a client that cannot connect anywhere
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    events.lock().unwrap().clear();
+    let client = Licheszter::builder()
+        .with_base_url(url)
+        .unwrap()
+        .with_http_client(reqwest::Client::new())
+        .with_middleware(Recorder(Arc::clone(&events)))
+        .build();
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_err(),
+        "Sending a request to a closed port did not fail: {:?}",
+        result.unwrap()
+    );
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "request /api/bot/game/abcdefgh/move/e2e4 auth=false",
+            "error POST"
+        ]
+    );
+}
+
+#[cfg(feature = "tablebase")]
+struct Revalidations(Arc<Mutex<Vec<Option<String>>>>);
+
+#[cfg(feature = "tablebase")]
+impl Middleware for Revalidations {
+    fn on_request(&self, request: &mut Request) {
+        let etag = request
+            .headers()
+            .get(header::IF_NONE_MATCH)
+            .map(|etag| etag.to_str().unwrap().to_string());
+        self.0.lock().unwrap().push(etag);
+    }
+}
+
+#[cfg(feature = "tablebase")]
+#[tokio::test]
+async fn response_cache() {
+    use licheszter::config::client::{CacheOptions, MemoryCache};
+
+    // Set up a server that asks for revalidation, confirms the response is unchanged and then forbids caching
+    let body = r#"{"checkmate":false,"stalemate":false,"variant_win":false,"variant_loss":false,"insufficient_material":false,"category":"win","moves":[]}"#;
+    let response = |cache_control: &str| {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nETag: \"v1\"\r\nCache-Control: {cache_control}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    };
+    let not_modified =
+        "HTTP/1.1 304 Not Modified\r\nCache-Control: max-age=3600\r\nConnection: close\r\n\r\n";
+    let first = response("max-age=0");
+    let last = response("no-store");
+    let url = serve(&[&first, not_modified, &last]).await;
+
+    let revalidations = Arc::new(Mutex::new(Vec::new()));
+    let client = Licheszter::builder()
+        .with_tablebase_url(&url)
+        .unwrap()
+        .with_base_url(&url)
+        .unwrap()
+        .with_cache(CacheOptions::new().backend(MemoryCache::new(1)))
+        .with_middleware(Revalidations(Arc::clone(&revalidations)))
+        .build();
+
+    // Run some test cases
+    let fen = "4k3/6KP/8/8/8/8/7p/8 w - - 0 1";
+    let expected = client.tablebase().standard(fen).await.unwrap();
+    for _ in 0..3 {
+        let result = client.tablebase().standard(fen).await;
+        assert_eq!(result.unwrap(), expected);
+    }
+    assert_eq!(
+        *revalidations.lock().unwrap(),
+        [None, Some(String::from("\"v1\""))]
+    );
+
+    let other = "8/8/8/8/8/8/4k1K1/7Q w - - 0 1";
+    let result = client.tablebase().atomic(other).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get a response that is not cached: {:?}",
+        result.unwrap_err()
+    );
+
+    // The uncached response did not evict the cached one, which is still fresh
+    let result = client.tablebase().standard(fen).await;
+    assert_eq!(result.unwrap(), expected);
+
+    let result = client.tablebase().atomic(other).await;
+    assert!(result.is_err(), "A response with no-store was cached");
+}
+
+#[test]
+fn memory_cache_eviction() {
+    use licheszter::config::client::{CacheBackend, CachedResponse, MemoryCache};
+    use std::time::SystemTime;
+
+    let response = |body: &str| CachedResponse {
+        body: body.as_bytes().to_vec(),
+        etag: None,
+        expires: SystemTime::now() + Duration::from_secs(60),
+    };
+
+    // Run some test cases
+    let cache = MemoryCache::new(2);
+    cache.insert("a", response("a"));
+    cache.insert("b", response("b"));
+    assert!(cache.get("a").is_some_and(|response| response.is_fresh()));
+
+    cache.insert("c", response("c"));
+    assert_eq!(cache.len(), 2);
+    assert!(
+        cache.get("b").is_none(),
+        "The least recently used response was not evicted"
+    );
+    assert_eq!(cache.get("a").unwrap().body, b"a");
+
+    cache.insert("a", response("d"));
+    assert_eq!(cache.get("a").unwrap().body, b"d");
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,250 @@
+#![cfg(feature = "external_engine")]
+
+use std::{error::Error, sync::LazyLock};
+
+use licheszter::{
+    client::Licheszter, config::external_engine::ExternalEngineOptions,
+    models::external_engine::EngineVariant,
+};
+
+// Connect to test clients
+static LI: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_li")
+        .unwrap()
+        .build()
+});
+
+#[tokio::test]
+async fn external_engine_manage() {
+    // Create options for testing
+    let options = ExternalEngineOptions::new("Licheszter", "licheszter-provider-secret")
+        .max_threads(4)
+        .max_hash(512)
+        .variants(&[EngineVariant::Chess, EngineVariant::Atomic])
+        .provider_data("local");
+
+    // Run some test cases
+    let result = LI.external_engine().create(&options).await;
+    assert!(
+        result.is_ok(),
+        "Failed to register external engine: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let engine = result.unwrap();
+    let result = LI.external_engine().list().await;
+    assert!(
+        result.is_ok(),
+        "Failed to list external engines: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI
+        .external_engine()
+        .update(&engine.id, &options.max_threads(8))
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to update external engine: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.external_engine().show(&engine.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get external engine: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.external_engine().delete(&engine.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to delete external engine: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.external_engine().show("notvalid").await;
+    assert!(result.is_err(), "Getting external engine did not fail");
+}
+
+#[cfg(all(feature = "testing", unix))]
+mod provider {
+    use std::{env, fs, os::unix::fs::PermissionsExt, path::PathBuf, time::Duration};
+
+    use futures_util::StreamExt;
+    use licheszter::{
+        models::{external_engine::EngineWork, score::Score},
+        testing::{FakeLichess, FakeResponse},
+        uci::UciEngine,
+    };
+    use reqwest::{Method, StatusCode};
+
+    // A fake engine that reports the position and the search limits it receives as info lines
+    const FAKE_ENGINE: &str = r#"#!/bin/sh
+while read -r line; do
+    case "$line" in
+        uci) echo "id name Fake Engine"; echo "uciok" ;;
+        isready) echo "readyok" ;;
+        position*) position="$line" ;;
+        go*)
+            echo "info string $position"
+            echo "info string $line"
+            echo "info depth 1 multipv 1 score cp 20 nodes 20 time 1 pv e7e5"
+            echo "bestmove e7e5" ;;
+        quit) exit 0 ;;
+    esac
+done
+"#;
+
+    const JOB: &str = r#"{"id":"work0001","work":{"sessionId":"session1","threads":2,"hash":32,"multiPv":1,"variant":"chess","initialFen":"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1","moves":["e2e4"],"depth":10},"engine":{"id":"eei_0001","name":"Fake Engine","clientSecret":"ees_0001","userId":"li","maxThreads":4,"maxHash":512,"variants":["chess"],"providerData":"local"}}"#;
+    const ANALYSIS: &str =
+        r#"{"time":1,"depth":1,"nodes":20,"pvs":[{"depth":1,"cp":20,"moves":["e7e5"]}]}"#;
+
+    // Write the fake engine script into a temporary file
+    fn fake_engine(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!(
+            "licheszter-fake-provider-{name}-{}",
+            std::process::id()
+        ));
+        fs::write(&path, FAKE_ENGINE).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn external_engine_provider() {
+        // Set up a server without any work available
+        let server = FakeLichess::start().await.unwrap();
+        let empty = FakeResponse::new(StatusCode::NO_CONTENT, "text/plain", "");
+        server.route(Method::POST, "/api/external-engine/work", empty.clone());
+        server.route(Method::POST, "/api/external-engine/work/*", empty);
+
+        let client = server.builder().build();
+        let path = fake_engine("work");
+        let mut engine = UciEngine::spawn(&path).await.unwrap();
+
+        // Run some test cases
+        let result = client.external_engine().acquire_work("secret").await;
+        assert!(
+            matches!(result, Ok(None)),
+            "Acquiring work did not time out: {result:?}"
+        );
+
+        let job = FakeResponse::new(StatusCode::OK, "application/json", JOB);
+        server.route(Method::POST, "/api/external-engine/work", job);
+        let job = client
+            .external_engine()
+            .acquire_work("secret")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.work.moves, ["e2e4"]);
+
+        let result = client
+            .external_engine()
+            .submit_work(&job, &mut engine)
+            .await;
+        assert!(
+            result.is_ok(),
+            "Failed to submit work: {:?}",
+            result.unwrap_err()
+        );
+
+        let requests = server.requests();
+        let acquire = &requests[1];
+        assert_eq!(acquire.body, r#"{"providerSecret":"secret"}"#);
+
+        let submit = requests.last().unwrap();
+        assert_eq!(submit.path, "/api/external-engine/work/work0001");
+        assert_eq!(
+            submit.body,
+            "info string position fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 moves e2e4\n\
+             info string go depth 10\n\
+             info depth 1 multipv 1 score cp 20 nodes 20 time 1 pv e7e5\n"
+        );
+
+        engine.quit().await.unwrap();
+        fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn external_engine_analyse() {
+        // Set up a server with an analysis of two lines
+        let server = FakeLichess::start().await.unwrap();
+        let body = format!("{ANALYSIS}\n{ANALYSIS}\n");
+        server.route(
+            Method::POST,
+            "/api/external-engine/*/analyse",
+            FakeResponse::new(StatusCode::OK, "application/x-ndjson", &body),
+        );
+
+        let client = server.builder().build();
+        let work: EngineWork = serde_json::from_str(
+            r#"{"sessionId":"session1","threads":1,"hash":16,"multiPv":1,"variant":"chess","initialFen":"startpos","moves":[],"infinite":true}"#,
+        )
+        .unwrap();
+
+        // Run some test cases
+        let mut stream = client
+            .external_engine()
+            .analyse("eei_0001", "ees_0001", &work)
+            .await
+            .unwrap();
+        let mut count = 0;
+        while let Some(analysis) = stream.next().await {
+            let analysis = analysis.unwrap();
+            assert_eq!(analysis.pvs[0].score, Score::Centipawns(20));
+            count += 1;
+        }
+        assert_eq!(count, 2);
+
+        let request = server.requests().remove(0);
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["clientSecret"], "ees_0001");
+        assert_eq!(body["work"]["infinite"], true);
+    }
+
+    #[tokio::test]
+    async fn external_engine_provider_errors() {
+        // Set up a server that is temporarily unavailable
+        let server = FakeLichess::start().await.unwrap();
+        server.route(
+            Method::POST,
+            "/api/external-engine/work",
+            FakeResponse::error(StatusCode::SERVICE_UNAVAILABLE, "Service unavailable"),
+        );
+
+        let client = server.builder().build();
+        let path = fake_engine("errors");
+        let mut engine = UciEngine::spawn(&path).await.unwrap();
+
+        // Run some test cases
+        let result = tokio::time::timeout(
+            Duration::from_millis(1500),
+            client.external_engine().run_provider("secret", &mut engine),
+        )
+        .await;
+        assert!(
+            result.is_err(),
+            "The provider stopped after a server error: {result:?}"
+        );
+        assert!(server.requests().len() >= 2);
+
+        server.route(
+            Method::POST,
+            "/api/external-engine/work",
+            FakeResponse::error(StatusCode::UNAUTHORIZED, "Invalid provider secret"),
+        );
+        let result = client
+            .external_engine()
+            .run_provider("secret", &mut engine)
+            .await;
+        assert_eq!(result.unwrap_err().status(), Some(StatusCode::UNAUTHORIZED));
+
+        engine.quit().await.unwrap();
+        fs::remove_file(path).unwrap();
+    }
+}
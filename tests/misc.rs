@@ -1,7 +1,7 @@
 use std::{error::Error, panic, sync::LazyLock};
 
 use futures_util::StreamExt;
-use licheszter::client::Licheszter;
+use licheszter::{client::Licheszter, models::oauth::Scope};
 use tokio::time::{sleep, Duration};
 
 // Connect to test clients
@@ -76,3 +76,20 @@ async fn bots_online() {
     let result = LI.bots_online(0).await;
     assert!(result.is_ok(), "Failed to get 0 online bots");
 }
+
+#[tokio::test]
+async fn test_tokens() {
+    // Run some test cases
+    let result = LI.test_tokens(&["lip_bot0", "notvalid"]).await;
+    assert!(
+        result.is_ok(),
+        "Failed to test tokens: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let tokens = result.unwrap();
+    let bot0 = tokens["lip_bot0"].as_ref().unwrap();
+    assert_eq!(bot0.user_id, "bot0");
+    assert!(bot0.scopes.contains(&Scope::BotPlay));
+    assert!(tokens["notvalid"].is_none());
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "oauth")]
+
+use licheszter::{models::oauth::Scope, oauth::OAuthClient};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::mpsc::{self, UnboundedReceiver},
+};
+
+const TOKEN: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 77\r\nConnection: close\r\n\r\n{\"token_type\":\"Bearer\",\"access_token\":\"lio_licheszter\",\"expires_in\":31536000}";
+const INVALID_GRANT: &str = "HTTP/1.1 400 Bad Request\r\nContent-Type: application/json\r\nContent-Length: 25\r\nConnection: close\r\n\r\n{\"error\":\"invalid_grant\"}";
+const NO_CONTENT: &str = "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n";
+
+// Start a local token endpoint that answers each connection with the next scripted response
+// and passes the received requests back to the test
+async fn serve(responses: &[&str]) -> (String, UnboundedReceiver<String>) {
+    let responses: Vec<String> = responses.iter().map(ToString::to_string).collect();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            tx.send(String::from_utf8_lossy(&buf[..n]).to_string())
+                .unwrap();
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+    });
+
+    (url, rx)
+}
+
+#[test]
+fn oauth_authorize() {
+    // Create a client for testing
+    let client = OAuthClient::new("licheszter", "http://localhost:3000/callback");
+
+    // Run some test cases
+    let request = client
+        .authorize(&[Scope::ChallengeWrite, Scope::BoardPlay])
+        .unwrap();
+    let query = request.url().query_pairs().into_owned().collect::<Vec<_>>();
+    assert_eq!(request.url().path(), "/oauth");
+    assert!(query.contains(&("response_type".into(), "code".into())));
+    assert!(query.contains(&("client_id".into(), "licheszter".into())));
+    assert!(query.contains(&(
+        "redirect_uri".into(),
+        "http://localhost:3000/callback".into()
+    )));
+    assert!(query.contains(&("code_challenge_method".into(), "S256".into())));
+    assert!(query.contains(&("scope".into(), "challenge:write board:play".into())));
+    assert!(query.contains(&("state".into(), request.state().into())));
+    assert_eq!(request.code_verifier().len(), 43);
+
+    let challenge = query
+        .iter()
+        .find(|(key, _)| key == "code_challenge")
+        .map(|(_, value)| value.clone())
+        .unwrap();
+    assert_eq!(challenge.len(), 43);
+    assert_ne!(challenge, request.code_verifier());
+
+    let other = client.authorize(&[]).unwrap();
+    assert_ne!(other.state(), request.state());
+    assert_ne!(other.code_verifier(), request.code_verifier());
+}
+
+#[tokio::test]
+async fn oauth_exchange_code() {
+    // Set up a token endpoint that rejects the second code
+    let (url, mut requests) = serve(&[TOKEN, INVALID_GRANT]).await;
+    let client = OAuthClient::new("licheszter", "http://localhost:3000/callback")
+        .with_base_url(&url)
+        .unwrap();
+
+    // Run some test cases
+    let result = client.exchange_code("abcdef", "verifier").await;
+    assert!(
+        result.is_ok(),
+        "Failed to exchange authorization code: {:?}",
+        result.unwrap_err()
+    );
+
+    let token = result.unwrap();
+    assert_eq!(token.access_token, "lio_licheszter");
+    assert_eq!(token.expires_in, Some(31_536_000));
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/token "));
+    assert!(request.contains("grant_type=authorization_code"));
+    assert!(request.contains("code=abcdef"));
+    assert!(request.contains("code_verifier=verifier"));
+    assert!(request.contains("client_id=licheszter"));
+
+    let result = client.builder(&token);
+    assert!(
+        result.is_ok(),
+        "Failed to create an authenticated builder: {:?}",
+        result.unwrap_err()
+    );
+
+    let result = client.exchange_code("notvalid", "verifier").await;
+    assert!(
+        result.is_err(),
+        "Exchanging authorization code did not fail: {:?}",
+        result.unwrap()
+    );
+    assert!(result.unwrap_err().is_lichess());
+}
+
+#[tokio::test]
+async fn oauth_revoke() {
+    // Set up a token endpoint that accepts the revocation
+    let (url, mut requests) = serve(&[NO_CONTENT]).await;
+    let client = OAuthClient::new("licheszter", "http://localhost:3000/callback")
+        .with_base_url(&url)
+        .unwrap();
+
+    // Run some test cases
+    let result = client.revoke("lio_licheszter").await;
+    assert!(
+        result.is_ok(),
+        "Failed to revoke access token: {:?}",
+        result.unwrap_err()
+    );
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("DELETE /api/token "));
+    assert!(request
+        .to_lowercase()
+        .contains("authorization: bearer lio_licheszter"));
+}
+
+#[test]
+fn oauth_scopes() {
+    // Run some test cases
+    for scope in Scope::ALL {
+        let json = serde_json::to_string(&scope).unwrap();
+        assert_eq!(json, format!("\"{scope}\""));
+        assert_eq!(serde_json::from_str::<Scope>(&json).unwrap(), scope);
+        assert_eq!(scope.as_str().parse::<Scope>().unwrap(), scope);
+    }
+
+    assert_eq!(Scope::ChallengeWrite.to_string(), "challenge:write");
+    assert!("challenge:delete".parse::<Scope>().is_err());
+}
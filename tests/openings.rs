@@ -182,3 +182,85 @@ async fn openings_masters_otb_game() {
         result.unwrap()
     );
 }
+
+#[cfg(feature = "testing")]
+mod crawl {
+    use licheszter::{
+        config::openings::{CrawlOptions, ExplorerSource, MastersOpeningsOptions},
+        error::ErrorKind,
+        models::openings::OpeningTree,
+        testing::{FakeLichess, FakeResponse},
+    };
+    use reqwest::{Method, StatusCode};
+
+    // Every position of the fake explorer has the same three moves, the last one rarely played
+    const POSITION: &str = r#"{"white":400,"black":300,"draws":300,"moves":[{"uci":"d2d4","san":"d4","averageRating":2400,"white":100,"black":100,"draws":100},{"uci":"e2e4","san":"e4","averageRating":2400,"white":250,"black":150,"draws":200,"opening":{"eco":"B00","name":"King's Pawn"}},{"uci":"b2b3","san":"b3","averageRating":2400,"white":5,"black":3,"draws":2}],"topGames":[]}"#;
+
+    #[tokio::test]
+    async fn openings_crawl() {
+        // Set up a server returning the same position for every request
+        let server = FakeLichess::start().await.unwrap();
+        server.route(
+            Method::GET,
+            "/masters",
+            FakeResponse::new(StatusCode::OK, "application/json", POSITION),
+        );
+
+        let client = server.builder().build();
+        let source = ExplorerSource::Masters(MastersOpeningsOptions::new());
+        let options = CrawlOptions::new().max_depth(2).min_frequency(0.05);
+
+        // Run some test cases
+        let tree = client.openings().crawl(&source, &options).await.unwrap();
+        assert_eq!(tree.games(), 1000);
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.children[0].stats.san, "e4");
+        assert_eq!(tree.children[1].stats.san, "d4");
+        assert_eq!(tree.children[0].children.len(), 2);
+        assert!(tree.children[0].children[0].children.is_empty());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(!requests[0]
+            .query
+            .as_deref()
+            .unwrap_or_default()
+            .contains("play"));
+        assert_eq!(requests[1].query.as_deref(), Some("play=e2e4"));
+        assert_eq!(requests[2].query.as_deref(), Some("play=d2d4"));
+
+        let pgn = tree.to_pgn().to_string();
+        assert!(pgn.starts_with(
+            "{ 1000 games: 40% white, 30% draws, 30% black } 1. e4 { B00 King's Pawn, 600 games: 42% white, 33% draws, 25% black }"
+        ));
+        assert!(pgn.contains("(1. d4 { 300 games: 33% white, 33% draws, 33% black } 1... e4"));
+        assert!(pgn.trim_end().ends_with('*'));
+
+        let json = tree.to_json().unwrap();
+        assert!(json.contains(r#"{"stats":{"uci":"e2e4","san":"e4","#));
+        assert_eq!(serde_json::from_str::<OpeningTree>(&json).unwrap(), tree);
+
+        let tree = client
+            .openings()
+            .crawl(&source, &CrawlOptions::new().max_depth(3).max_moves(1))
+            .await
+            .unwrap();
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.to_pgn().moves.len(), 3);
+
+        let tree = client
+            .openings()
+            .crawl(&source, &CrawlOptions::new().max_depth(0))
+            .await
+            .unwrap();
+        assert!(tree.is_empty());
+
+        server.route(
+            Method::GET,
+            "/masters",
+            FakeResponse::error(StatusCode::TOO_MANY_REQUESTS, "Too many requests"),
+        );
+        let result = client.openings().crawl(&source, &CrawlOptions::new()).await;
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::RateLimited);
+    }
+}
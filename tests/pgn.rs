@@ -0,0 +1,124 @@
+#![cfg(feature = "pgn")]
+
+use std::time::Duration;
+
+use licheszter::{
+    models::game::Game,
+    pgn::{self, PgnEval, PgnGame, PgnReader},
+};
+
+const PGN: &str = r#"[Event "Rated Blitz game"]
+[Site "https://lichess.org/abcd1234"]
+[White "Bot0"]
+[Black "Bot \"One\""]
+[Result "1-0"]
+
+{ A short game } 1. e4 { [%eval 0.36] [%clk 0:03:00] } 1... e5 { [%eval 0.2] [%clk 0:02:59.5] } 2. Qh5?! { Inaccuracy. [%eval -0.3] [%clk 0:02:58] } ( 2. Nf3 Nc6 $1 ) 2... Nc6 3. Bc4 Nf6?? { [%eval #1] } 4. Qxf7# 1-0
+
+
+[Event "Casual Blitz game"]
+[Result "*"]
+
+1.e4 c5 *
+"#;
+
+const GAME: &str = r#"{"id":"abcd1234","rated":true,"variant":"standard","speed":"blitz","perf":"blitz","createdAt":1700000000000,"lastMoveAt":1700000100000,"status":"mate","players":{"white":{"user":{"name":"Bot0","title":"BOT","id":"bot0"},"rating":1500,"ratingDiff":7},"black":{"user":{"name":"Bot1","id":"bot1"},"rating":1490,"ratingDiff":-7}},"winner":"white","opening":{"eco":"C20","name":"King's Pawn Game","ply":2},"moves":"e4 e5 Qh5 Nc6","clock":{"initial":180,"increment":0,"totalTime":180},"clocks":[18003,18003,17800,17500],"analysis":[{"eval":36},{"eval":20},{"eval":-30},{"mate":1}]}"#;
+
+#[test]
+fn pgn_parse() {
+    let games = pgn::parse_all(PGN).unwrap();
+    assert_eq!(games.len(), 2);
+    let game = &games[0];
+
+    // Run some test cases
+    assert_eq!(game.tag("Black"), Some("Bot \"One\""));
+    assert_eq!(game.tag("Opening"), None);
+    assert_eq!(game.comment.as_deref(), Some("A short game"));
+    assert_eq!(game.result.as_deref(), Some("1-0"));
+
+    let sans: Vec<&str> = game.moves.iter().map(|m| m.san.as_str()).collect();
+    assert_eq!(sans, ["e4", "e5", "Qh5", "Nc6", "Bc4", "Nf6", "Qxf7#"]);
+    assert_eq!(game.moves[0].eval, Some(PgnEval::Centipawns(36)));
+    assert_eq!(game.moves[1].clock, Some(Duration::from_millis(179500)));
+    assert_eq!(game.moves[2].nags, [6]);
+    assert_eq!(game.moves[2].comment.as_deref(), Some("Inaccuracy."));
+    assert_eq!(game.moves[2].eval, Some(PgnEval::Centipawns(-30)));
+    assert_eq!(game.moves[2].variations.len(), 1);
+    assert_eq!(game.moves[2].variations[0][1].nags, [1]);
+    assert_eq!(game.moves[5].eval, Some(PgnEval::Mate(1)));
+
+    let game = &games[1];
+    assert_eq!(game.moves.len(), 2);
+    assert_eq!(game.result.as_deref(), Some("*"));
+
+    let result = PgnGame::parse("1. e4 { unterminated");
+    assert!(result.unwrap_err().is_pgn());
+    let result = PgnGame::parse("1. e4 ( e5");
+    assert!(result.unwrap_err().is_pgn());
+}
+
+#[test]
+fn pgn_reader() {
+    let mut reader = PgnReader::new();
+
+    // Feed the PGN in small chunks to split the games at arbitrary places
+    let mut games = Vec::new();
+    for chunk in PGN.as_bytes().chunks(7) {
+        reader.push(std::str::from_utf8(chunk).unwrap());
+        games.extend(std::iter::from_fn(|| reader.next_pgn()));
+    }
+    assert_eq!(games.len(), 1);
+    reader.finish();
+    games.extend(std::iter::from_fn(|| reader.next_pgn()));
+
+    // Run some test cases
+    assert_eq!(games.len(), 2);
+    assert!(games[0].starts_with("[Event \"Rated Blitz game\"]"));
+    assert!(games[0].ends_with("4. Qxf7# 1-0\n"));
+    assert_eq!(
+        games[1],
+        "[Event \"Casual Blitz game\"]\n[Result \"*\"]\n\n1.e4 c5 *\n"
+    );
+}
+
+#[test]
+fn pgn_write() {
+    let game = &pgn::parse_all(PGN).unwrap()[0];
+    let written = game.to_string();
+
+    // Run some test cases
+    assert!(written.contains("[Black \"Bot \\\"One\\\"\"]"));
+    assert!(written.contains(
+        "2. Qh5 $6 { [%eval -0.30] [%clk 0:02:58] Inaccuracy. } (2. Nf3 Nc6 $1) 2... Nc6"
+    ));
+    assert_eq!(&PgnGame::parse(&written).unwrap(), game);
+
+    // Comments at the start of a variation belong to its first move
+    let game = PgnGame::parse("1. e4 ( { The Queen's Pawn } 1. d4 d5 ) 1... e5 *").unwrap();
+    assert_eq!(
+        game.moves[0].variations[0][0].starting_comment.as_deref(),
+        Some("The Queen's Pawn")
+    );
+    let written = game.to_string();
+    assert_eq!(written, "1. e4 ({ The Queen's Pawn } 1. d4 d5) 1... e5 *\n");
+    assert_eq!(PgnGame::parse(&written).unwrap(), game);
+
+    let game: Game = serde_json::from_str(GAME).unwrap();
+    let written = PgnGame::from(&game).to_string();
+    assert!(written.starts_with("[Event \"Rated Blitz game\"]\n[Site \"https://lichess.org/abcd1234\"]\n[Date \"2023.11.14\"]\n"));
+    assert!(written.contains("[WhiteRatingDiff \"+7\"]\n[WhiteTitle \"BOT\"]\n"));
+    assert!(written.contains("[TimeControl \"180+0\"]\n[ECO \"C20\"]\n"));
+    assert!(written.ends_with("\n\n1. e4 { [%eval 0.36] [%clk 0:03:00] } 1... e5 { [%eval 0.20] [%clk 0:03:00] } 2. Qh5 { [%eval -0.30] [%clk 0:02:58] } 2... Nc6 { [%eval #1] [%clk 0:02:55] } 1-0\n"));
+}
+
+#[cfg(feature = "position")]
+#[test]
+fn pgn_uci_moves() {
+    let game = &pgn::parse_all(PGN).unwrap()[0];
+
+    // Run some test cases
+    assert_eq!(
+        game.uci_moves().unwrap(),
+        ["e2e4", "e7e5", "d1h5", "b8c6", "f1c4", "g8f6", "h5f7"]
+    );
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "position")]
+
+use licheszter::{
+    models::{common::Color, game::VariantMode},
+    position::{Position, Role, Square},
+};
+
+// Count the leaf nodes of the move tree up to the given depth
+fn perft(position: &Position, depth: u8) -> usize {
+    if depth == 0 {
+        return 1;
+    }
+
+    position
+        .legal_moves()
+        .iter()
+        .map(|m| {
+            let mut after = position.clone();
+            after.play(m).unwrap();
+            perft(&after, depth - 1)
+        })
+        .sum()
+}
+
+#[test]
+fn position_perft() {
+    // Run some test cases
+    assert_eq!(perft(&Position::new(), 3), 8902);
+
+    let kiwipete =
+        Position::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    assert_eq!(perft(&kiwipete, 2), 2039);
+
+    let en_passant = Position::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+    assert_eq!(perft(&en_passant, 3), 2812);
+
+    let promotions =
+        Position::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1")
+            .unwrap();
+    assert_eq!(perft(&promotions, 2), 264);
+
+    let chess960 =
+        Position::from_fen("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9")
+            .unwrap();
+    assert!(chess960.is_chess960());
+    assert_eq!(perft(&chess960, 3), 12189);
+}
+
+#[test]
+fn position_fen() {
+    let mut position = Position::new();
+    position.play_uci_moves("e2e4 c7c5 g1f3").unwrap();
+
+    // Run some test cases
+    assert_eq!(
+        position.fen(),
+        "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+    );
+    assert_eq!(position.turn(), Color::Black);
+    assert_eq!(
+        position
+            .piece_at("f3".parse::<Square>().unwrap())
+            .unwrap()
+            .role,
+        Role::Knight
+    );
+
+    // The en passant square is only shown if the capture is legal
+    position.play_uci_moves("c5c4 d2d4").unwrap();
+    assert_eq!(
+        position.fen(),
+        "rnbqkbnr/pp1ppppp/8/8/2pPP3/5N2/PPP2PPP/RNBQKB1R b KQkq d3 0 3"
+    );
+
+    let result = Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1");
+    assert!(result.unwrap_err().is_invalid_fen());
+    let result = Position::from_fen(&format!("{}/8/8/8/8/8/8/8 w - - 0 1", "9".repeat(30)));
+    assert!(result.unwrap_err().is_invalid_fen());
+    let result = Position::from_fen("4k3/8/8/8/8/8/8/4K2 w - - 0 1");
+    assert!(result.unwrap_err().is_invalid_fen());
+    let result = Position::from_fen("4k3/8/8/8/8/8/8/4K03 w - - 0 1");
+    assert!(result.unwrap_err().is_invalid_fen());
+    let result = Position::from_fen("4k3/8/8/8/8/8/8/4K4 w - - 0 1");
+    assert!(result.unwrap_err().is_invalid_fen());
+    let result = Position::from_game("startpos", VariantMode::Atomic);
+    assert!(result.unwrap_err().is_unsupported_variant());
+}
+
+#[test]
+fn position_moves() {
+    let mut position = Position::new();
+
+    // Run some test cases
+    assert!(position.is_legal_uci("e2e4"));
+    assert!(!position.is_legal_uci("e2e5"));
+    assert!(!position.is_legal_uci("e7e5"));
+    assert!(position.parse_uci("e1g1").unwrap_err().is_illegal_move());
+
+    // The position is left unchanged if any of the moves is illegal
+    let result = position.play_uci_moves("e2e4 e7e5 e4e5");
+    assert!(result.unwrap_err().is_illegal_move());
+    assert_eq!(position, Position::new());
+
+    position.play_uci_moves("f2f3 e7e5 g2g4 d8h4").unwrap();
+    assert!(position.is_checkmate());
+    assert!(position.legal_moves().is_empty());
+
+    let stalemate = Position::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    assert!(stalemate.is_stalemate());
+}
+
+#[test]
+fn position_san() {
+    let position = Position::new();
+
+    // Run some test cases
+    let san = position
+        .uci_moves_to_san("e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 e1g1 f8c5 f3e5 c6e5 d2d4")
+        .unwrap();
+    assert_eq!(
+        san,
+        ["e4", "e5", "Nf3", "Nc6", "Bc4", "Nf6", "O-O", "Bc5", "Nxe5", "Nxe5", "d4"]
+    );
+
+    let mate = Position::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+    assert_eq!(mate.uci_to_san("a1a8").unwrap(), "Ra8#");
+
+    let promotion = Position::from_fen("3r2k1/4P3/8/8/8/8/3P4/R3K2R w KQ - 0 1").unwrap();
+    assert_eq!(promotion.uci_to_san("e7d8q").unwrap(), "exd8=Q+");
+    assert_eq!(promotion.uci_to_san("e1c1").unwrap(), "O-O-O");
+    assert_eq!(promotion.uci_to_san("a1d1").unwrap(), "Rd1");
+
+    let knights = Position::from_fen("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").unwrap();
+    assert_eq!(knights.uci_to_san("b1d2").unwrap(), "Nbd2");
+    assert_eq!(
+        knights.parse_san("Nfd2").unwrap().from(),
+        "f1".parse().unwrap()
+    );
+    assert!(knights.parse_san("Nd2").unwrap_err().is_illegal_move());
+}
+
+#[test]
+fn position_chess960() {
+    let mut position = Position::from_game(
+        "nrkbqrbn/pppppppp/8/8/8/8/PPPPPPPP/NRKBQRBN w KQkq - 0 1",
+        VariantMode::Chess960,
+    )
+    .unwrap();
+    position.play_uci_moves("e2e4 e7e5 d1e2 d8e7").unwrap();
+
+    // Castling is written as the king moving to the square of the rook
+    let castle = position.parse_uci("c1b1").unwrap();
+    assert!(castle.is_castle());
+    assert_eq!(position.uci(&castle), "c1b1");
+    assert_eq!(position.san(&castle), "O-O-O");
+
+    position.play(&castle).unwrap();
+    assert_eq!(
+        position.fen(),
+        "nrk1qrbn/ppppbppp/8/4p3/4P3/8/PPPPBPPP/N1KRQRBN b kq - 3 3"
+    );
+}
@@ -0,0 +1,267 @@
+#![cfg(feature = "bot")]
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, LazyLock, Mutex,
+};
+
+use licheszter::{
+    client::Licheszter,
+    config::client::ReconnectOptions,
+    error::Error,
+    models::{
+        challenge::{Challenge, ChallengeDecision},
+        common::Color,
+    },
+    runner::{BotGame, BotHandler, BotMove, BotRunner},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+const ACCOUNT: &str = r#"{"id":"bot0"}"#;
+const OK: &str = r#"{"ok":true}"#;
+const MOVE_REJECTED: &str = r#"{"error":"Not your turn, or game already over"}"#;
+const CHALLENGE: &str = r#"{"type":"challenge","challenge":{"id":"chal1","url":"http://localhost/chal1","status":"created","challenger":{"id":"bot1","name":"Bot1","title":"BOT","rating":1500},"destUser":{"id":"bot0","name":"Bot0","title":"BOT","rating":1500},"variant":{"key":"standard","name":"Standard","short":"Std"},"rated":false,"speed":"blitz","timeControl":{"type":"clock","limit":300,"increment":0,"show":"5+0"},"color":"random","finalColor":"white","perf":{"name":"Blitz"}}}"#;
+const GAME_START: &str = r#"{"type":"gameStart","game":{"id":"game1","fullId":"game1abcd","gameId":"game1","fen":"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1","color":"white","lastMove":"","source":"friend","variant":{"key":"standard","name":"Standard"},"speed":"blitz","perf":"blitz","rated":false,"hasMoved":false,"opponent":{"id":"bot1","username":"Bot1","rating":1500},"isMyTurn":true,"secondsLeft":300,"status":{"id":20,"name":"started"},"rating":1500}}"#;
+const GAME_FULL: &str = r#"{"type":"gameFull","id":"game1","rated":false,"variant":{"key":"standard","name":"Standard","short":"Std"},"clock":{"initial":300000,"increment":0},"speed":"blitz","perf":{"name":"Blitz"},"createdAt":1700000000000,"white":{"id":"bot0","name":"Bot0","title":"BOT","rating":1500},"black":{"id":"bot1","name":"Bot1","title":"BOT","rating":1500},"initialFen":"startpos","state":{"type":"gameState","moves":"","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"started"}}"#;
+const GAME_STATE: &str = r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"started"}"#;
+const GAME_DRAW_OFFER: &str = r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":300000,"btime":300000,"winc":0,"binc":0,"bdraw":true,"status":"started"}"#;
+const GAME_OVER: &str = r#"{"type":"gameState","moves":"e2e4 e7e5 g1f3","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"resign","winner":"white"}"#;
+
+// Start a local server that answers requests based on their path and records them,
+// rejecting the given number of moves first
+async fn serve(
+    requests: Arc<Mutex<Vec<String>>>,
+    events: &'static [&'static str],
+    rejected_moves: usize,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let moves = Arc::new(AtomicUsize::new(0));
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let requests = Arc::clone(&requests);
+            let moves = Arc::clone(&moves);
+            tokio::spawn(async move {
+                let mut buf = [0; 4096];
+                let len = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..len]);
+                let line = request.lines().next().unwrap().to_string();
+                let path = line.split_whitespace().nth(1).unwrap().to_string();
+                requests.lock().unwrap().push(line);
+
+                let response = match path.as_str() {
+                    "/api/account" => json(ACCOUNT),
+                    "/api/stream/event" => ndjson(events),
+                    "/api/bot/game/stream/game1" => {
+                        ndjson(&[GAME_FULL, GAME_STATE, GAME_DRAW_OFFER, GAME_OVER])
+                    }
+                    path if path.starts_with("/api/bot/game/game1/move/")
+                        && moves.fetch_add(1, Ordering::SeqCst) < rejected_moves =>
+                    {
+                        bad_request(MOVE_REJECTED)
+                    }
+                    _ => json(OK),
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            });
+        }
+    });
+
+    url
+}
+
+fn json(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn bad_request(body: &str) -> String {
+    format!(
+        "HTTP/1.1 400 Bad Request\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn ndjson(lines: &[&str]) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n{}\n",
+        lines.join("\n")
+    )
+}
+
+// A bot that records the callbacks it receives
+struct TestBot {
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl BotHandler for TestBot {
+    async fn on_challenge(&self, challenge: &Challenge) -> ChallengeDecision {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("challenge {}", challenge.id));
+        ChallengeDecision::Accept
+    }
+
+    async fn on_my_turn(&self, game: &BotGame) -> Option<BotMove> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("turn {}", game.ply()));
+        match game.ply() {
+            0 => Some(BotMove::new("e2e4")),
+            _ => Some(BotMove::new("g1f3")),
+        }
+    }
+
+    async fn on_game_start(&self, game: &BotGame) {
+        assert_eq!(game.color, Color::White);
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("start {}", game.id()));
+    }
+
+    async fn on_game_end(&self, game: &BotGame) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("end {}", game.id()));
+    }
+
+    async fn on_error(&self, game_id: Option<&str>, _error: &Error) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("error {}", game_id.unwrap_or_default()));
+    }
+}
+
+#[tokio::test]
+async fn runner_play_game() {
+    // Set up a server that sends a challenge and a game
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let url = serve(Arc::clone(&requests), &[CHALLENGE, GAME_START], 0).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let bot = TestBot {
+        calls: Arc::clone(&calls),
+    };
+    let result = BotRunner::new(&client, bot)
+        .max_games(2)
+        .reconnect_options(ReconnectOptions::new().max_attempts(0))
+        .run()
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to run the bot: {:?}",
+        result.unwrap_err()
+    );
+
+    let calls = calls.lock().unwrap().clone();
+    assert_eq!(
+        calls,
+        [
+            "challenge chal1",
+            "start game1",
+            "turn 0",
+            "turn 2",
+            "end game1"
+        ]
+    );
+
+    let requests = requests.lock().unwrap().clone();
+    assert!(requests.contains(&String::from("POST /api/challenge/chal1/accept HTTP/1.1")));
+    assert!(requests.contains(&String::from(
+        "POST /api/bot/game/game1/move/e2e4?offeringDraw=false HTTP/1.1"
+    )));
+    assert!(requests.contains(&String::from(
+        "POST /api/bot/game/game1/move/g1f3?offeringDraw=false HTTP/1.1"
+    )));
+}
+
+#[tokio::test]
+async fn runner_max_games() {
+    // Set up a server that sends two challenges before any game starts
+    static OTHER_CHALLENGE: LazyLock<String> =
+        LazyLock::new(|| CHALLENGE.replace("chal1", "chal2"));
+    static EVENTS: LazyLock<[&str; 2]> = LazyLock::new(|| [CHALLENGE, &OTHER_CHALLENGE]);
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let url = serve(Arc::clone(&requests), &*EVENTS, 0).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let bot = TestBot {
+        calls: Arc::clone(&calls),
+    };
+    let result = BotRunner::new(&client, bot)
+        .reconnect_options(ReconnectOptions::new().max_attempts(0))
+        .run()
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to run the bot: {:?}",
+        result.unwrap_err()
+    );
+
+    let calls = calls.lock().unwrap().clone();
+    assert_eq!(calls, ["challenge chal1"]);
+
+    let requests = requests.lock().unwrap().clone();
+    assert!(requests.contains(&String::from("POST /api/challenge/chal1/accept HTTP/1.1")));
+    assert!(requests.contains(&String::from("POST /api/challenge/chal2/decline HTTP/1.1")));
+}
+
+#[tokio::test]
+async fn runner_move_rejected() {
+    // Set up a server that rejects the first move of the game
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let url = serve(Arc::clone(&requests), &[GAME_START], 1).await;
+    let client = Licheszter::builder().with_base_url(url).unwrap().build();
+
+    // Run some test cases
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let bot = TestBot {
+        calls: Arc::clone(&calls),
+    };
+    let result = BotRunner::new(&client, bot)
+        .reconnect_options(ReconnectOptions::new().max_attempts(0))
+        .run()
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to run the bot: {:?}",
+        result.unwrap_err()
+    );
+
+    let calls = calls.lock().unwrap().clone();
+    assert_eq!(
+        calls,
+        [
+            "start game1",
+            "turn 0",
+            "error game1",
+            "turn 0",
+            "turn 2",
+            "end game1"
+        ]
+    );
+
+    let requests = requests.lock().unwrap().clone();
+    let moves = requests
+        .iter()
+        .filter(|request| request.starts_with("POST /api/bot/game/game1/move/e2e4"))
+        .count();
+    assert_eq!(moves, 2);
+}
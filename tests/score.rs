@@ -0,0 +1,113 @@
+use licheszter::models::{common::FinalColor, game::MoveAnalysis, score::Score};
+
+#[cfg(feature = "analysis")]
+use licheszter::models::analysis::CloudAnalysis;
+
+#[test]
+fn score_deserialize() {
+    // Run some test cases
+    let analysis: MoveAnalysis = serde_json::from_str(r#"{"eval":-250,"best":"e2e4"}"#).unwrap();
+    assert_eq!(analysis.score, Some(Score::Centipawns(-250)));
+    assert_eq!(analysis.best.as_deref(), Some("e2e4"));
+
+    let analysis: MoveAnalysis = serde_json::from_str(r#"{"mate":-3}"#).unwrap();
+    assert_eq!(analysis.score, Some(Score::Mate(-3)));
+    assert_eq!(serde_json::to_string(&analysis).unwrap(), r#"{"mate":-3}"#);
+
+    let analysis: MoveAnalysis = serde_json::from_str("{}").unwrap();
+    assert_eq!(analysis.score, None);
+}
+
+#[cfg(feature = "analysis")]
+#[test]
+fn score_cloud_analysis() {
+    let json = r#"{"fen":"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1","knodes":1000,"depth":40,"pvs":[{"moves":"c7c5 g1f3","cp":2500},{"moves":"f7f6 d1h5","mate":2}]}"#;
+
+    // Run some test cases
+    let analysis: CloudAnalysis = serde_json::from_str(json).unwrap();
+    assert_eq!(analysis.pvs[0].score, Score::Centipawns(2500));
+    assert_eq!(analysis.pvs[1].score, Score::Mate(2));
+    assert_eq!(serde_json::to_string(&analysis).unwrap(), json);
+}
+
+#[test]
+fn score_compare() {
+    // Run some test cases
+    let mut scores = vec![
+        Score::Mate(5),
+        Score::Centipawns(-40),
+        Score::Mate(-1),
+        Score::Mate(1),
+        Score::Centipawns(300),
+        Score::Mate(-6),
+    ];
+    scores.sort();
+    assert_eq!(
+        scores,
+        [
+            Score::Mate(-1),
+            Score::Mate(-6),
+            Score::Centipawns(-40),
+            Score::Centipawns(300),
+            Score::Mate(5),
+            Score::Mate(1),
+        ]
+    );
+
+    assert_eq!(
+        Score::Centipawns(120).for_color(FinalColor::Black),
+        Score::Centipawns(-120)
+    );
+    assert_eq!(Score::Mate(3).for_color(FinalColor::White), Score::Mate(3));
+    assert_eq!(-Score::Mate(3), Score::Mate(-3));
+    assert_eq!(Score::Centipawns(-5).to_string(), "-0.05");
+    assert_eq!(Score::Centipawns(150).to_string(), "+1.50");
+    assert_eq!(Score::Mate(-2).to_string(), "#-2");
+}
+
+#[test]
+fn score_win_probability() {
+    // Run some test cases
+    assert!((Score::Centipawns(0).win_probability() - 0.5).abs() < 1e-9);
+    assert!(Score::Centipawns(100).win_probability() > 0.5);
+    assert!(
+        (Score::Centipawns(100).win_probability() + Score::Centipawns(-100).win_probability()
+            - 1.0)
+            .abs()
+            < 1e-9
+    );
+    assert_eq!(
+        Score::Centipawns(5000).win_probability(),
+        Score::Centipawns(1000).win_probability()
+    );
+    assert_eq!(Score::Mate(4).win_probability(), 1.0);
+    assert_eq!(Score::Mate(-4).win_probability(), 0.0);
+}
+
+#[cfg(feature = "serde-strict")]
+#[test]
+fn score_strict() {
+    // Run some test cases
+    let result = serde_json::from_str::<MoveAnalysis>(r#"{"eval":15,"unknown":true}"#);
+    assert!(result.is_err(), "Unknown field was accepted: {result:?}");
+
+    #[cfg(feature = "analysis")]
+    {
+        use licheszter::models::analysis::PositionVariation;
+
+        let result =
+            serde_json::from_str::<PositionVariation>(r#"{"moves":"e2e4","cp":15,"unknown":true}"#);
+        assert!(result.is_err(), "Unknown field was accepted: {result:?}");
+        let result = serde_json::from_str::<PositionVariation>(r#"{"moves":"e2e4"}"#);
+        assert!(result.is_err(), "Missing score was accepted: {result:?}");
+    }
+
+    #[cfg(feature = "external_engine")]
+    {
+        use licheszter::models::external_engine::EnginePv;
+
+        let result =
+            serde_json::from_str::<EnginePv>(r#"{"depth":1,"mate":2,"moves":[],"unknown":true}"#);
+        assert!(result.is_err(), "Unknown field was accepted: {result:?}");
+    }
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "studies")]
+
+use std::{error::Error, sync::LazyLock};
+
+use futures_util::StreamExt;
+use licheszter::{
+    client::Licheszter,
+    config::studies::{StudyExportOptions, StudyImportOptions},
+    models::{common::FinalColor, game::VariantMode},
+};
+
+// A game to import into studies
+const PGN: &str = r#"[White "Player A"]
+[Black "Player B"]
+[Result "1-0"]
+
+1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7# 1-0
+"#;
+
+// Connect to test clients
+static LI: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_li")
+        .unwrap()
+        .build()
+});
+
+static BOT0: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build()
+});
+
+// Get the ID of a study owned by the Li test account
+async fn study_id() -> String {
+    let mut studies = LI.studies().list_by_user("li").await.unwrap();
+    studies.next().await.unwrap().unwrap().id
+}
+
+#[tokio::test]
+async fn studies_list() {
+    // Run some test cases
+    let mut result = LI.studies().list_by_user("li").await.unwrap();
+    while let Some(study) = result.next().await {
+        assert!(
+            study.is_ok(),
+            "Failed to get studies of user: {:?}",
+            study.unwrap_err().source().unwrap()
+        );
+    }
+}
+
+#[tokio::test]
+async fn studies_export() {
+    // Create options for testing
+    let study = study_id().await;
+    let options = StudyExportOptions::new()
+        .clocks(true)
+        .comments(false)
+        .variations(false)
+        .orientation(true);
+
+    // Run some test cases
+    let mut result = LI.studies().export(&study, Some(&options)).await.unwrap();
+    while let Some(pgn) = result.next().await {
+        assert!(
+            pgn.is_ok(),
+            "Failed to export study: {:?}",
+            pgn.unwrap_err().source().unwrap()
+        );
+    }
+
+    let mut result = LI.studies().export_by_user("li", None).await.unwrap();
+    while let Some(pgn) = result.next().await {
+        assert!(
+            pgn.is_ok(),
+            "Failed to export studies of user: {:?}",
+            pgn.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = LI.studies().export("notvalid", None).await;
+    assert!(result.is_err(), "Exporting study did not fail");
+}
+
+#[tokio::test]
+async fn studies_import_chapters() {
+    // Create options for testing
+    let study = study_id().await;
+    let options = StudyImportOptions::new()
+        .name("Licheszter")
+        .orientation(FinalColor::Black)
+        .variant(VariantMode::Standard);
+
+    // Run some test cases
+    let result = LI.studies().import_pgn(&study, PGN, Some(&options)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to import PGN into study: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let chapter = result.unwrap().chapters.remove(0);
+    let result = LI.studies().export_chapter(&study, &chapter.id, None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to export study chapter: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let tags = [("Event", "Licheszter \"Open\""), ("Site", "")];
+    let result = LI.studies().update_tags(&study, &chapter.id, &tags).await;
+    assert!(
+        result.is_ok(),
+        "Failed to update study chapter tags: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.studies().delete_chapter(&study, &chapter.id).await;
+    assert!(
+        result.is_err(),
+        "Deleting study chapter did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = LI.studies().delete_chapter(&study, &chapter.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to delete study chapter: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.studies().import_pgn(&study, PGN, None).await;
+    assert!(
+        result.is_err(),
+        "Importing PGN into study did not fail: {:?}",
+        result.unwrap()
+    );
+}
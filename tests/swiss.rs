@@ -0,0 +1,259 @@
+#![cfg(feature = "swiss")]
+
+use std::{error::Error, sync::LazyLock};
+
+use futures_util::StreamExt;
+use licheszter::{
+    client::Licheszter,
+    config::{games::GameOptions, swiss::SwissOptions},
+    models::{
+        game::VariantMode,
+        swiss::{SwissChat, SwissStatus},
+    },
+};
+
+// The team led by the Li test account
+const TEAM: &str = "lichess-swiss";
+
+// Connect to test clients
+static LI: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_li")
+        .unwrap()
+        .build()
+});
+
+static BOT0: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build()
+});
+
+// Options for a tournament with manually scheduled rounds
+fn options() -> SwissOptions {
+    SwissOptions::new()
+        .name("Licheszter")
+        .clock(180, 2)
+        .nb_rounds(5)
+        .round_interval(99_999_999)
+}
+
+#[tokio::test]
+async fn swiss_create() {
+    // Create options for testing
+    let options1 = options()
+        .variant(VariantMode::Standard)
+        .rated(false)
+        .position("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+        .description("Created by Licheszter")
+        .password("password")
+        .forbidden_pairings(&[("bot0", "bot1")])
+        .chat_for(SwissChat::Everyone)
+        .min_rated_games(0)
+        .play_your_games(true)
+        .account_age(1);
+
+    // Run some test cases
+    let result = LI.swiss().create(TEAM, &options1).await;
+    assert!(
+        result.is_ok(),
+        "Failed to create Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.swiss().create(TEAM, &options()).await;
+    assert!(
+        result.is_ok(),
+        "Failed to create Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.swiss().create(TEAM, &SwissOptions::new()).await;
+    assert!(
+        result.is_err(),
+        "Creating Swiss tournament did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = BOT0.swiss().create(TEAM, &options()).await;
+    assert!(
+        result.is_err(),
+        "Creating Swiss tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn swiss_show_edit() {
+    // Create a tournament for testing
+    let swiss = LI.swiss().create(TEAM, &options()).await.unwrap();
+
+    // Run some test cases
+    let result = LI.swiss().show(&swiss.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.swiss().edit(&swiss.id, &options().nb_rounds(7)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to edit Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.swiss().edit(&swiss.id, &options()).await;
+    assert!(
+        result.is_err(),
+        "Editing Swiss tournament did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = LI.swiss().show("notvalid").await;
+    assert!(
+        result.is_err(),
+        "Getting Swiss tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn swiss_join_withdraw() {
+    // Create tournaments for testing
+    let swiss = LI.swiss().create(TEAM, &options()).await.unwrap();
+    let private = LI
+        .swiss()
+        .create(TEAM, &options().password("password"))
+        .await
+        .unwrap();
+
+    // Run some test cases
+    let result = LI.swiss().join(&swiss.id, None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to join Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.swiss().withdraw(&swiss.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to withdraw from Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.swiss().join(&private.id, Some("password")).await;
+    assert!(
+        result.is_ok(),
+        "Failed to join Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.swiss().join("notvalid", None).await;
+    assert!(
+        result.is_err(),
+        "Joining Swiss tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn swiss_schedule_terminate() {
+    // Create a tournament for testing
+    let swiss = LI.swiss().create(TEAM, &options()).await.unwrap();
+    let timestamp = swiss.starts_at.unix_timestamp() as u64 * 1000 + 3_600_000;
+
+    // Run some test cases
+    let result = LI.swiss().schedule_next_round(&swiss.id, timestamp).await;
+    assert!(
+        result.is_ok(),
+        "Failed to schedule next round: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.swiss().terminate(&swiss.id).await;
+    assert!(
+        result.is_err(),
+        "Terminating Swiss tournament did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = LI.swiss().terminate(&swiss.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to terminate Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn swiss_export() {
+    // Create a tournament for testing
+    let swiss = LI.swiss().create(TEAM, &options()).await.unwrap();
+    LI.swiss().join(&swiss.id, None).await.unwrap();
+    let options = GameOptions::new().moves(true).clocks(true).opening(true);
+
+    // Run some test cases
+    let result = LI.swiss().export_trf(&swiss.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to export Swiss tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let mut result = LI.swiss().games(&swiss.id, Some(&options)).await.unwrap();
+    while let Some(game) = result.next().await {
+        assert!(
+            game.is_ok(),
+            "Failed to get Swiss tournament games: {:?}",
+            game.unwrap_err().source().unwrap()
+        );
+    }
+
+    let mut result = LI.swiss().results(&swiss.id, Some(10)).await.unwrap();
+    while let Some(player) = result.next().await {
+        assert!(
+            player.is_ok(),
+            "Failed to get Swiss tournament results: {:?}",
+            player.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = LI.swiss().results("notvalid", None).await;
+    assert!(
+        result.is_err(),
+        "Getting Swiss tournament results did not fail"
+    );
+}
+
+#[tokio::test]
+async fn swiss_team() {
+    // Create a tournament for testing
+    LI.swiss().create(TEAM, &options()).await.unwrap();
+
+    // Run some test cases
+    let mut result = LI
+        .swiss()
+        .team(TEAM, Some(10), Some(SwissStatus::Created))
+        .await
+        .unwrap();
+    while let Some(swiss) = result.next().await {
+        assert!(
+            swiss.is_ok(),
+            "Failed to get team Swiss tournaments: {:?}",
+            swiss.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = LI.swiss().team("notvalid", None, None).await;
+    assert!(
+        result.is_err(),
+        "Getting team Swiss tournaments did not fail"
+    );
+}
@@ -0,0 +1,239 @@
+#![cfg(feature = "teams")]
+
+use std::{error::Error, sync::LazyLock};
+
+use futures_util::StreamExt;
+use licheszter::client::Licheszter;
+
+// The team led by the Li test account
+const TEAM: &str = "lichess-swiss";
+
+// Connect to test clients
+static LI: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_li")
+        .unwrap()
+        .build()
+});
+
+static BOT0: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build()
+});
+
+#[tokio::test]
+async fn teams_show() {
+    // Run some test cases
+    let result = LI.teams().show(TEAM).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get team: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.teams().show("notvalid").await;
+    assert!(
+        result.is_err(),
+        "Getting team did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn teams_popular_search() {
+    // Run some test cases
+    let result = LI.teams().popular(None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get popular teams: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.teams().popular(Some(2)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get popular teams: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.teams().search("lichess", None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to search teams: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.teams().search("notvalid", Some(2)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to search teams: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn teams_of_user() {
+    // Run some test cases
+    let result = LI.teams().of_user("li").await;
+    assert!(
+        result.is_ok(),
+        "Failed to get teams of user: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.teams().of_user("bot0").await;
+    assert!(
+        result.is_ok(),
+        "Failed to get teams of user: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn teams_members() {
+    // Run some test cases
+    let mut result = LI.teams().members(TEAM).await.unwrap();
+    while let Some(member) = result.next().await {
+        assert!(
+            member.is_ok(),
+            "Failed to get team members: {:?}",
+            member.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = LI.teams().members("notvalid").await;
+    assert!(result.is_err(), "Getting team members did not fail");
+}
+
+#[tokio::test]
+async fn teams_join_leave() {
+    // Run some test cases
+    let result = BOT0
+        .teams()
+        .join(TEAM, Some("Licheszter wants to join"), None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to join team: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.teams().leave(TEAM).await;
+    assert!(
+        result.is_ok(),
+        "Failed to leave team: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.teams().join("notvalid", None, None).await;
+    assert!(
+        result.is_err(),
+        "Joining team did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn teams_requests() {
+    // Run some test cases
+    let result = LI.teams().requests(TEAM, false).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get team join requests: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.teams().requests(TEAM, true).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get declined team join requests: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.teams().requests(TEAM, false).await;
+    assert!(
+        result.is_err(),
+        "Getting team join requests did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = BOT0.teams().accept_request(TEAM, "li").await;
+    assert!(
+        result.is_err(),
+        "Accepting team join request did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = BOT0.teams().decline_request(TEAM, "li").await;
+    assert!(
+        result.is_err(),
+        "Declining team join request did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn teams_kick() {
+    // Run some test cases
+    let result = BOT0.teams().kick(TEAM, "li").await;
+    assert!(
+        result.is_err(),
+        "Kicking team member did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn teams_message_all() {
+    // Run some test cases
+    let result = LI.teams().message_all(TEAM, "Hello from Licheszter!").await;
+    assert!(
+        result.is_ok(),
+        "Failed to message team members: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0
+        .teams()
+        .message_all(TEAM, "Hello from Licheszter!")
+        .await;
+    assert!(
+        result.is_err(),
+        "Messaging team members did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[cfg(feature = "tournaments")]
+#[tokio::test]
+async fn teams_arenas() {
+    // Run some test cases
+    let mut result = LI.teams().arenas(TEAM, Some(10)).await.unwrap();
+    while let Some(tournament) = result.next().await {
+        assert!(
+            tournament.is_ok(),
+            "Failed to get team arena tournaments: {:?}",
+            tournament.unwrap_err().source().unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "swiss")]
+#[tokio::test]
+async fn teams_swisses() {
+    // Run some test cases
+    let mut result = LI.teams().swisses(TEAM, Some(10), None).await.unwrap();
+    while let Some(swiss) = result.next().await {
+        assert!(
+            swiss.is_ok(),
+            "Failed to get team Swiss tournaments: {:?}",
+            swiss.unwrap_err().source().unwrap()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+#![cfg(all(feature = "testing", feature = "bot"))]
+
+use futures_util::StreamExt;
+use licheszter::{
+    client::ResilientItem,
+    config::client::ReconnectOptions,
+    models::board::{BoardState, Event},
+    testing::{FakeLichess, FakeResponse},
+};
+use reqwest::{Method, StatusCode};
+use serde_json::json;
+
+const GAME_START: &str = r#"{"type":"gameStart","game":{"id":"abcdefgh","fullId":"abcdefghijkl","gameId":"abcdefgh","fen":"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1","color":"white","lastMove":"","source":"friend","variant":{"key":"standard","name":"Standard"},"speed":"blitz","perf":"blitz","rated":false,"hasMoved":false,"opponent":{"id":"bot1","username":"Bot1"},"isMyTurn":true,"secondsLeft":300,"status":{"id":20,"name":"started"},"rating":1500}}"#;
+const GAME_FULL: &str = r#"{"type":"gameFull","id":"abcdefgh","rated":false,"variant":{"key":"standard","name":"Standard","short":"Std"},"clock":{"initial":300000,"increment":0},"speed":"blitz","perf":{"name":"Blitz"},"createdAt":1700000000000,"white":{"id":"bot0","name":"Bot0","title":"BOT","rating":1500},"black":{"id":"bot1","name":"Bot1","title":"BOT","rating":1500},"initialFen":"startpos","state":{"type":"gameState","moves":"","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"started"}}"#;
+const GAME_STATE: &str = r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":300000,"btime":300000,"winc":0,"binc":0,"status":"started"}"#;
+
+#[tokio::test]
+async fn fake_bot_game() {
+    // Set up a server with a game waiting to be played
+    let server = FakeLichess::start().await.unwrap();
+    let events = server.event_stream();
+    let game = server.bot_game_stream("abcdefgh");
+    events.send_raw(GAME_START);
+    game.send_raw(GAME_FULL);
+
+    let client = server
+        .builder()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build();
+
+    // Run some test cases
+    let mut stream = client.connect().await.unwrap();
+    let event = stream.next().await.unwrap();
+    assert!(
+        matches!(event, Ok(Event::GameStart { ref game }) if game.id == "abcdefgh"),
+        "Failed to receive a scripted event: {event:?}"
+    );
+
+    let mut stream = client.bot().game_connect("abcdefgh").await.unwrap();
+    let state = stream.next().await.unwrap();
+    assert!(
+        matches!(state, Ok(BoardState::GameFull(_))),
+        "Failed to receive a scripted game state: {state:?}"
+    );
+
+    let result = client.bot().play_move("abcdefgh", "e2e4", true).await;
+    assert!(
+        result.is_ok(),
+        "Failed to play a move on the fake server: {:?}",
+        result.unwrap_err()
+    );
+
+    game.keep_alive();
+    game.send_raw(GAME_STATE);
+    let state = stream.next().await.unwrap();
+    assert!(
+        matches!(state, Ok(BoardState::GameState(ref state)) if state.moves == "e2e4 e7e5"),
+        "Failed to receive a scripted game state: {state:?}"
+    );
+
+    let requests = server.requests();
+    let request = requests
+        .iter()
+        .find(|request| request.method == Method::POST)
+        .unwrap();
+    assert_eq!(request.path, "/api/bot/game/abcdefgh/move/e2e4");
+    assert_eq!(request.query.as_deref(), Some("offeringDraw=true"));
+    assert_eq!(request.header("Authorization"), Some("Bearer lip_bot0"));
+}
+
+#[tokio::test]
+async fn fake_routes() {
+    // Set up a server with custom responses
+    let server = FakeLichess::start().await.unwrap();
+    server.route(
+        Method::POST,
+        "/api/bot/game/*/move/*",
+        FakeResponse::error(
+            StatusCode::BAD_REQUEST,
+            "Not your turn, or game already over",
+        ),
+    );
+    server.json(Method::GET, "/api/bot/game/abcdefgh/chat", &json!([]));
+    let client = server.builder().build();
+
+    // Run some test cases
+    let result = client.bot().play_move("abcdefgh", "e2e4", false).await;
+    assert!(
+        result.is_err(),
+        "Playing a move did not fail: {:?}",
+        result.unwrap()
+    );
+    assert_eq!(
+        result.unwrap_err().lichess().unwrap().message(),
+        "Not your turn, or game already over"
+    );
+
+    let result = client.bot().chat_read("abcdefgh").await;
+    assert!(
+        result.is_ok(),
+        "Failed to read the chat from the fake server: {:?}",
+        result.unwrap_err()
+    );
+
+    let result = client.bot().game_connect("notvalid").await;
+    assert!(
+        result.is_err(),
+        "Connecting to an unknown game did not fail"
+    );
+    assert_eq!(result.err().unwrap().status(), Some(StatusCode::NOT_FOUND));
+}
+
+#[tokio::test]
+async fn fake_stream_reconnect() {
+    // Set up a server that drops the game stream after the first state
+    let server = FakeLichess::start().await.unwrap();
+    let game = server.bot_game_stream("abcdefgh");
+    game.send_raw(GAME_FULL);
+    game.disconnect();
+    game.send_raw(GAME_STATE);
+
+    let client = server.builder().build();
+    let options = ReconnectOptions::new()
+        .initial_backoff(std::time::Duration::from_millis(10))
+        .jitter(false);
+
+    // Run some test cases
+    let mut stream = client
+        .bot()
+        .game_connect_resilient("abcdefgh", options)
+        .await
+        .unwrap();
+    assert!(matches!(
+        stream.next().await,
+        Some(Ok(ResilientItem::Item(BoardState::GameFull(_))))
+    ));
+    assert!(matches!(
+        stream.next().await,
+        Some(Ok(ResilientItem::Reconnected))
+    ));
+    assert!(matches!(
+        stream.next().await,
+        Some(Ok(ResilientItem::Item(BoardState::GameState(_))))
+    ));
+}
+
+#[tokio::test]
+async fn fake_event_replay() {
+    // Set up a server that sends the current challenges and games again after reconnecting
+    let server = FakeLichess::start().await.unwrap();
+    let events = server.event_stream();
+    events.send_raw(&challenge_event("challenge", "chal0001"));
+    events.send_raw(GAME_START);
+    events.send_raw(&challenge_event("challenge", "chal0002"));
+    events.send_raw(&challenge_event("challengeCanceled", "chal0002"));
+    events.disconnect();
+    events.send_raw(&challenge_event("challenge", "chal0001"));
+    events.send_raw(GAME_START);
+    events.send_raw(&challenge_event("challenge", "chal0002"));
+
+    let client = server.builder().build();
+    let options = ReconnectOptions::new()
+        .initial_backoff(std::time::Duration::from_millis(10))
+        .jitter(false);
+
+    // Run some test cases
+    let mut stream = client.connect_resilient(options).await.unwrap();
+    let mut items = Vec::new();
+    for _ in 0..6 {
+        items.push(stream.next().await.unwrap().unwrap());
+    }
+    let ids: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            ResilientItem::Item(Event::Challenge { challenge }) => {
+                format!("challenge/{}", challenge.id)
+            }
+            ResilientItem::Item(Event::ChallengeCanceled { challenge }) => {
+                format!("canceled/{}", challenge.id)
+            }
+            ResilientItem::Item(Event::GameStart { game }) => format!("game/{}", game.id),
+            ResilientItem::Item(event) => format!("{event:?}"),
+            ResilientItem::Reconnected => "reconnected".to_string(),
+        })
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            "challenge/chal0001",
+            "game/abcdefgh",
+            "challenge/chal0002",
+            "canceled/chal0002",
+            "reconnected",
+            "challenge/chal0002",
+        ],
+        "Replayed events were not suppressed after reconnecting"
+    );
+}
+
+fn challenge_event(kind: &str, id: &str) -> String {
+    format!(
+        r#"{{"type":"{kind}","challenge":{{"id":"{id}","url":"http://localhost/{id}","status":"created","challenger":{{"id":"bot1","name":"Bot1","title":"BOT","rating":1500}},"destUser":{{"id":"bot0","name":"Bot0","title":"BOT","rating":1500}},"variant":{{"key":"standard","name":"Standard","short":"Std"}},"rated":false,"speed":"blitz","timeControl":{{"type":"clock","limit":300,"increment":0,"show":"5+0"}},"color":"random","finalColor":"white","perf":{{"name":"Blitz"}}}}}}"#
+    )
+}
+
+#[cfg(feature = "tv")]
+#[tokio::test]
+async fn fake_tv_feed() {
+    // Set up a server with a scripted TV feed
+    let server = FakeLichess::start().await.unwrap();
+    let feed = server.tv_feed();
+    feed.send(&json!({
+        "t": "fen",
+        "d": {"fen": "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1", "lm": "e2e4", "wc": 180, "bc": 180}
+    }));
+    let client = server.builder().build();
+
+    // Run some test cases
+    let mut stream = client.tv().connect().await.unwrap();
+    let event = stream.next().await.unwrap();
+    assert!(
+        event.is_ok(),
+        "Failed to receive a scripted TV event: {:?}",
+        event.unwrap_err()
+    );
+}
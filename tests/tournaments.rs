@@ -0,0 +1,280 @@
+#![cfg(feature = "tournaments")]
+
+use std::{error::Error, sync::LazyLock};
+
+use futures_util::StreamExt;
+use licheszter::{
+    client::Licheszter,
+    config::{
+        games::GameOptions,
+        tournaments::{ArenaJoinOptions, ArenaTournamentOptions},
+    },
+    models::{game::VariantMode, tournament::ArenaStatus},
+};
+
+// Connect to test clients
+static LI: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_li")
+        .unwrap()
+        .build()
+});
+
+static BOT0: LazyLock<Licheszter> = LazyLock::new(|| {
+    Licheszter::builder()
+        .with_base_url("http://localhost:8080")
+        .unwrap()
+        .with_authentication("lip_bot0")
+        .unwrap()
+        .build()
+});
+
+// Options for a tournament that bots can join
+fn options() -> ArenaTournamentOptions {
+    ArenaTournamentOptions::new()
+        .name("Licheszter")
+        .clock(180, 2)
+        .minutes(60)
+        .bots(true)
+}
+
+#[tokio::test]
+async fn tournaments_current() {
+    // Run some test cases
+    let result = LI.tournaments().current().await;
+    assert!(
+        result.is_ok(),
+        "Failed to get current tournaments: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tournaments_create() {
+    // Create options for testing
+    let options1 = options()
+        .wait_minutes(10)
+        .variant(VariantMode::Standard)
+        .rated(false)
+        .position("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+        .berserkable(false)
+        .streakable(false)
+        .has_chat(false)
+        .description("Created by Licheszter")
+        .password("password")
+        .min_rated_games(0)
+        .account_age(1);
+
+    // Run some test cases
+    let result = LI.tournaments().create(&options1).await;
+    assert!(
+        result.is_ok(),
+        "Failed to create tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.tournaments().create(&options()).await;
+    assert!(
+        result.is_ok(),
+        "Failed to create tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI
+        .tournaments()
+        .create(&ArenaTournamentOptions::new())
+        .await;
+    assert!(
+        result.is_err(),
+        "Creating tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tournaments_show() {
+    // Create a tournament for testing
+    let tournament = LI.tournaments().create(&options()).await.unwrap();
+
+    // Run some test cases
+    let result = LI.tournaments().show(&tournament.id, None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.tournaments().show(&tournament.id, Some(2)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to get tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = LI.tournaments().show("notvalid", None).await;
+    assert!(
+        result.is_err(),
+        "Getting tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tournaments_update() {
+    // Create a tournament for testing
+    let tournament = LI.tournaments().create(&options()).await.unwrap();
+
+    // Run some test cases
+    let result = LI
+        .tournaments()
+        .update(&tournament.id, &options().minutes(90))
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to update tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.tournaments().update(&tournament.id, &options()).await;
+    assert!(
+        result.is_err(),
+        "Updating tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tournaments_join_withdraw() {
+    // Create tournaments for testing
+    let tournament = LI.tournaments().create(&options()).await.unwrap();
+    let private = LI
+        .tournaments()
+        .create(&options().password("password"))
+        .await
+        .unwrap();
+
+    // Run some test cases
+    let result = BOT0.tournaments().join(&tournament.id, None).await;
+    assert!(
+        result.is_ok(),
+        "Failed to join tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.tournaments().withdraw(&tournament.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to withdraw from tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let join_options = ArenaJoinOptions::new()
+        .password("password")
+        .pair_me_asap(true);
+    let result = BOT0
+        .tournaments()
+        .join(&private.id, Some(&join_options))
+        .await;
+    assert!(
+        result.is_ok(),
+        "Failed to join tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+
+    let result = BOT0.tournaments().join("notvalid", None).await;
+    assert!(
+        result.is_err(),
+        "Joining tournament did not fail: {:?}",
+        result.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tournaments_terminate() {
+    // Create a tournament for testing
+    let tournament = LI.tournaments().create(&options()).await.unwrap();
+
+    // Run some test cases
+    let result = BOT0.tournaments().terminate(&tournament.id).await;
+    assert!(
+        result.is_err(),
+        "Terminating tournament did not fail: {:?}",
+        result.unwrap()
+    );
+
+    let result = LI.tournaments().terminate(&tournament.id).await;
+    assert!(
+        result.is_ok(),
+        "Failed to terminate tournament: {:?}",
+        result.unwrap_err().source().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tournaments_games_results() {
+    // Create a tournament for testing
+    let tournament = LI.tournaments().create(&options()).await.unwrap();
+    BOT0.tournaments().join(&tournament.id, None).await.unwrap();
+    let options = GameOptions::new().moves(true).clocks(true).opening(true);
+
+    // Run some test cases
+    let mut result = LI
+        .tournaments()
+        .games(&tournament.id, Some(&options))
+        .await
+        .unwrap();
+    while let Some(game) = result.next().await {
+        assert!(
+            game.is_ok(),
+            "Failed to get tournament games: {:?}",
+            game.unwrap_err().source().unwrap()
+        );
+    }
+
+    let mut result = LI
+        .tournaments()
+        .results(&tournament.id, Some(10), true)
+        .await
+        .unwrap();
+    while let Some(player) = result.next().await {
+        assert!(
+            player.is_ok(),
+            "Failed to get tournament results: {:?}",
+            player.unwrap_err().source().unwrap()
+        );
+    }
+
+    let result = LI.tournaments().results("notvalid", None, false).await;
+    assert!(result.is_err(), "Getting tournament results did not fail");
+}
+
+#[tokio::test]
+async fn tournaments_created_played() {
+    // Create a tournament for testing
+    LI.tournaments().create(&options()).await.unwrap();
+
+    // Run some test cases
+    let mut result = LI
+        .tournaments()
+        .created("li", Some(ArenaStatus::Created))
+        .await
+        .unwrap();
+    while let Some(tournament) = result.next().await {
+        assert!(
+            tournament.is_ok(),
+            "Failed to get created tournaments: {:?}",
+            tournament.unwrap_err().source().unwrap()
+        );
+    }
+
+    let mut result = LI.tournaments().played("bot0", Some(10)).await.unwrap();
+    while let Some(tournament) = result.next().await {
+        assert!(
+            tournament.is_ok(),
+            "Failed to get played tournaments: {:?}",
+            tournament.unwrap_err().source().unwrap()
+        );
+    }
+}
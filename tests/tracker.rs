@@ -0,0 +1,109 @@
+#![cfg(any(feature = "bot", feature = "board"))]
+
+use std::time::Duration;
+
+use licheszter::{
+    models::{board::BoardState, common::Color, game::GameStatus},
+    tracker::{GameChange, GameTracker},
+};
+
+const GAME_FULL: &str = r#"{"type":"gameFull","id":"game1","rated":false,"variant":{"key":"standard","name":"Standard","short":"Std"},"clock":{"initial":300000,"increment":2000},"speed":"blitz","perf":{"name":"Blitz"},"createdAt":1700000000000,"white":{"id":"bot0","name":"Bot0","title":"BOT","rating":1500},"black":{"id":"bot1","name":"Bot1","title":"BOT","rating":1500},"initialFen":"startpos","state":{"type":"gameState","moves":"e2e4","wtime":300000,"btime":300000,"winc":2000,"binc":2000,"status":"started"}}"#;
+
+fn state(json: &str) -> BoardState {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn tracker_game() {
+    let mut tracker = GameTracker::new("Bot1");
+
+    // States received before the full game are ignored
+    let changes = tracker.update(&state(
+        r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":300000,"btime":300000,"winc":2000,"binc":2000,"status":"started"}"#,
+    ));
+    assert!(changes.is_empty());
+    assert!(tracker.snapshot().is_none());
+
+    // Run some test cases
+    let changes = tracker.update(&state(GAME_FULL));
+    assert_eq!(
+        changes,
+        [GameChange::Started, GameChange::Move(String::from("e2e4"))]
+    );
+
+    let snapshot = tracker.snapshot().unwrap();
+    assert_eq!(snapshot.id, "game1");
+    assert_eq!(snapshot.color, Some(Color::Black));
+    assert_eq!(snapshot.turn, Color::Black);
+    assert!(snapshot.is_my_turn());
+    assert_eq!(snapshot.ply, 1);
+    assert_eq!(snapshot.last_move.as_deref(), Some("e2e4"));
+    assert_eq!(snapshot.black_time, Duration::from_secs(300));
+    assert_eq!(snapshot.white_increment, Duration::from_secs(2));
+
+    let changes = tracker.update(&state(
+        r#"{"type":"gameState","moves":"e2e4 e7e5 g1f3","wtime":299000,"btime":298000,"winc":2000,"binc":2000,"wdraw":true,"status":"started"}"#,
+    ));
+    assert_eq!(
+        changes,
+        [
+            GameChange::Move(String::from("e7e5")),
+            GameChange::Move(String::from("g1f3")),
+            GameChange::DrawOffer(Color::White)
+        ]
+    );
+
+    let snapshot = tracker.snapshot().unwrap();
+    assert_eq!(snapshot.draw_offer, Some(Color::White));
+    assert!(snapshot.is_my_turn());
+    assert!(snapshot.black_time <= Duration::from_millis(298000));
+    assert_eq!(snapshot.white_time, Duration::from_millis(299000));
+
+    let changes = tracker.update(&state(
+        r#"{"type":"opponentGone","gone":true,"claimWinInSeconds":10}"#,
+    ));
+    assert_eq!(
+        changes,
+        [GameChange::OpponentGone(Some(Duration::from_secs(10)))]
+    );
+
+    let snapshot = tracker.snapshot().unwrap();
+    assert!(snapshot.opponent_gone);
+    assert!(snapshot.claim_win_in.unwrap() <= Duration::from_secs(10));
+    assert!(!snapshot.can_claim_win());
+
+    let changes = tracker.update(&state(r#"{"type":"opponentGone","gone":false}"#));
+    assert_eq!(changes, [GameChange::OpponentBack]);
+
+    let changes = tracker.update(&state(
+        r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":299000,"btime":298000,"winc":2000,"binc":2000,"status":"started"}"#,
+    ));
+    assert_eq!(changes, [GameChange::TakenBack(2)]);
+
+    let changes = tracker.update(&state(
+        r#"{"type":"gameState","moves":"e2e4 e7e5","wtime":299000,"btime":298000,"winc":2000,"binc":2000,"status":"resign","winner":"black"}"#,
+    ));
+    assert_eq!(
+        changes,
+        [GameChange::Status {
+            from: GameStatus::Started,
+            to: GameStatus::Resign
+        }]
+    );
+
+    let snapshot = tracker.snapshot().unwrap();
+    assert!(snapshot.is_over());
+    assert!(!snapshot.is_my_turn());
+    assert_eq!(snapshot.winner, Some(Color::Black));
+}
+
+#[test]
+fn tracker_spectator() {
+    let mut tracker = GameTracker::spectator();
+    tracker.update(&state(GAME_FULL));
+
+    // Run some test cases
+    let snapshot = tracker.snapshot().unwrap();
+    assert_eq!(snapshot.color, None);
+    assert!(!snapshot.is_my_turn());
+}
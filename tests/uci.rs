@@ -0,0 +1,105 @@
+#![cfg(all(feature = "uci", unix))]
+
+use std::{env, fs, os::unix::fs::PermissionsExt, path::PathBuf};
+
+use licheszter::{config::uci::GoOptions, models::game::GameState, uci::UciEngine};
+
+// A fake engine that only plays e7e5 after e2e4 with the expected search limits
+const FAKE_ENGINE: &str = r#"#!/bin/sh
+while read -r line; do
+    case "$line" in
+        uci) echo "id name Fake Engine"; echo "option name UCI_Chess960 type check default false"; echo "uciok" ;;
+        isready) echo "readyok" ;;
+        position*) position="$line" ;;
+        go*)
+            echo "info depth 1 score cp 20 pv e7e5"
+            if [ "$position" = "position startpos moves e2e4" ] && [ "$line" = "go wtime 300000 btime 290000 winc 2000 binc 3000" ]; then
+                echo "bestmove e7e5 ponder g1f3"
+            elif [ "$position" = "position fen 8/8/8/8/8/8/8/K1k5 w - - 0 1" ]; then
+                echo "bestmove (none)"
+            else
+                echo "bestmove a7a6"
+            fi ;;
+        quit) exit 0 ;;
+    esac
+done
+"#;
+
+// Write the fake engine script into a temporary file
+fn fake_engine(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!(
+        "licheszter-fake-engine-{name}-{}",
+        std::process::id()
+    ));
+    fs::write(&path, FAKE_ENGINE).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+#[tokio::test]
+async fn uci_best_move() {
+    let path = fake_engine("best-move");
+    let mut engine = UciEngine::spawn(&path).await.unwrap();
+
+    // Run some test cases
+    assert_eq!(engine.name(), Some("Fake Engine"));
+    engine.new_game().await.unwrap();
+    engine.set_option("Hash", 16).await.unwrap();
+
+    let state: GameState = serde_json::from_str(
+        r#"{"type":"gameState","moves":"e2e4","wtime":300000,"btime":290000,"winc":2000,"binc":3000,"status":"started"}"#,
+    )
+    .unwrap();
+    let result = engine
+        .best_move("startpos", &state.moves, &GoOptions::from_state(&state))
+        .await;
+    assert_eq!(result.unwrap(), "e7e5");
+
+    let result = engine
+        .best_move(
+            "8/8/8/8/8/8/8/K1k5 w - - 0 1",
+            "",
+            &GoOptions::new().depth(10),
+        )
+        .await;
+    assert!(result.unwrap_err().is_engine());
+
+    engine.quit().await.unwrap();
+    fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn uci_spawn_missing() {
+    let result = UciEngine::spawn("/nonexistent/licheszter-engine").await;
+    assert!(result.unwrap_err().is_io());
+}
+
+#[tokio::test]
+async fn uci_analyse() {
+    let path = fake_engine("analyse");
+    let mut engine = UciEngine::spawn(&path).await.unwrap();
+
+    // Run some test cases
+    let mut lines = Vec::new();
+    engine
+        .analyse(
+            "startpos",
+            "e2e4",
+            &GoOptions::new().infinite(true),
+            |line| {
+                lines.push(line.to_string());
+                true
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(lines, ["info depth 1 score cp 20 pv e7e5"]);
+
+    let result = engine
+        .analyse("startpos", "", &GoOptions::new().depth(5), |_| false)
+        .await;
+    assert!(result.is_ok(), "Failed to stop the analysis: {result:?}");
+
+    engine.quit().await.unwrap();
+    fs::remove_file(path).unwrap();
+}